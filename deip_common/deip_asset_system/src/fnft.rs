@@ -1,5 +1,6 @@
+use codec::Encode;
 use frame_support::dispatch::DispatchResult;
-use sp_runtime::DispatchError;
+use sp_runtime::{traits::Hash, DispatchError};
 
 use crate::{CollectionRecordT, FractionalT, FractionRecordT, NFTImplT, Seal, error::Error};
 
@@ -184,6 +185,16 @@ impl<Impl: NFTImplT> Unique<Impl> for OpaqueUnique<Impl> {
     }
 }
 
+/// Item bound to a Project: the fingerprint is derived from the project id,
+/// so the item of the project is found without separate bookkeeping.
+pub struct UniqueProject<Id: Encode>(pub Id);
+
+impl<Impl: NFTImplT, Id: Encode> Unique<Impl> for UniqueProject<Id> {
+    fn fingerprint(self) -> Impl::Fingerprint {
+        Impl::Hasher::hash_of(&("PROJECT", self.0))
+    }
+}
+
 pub mod unique_demo {
     use super::Unique;
     use crate::NFTImplT;
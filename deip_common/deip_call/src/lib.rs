@@ -116,6 +116,41 @@ impl WrappedCall<Call> {
             }
             .serialize(serializer),
 
+            archive_project { project_id } => CallObject {
+                module: "deip",
+                call: "archive_project",
+                args: &DeipArchiveProjectCallArgs { project_id },
+            }
+            .serialize(serializer),
+
+            remove_project { project_id, contents, reviews, versions } => CallObject {
+                module: "deip",
+                call: "remove_project",
+                args: &DeipRemoveProjectCallArgs { project_id, contents, reviews, versions },
+            }
+            .serialize(serializer),
+
+            offer_project_transfer { project_id, team_id } => CallObject {
+                module: "deip",
+                call: "offer_project_transfer",
+                args: &DeipOfferProjectTransferCallArgs { project_id, team_id },
+            }
+            .serialize(serializer),
+
+            cancel_project_transfer { project_id } => CallObject {
+                module: "deip",
+                call: "cancel_project_transfer",
+                args: &DeipCancelProjectTransferCallArgs { project_id },
+            }
+            .serialize(serializer),
+
+            accept_project_transfer { project_id } => CallObject {
+                module: "deip",
+                call: "accept_project_transfer",
+                args: &DeipAcceptProjectTransferCallArgs { project_id },
+            }
+            .serialize(serializer),
+
             create_project_content {
                 external_id,
                 project_external_id,
@@ -124,7 +159,9 @@ impl WrappedCall<Call> {
                 description,
                 content,
                 authors,
+                author_shares,
                 references,
+                license,
             } => CallObject {
                 module: "deip",
                 call: "create_project_content",
@@ -136,78 +173,110 @@ impl WrappedCall<Call> {
                     description,
                     content,
                     authors,
+                    author_shares,
                     references,
+                    license,
+                },
+            }
+            .serialize(serializer),
+
+            update_project_content { external_id, description, content, authors, author_shares } =>
+                CallObject {
+                    module: "deip",
+                    call: "update_project_content",
+                    args: &DeipUpdateProjectContentCallArgs {
+                        external_id,
+                        description,
+                        content,
+                        authors,
+                        author_shares,
+                    },
+                }
+                .serialize(serializer),
+
+            set_project_content_license { external_id, license } => CallObject {
+                module: "deip",
+                call: "set_project_content_license",
+                args: &DeipSetProjectContentLicenseCallArgs { external_id, license },
+            }
+            .serialize(serializer),
+
+            create_project_nda {
+                external_id,
+                end_date,
+                contract_hash,
+                maybe_start_date,
+                parties,
+                projects,
+            } => CallObject {
+                module: "deip",
+                call: "create_project_nda",
+                args: &DeipCreateProjectNdaCallArgs {
+                    external_id,
+                    end_date,
+                    contract_hash,
+                    maybe_start_date,
+                    parties,
+                    projects,
                 },
             }
             .serialize(serializer),
 
-            // create_project_nda {
-            //     external_id,
-            //     end_date,
-            //     contract_hash,
-            //     maybe_start_date,
-            //     parties,
-            //     projects,
-            // } => CallObject {
-            //     module: "deip",
-            //     call: "create_project_nda",
-            //     args: &DeipCreateProjectNdaCallArgs {
-            //         external_id,
-            //         end_date,
-            //         contract_hash,
-            //         maybe_start_date,
-            //         parties,
-            //         projects,
-            //     },
-            // }
-            // .serialize(serializer),
-
-            // create_nda_content_access_request {
-            //     external_id,
-            //     nda_external_id,
-            //     encrypted_payload_hash,
-            //     encrypted_payload_iv,
-            // } => CallObject {
-            //     module: "deip",
-            //     call: "create_nda_content_access_request",
-            //     args: &DeipCreateProjectNdaAccessRequestCallArgs {
-            //         external_id,
-            //         nda_external_id,
-            //         encrypted_payload_hash,
-            //         encrypted_payload_iv,
-            //     },
-            // }
-            // .serialize(serializer),
-
-            // fulfill_nda_content_access_request {
-            //     external_id,
-            //     encrypted_payload_encryption_key,
-            //     proof_of_encrypted_payload_encryption_key,
-            // } => CallObject {
-            //     module: "deip",
-            //     call: "fulfill_nda_content_access_request",
-            //     args: &DeipFulfillNdaAccessRequestCallArgs {
-            //         external_id,
-            //         encrypted_payload_encryption_key,
-            //         proof_of_encrypted_payload_encryption_key,
-            //     },
-            // }
-            // .serialize(serializer),
-
-            // reject_nda_content_access_request { external_id } => CallObject {
-            //     module: "deip",
-            //     call: "reject_nda_content_access_request",
-            //     args: &DeipRejectNdaAccessRequestCallArgs { external_id },
-            // }
-            // .serialize(serializer),
+            create_nda_content_access_request {
+                external_id,
+                nda_external_id,
+                encrypted_payload_hash,
+                encrypted_payload_iv,
+            } => CallObject {
+                module: "deip",
+                call: "create_nda_content_access_request",
+                args: &DeipCreateProjectNdaAccessRequestCallArgs {
+                    external_id,
+                    nda_external_id,
+                    encrypted_payload_hash,
+                    encrypted_payload_iv,
+                },
+            }
+            .serialize(serializer),
+
+            fulfill_nda_content_access_request {
+                external_id,
+                encrypted_payload_encryption_key,
+                proof_of_encrypted_payload_encryption_key,
+            } => CallObject {
+                module: "deip",
+                call: "fulfill_nda_content_access_request",
+                args: &DeipFulfillNdaAccessRequestCallArgs {
+                    external_id,
+                    encrypted_payload_encryption_key,
+                    proof_of_encrypted_payload_encryption_key,
+                },
+            }
+            .serialize(serializer),
+
+            reject_nda_content_access_request { external_id } => CallObject {
+                module: "deip",
+                call: "reject_nda_content_access_request",
+                args: &DeipRejectNdaAccessRequestCallArgs { external_id },
+            }
+            .serialize(serializer),
+
+            add_assessment_model { external_id, name, criteria } => CallObject {
+                module: "deip",
+                call: "add_assessment_model",
+                args: &DeipAddAssessmentModelCallArgs { external_id, name, criteria },
+            }
+            .serialize(serializer),
+
             create_review {
                 external_id,
                 author,
                 content,
                 domains,
                 assessment_model,
-                weight,
+                scores,
                 project_content_external_id,
+                project_content_version,
             } => CallObject {
                 module: "deip",
                 call: "create_review",
@@ -217,8 +286,9 @@ impl WrappedCall<Call> {
                     content,
                     domains,
                     assessment_model,
-                    weight,
+                    scores,
                     project_content_external_id,
+                    project_content_version,
                 },
             }
             .serialize(serializer),
@@ -230,12 +300,83 @@ impl WrappedCall<Call> {
             }
             .serialize(serializer),
 
-            // add_domain { domain } => CallObject {
-            //     module: "deip",
-            //     call: "add_domain",
-            //     args: &DeipAddDomainCallArgs { domain },
-            // }
-            // .serialize(serializer),
+            create_review_request {
+                external_id,
+                project_content_id,
+                domain_id,
+                reward,
+                required_upvotes,
+                deadline,
+            } => CallObject {
+                module: "deip",
+                call: "create_review_request",
+                args: &DeipCreateReviewRequestCallArgs::new(
+                    external_id,
+                    project_content_id,
+                    domain_id,
+                    reward,
+                    required_upvotes,
+                    deadline,
+                ),
+            }
+            .serialize(serializer),
+
+            claim_review_reward { request_id, review_id } => CallObject {
+                module: "deip",
+                call: "claim_review_reward",
+                args: &DeipClaimReviewRewardCallArgs { request_id, review_id },
+            }
+            .serialize(serializer),
+
+            refund_review_request { request_id } => CallObject {
+                module: "deip",
+                call: "refund_review_request",
+                args: &DeipRefundReviewRequestCallArgs { request_id },
+            }
+            .serialize(serializer),
+
+            add_domain { external_id, name, parent } => CallObject {
+                module: "deip",
+                call: "add_domain",
+                args: &DeipAddDomainCallArgs { external_id, name, parent },
+            }
+            .serialize(serializer),
+
+            rename_domain { domain_id, name } => CallObject {
+                module: "deip",
+                call: "rename_domain",
+                args: &DeipRenameDomainCallArgs { domain_id, name },
+            }
+            .serialize(serializer),
+
+            set_domain_parent { domain_id, parent } => CallObject {
+                module: "deip",
+                call: "set_domain_parent",
+                args: &DeipSetDomainParentCallArgs { domain_id, parent },
+            }
+            .serialize(serializer),
+
+            deprecate_domain { domain_id } => CallObject {
+                module: "deip",
+                call: "deprecate_domain",
+                args: &DeipDeprecateDomainCallArgs { domain_id },
+            }
+            .serialize(serializer),
+
+            add_content_type { external_id, name, finalizes_project } => CallObject {
+                module: "deip",
+                call: "add_content_type",
+                args: &DeipAddContentTypeCallArgs { external_id, name, finalizes_project },
+            }
+            .serialize(serializer),
+
+            deprecate_content_type { external_id } => CallObject {
+                module: "deip",
+                call: "deprecate_content_type",
+                args: &DeipDeprecateContentTypeCallArgs { external_id },
+            }
+            .serialize(serializer),
+
             create_contract_agreement {
                 id,
                 creator,
@@ -273,6 +414,27 @@ impl WrappedCall<Call> {
             }
             .serialize(serializer),
 
+            approve_contract_milestone { id, milestone } => CallObject {
+                module: "deip",
+                call: "approve_contract_milestone",
+                args: &DeipApproveContractMilestoneCallArgs { id, milestone },
+            }
+            .serialize(serializer),
+
+            expire_contract_agreements { ids } => CallObject {
+                module: "deip",
+                call: "expire_contract_agreements",
+                args: &DeipExpireContractAgreementsCallArgs { ids },
+            }
+            .serialize(serializer),
+
+            mint_project_nft { project_id, collection } => CallObject {
+                module: "deip",
+                call: "mint_project_nft",
+                args: &DeipMintProjectNftCallArgs { project_id, collection },
+            }
+            .serialize(serializer),
+
             __PhantomItem(..) => unreachable!(),
         }
     }
@@ -427,6 +589,69 @@ impl WrappedCall<Call> {
             }
             .serialize(serializer),
 
+            alter_roles { roles } => CallObject {
+                module: "deip_dao",
+                call: "alter_roles",
+                args: &DeipDaoAlterRolesCallArgs { alter_roles: roles },
+            }
+            .serialize(serializer),
+
+            set_spending_limit { asset, limit } => CallObject {
+                module: "deip_dao",
+                call: "set_spending_limit",
+                args: &DeipDaoSetSpendingLimitCallArgs { asset, limit },
+            }
+            .serialize(serializer),
+
+            set_timelock { delay } => CallObject {
+                module: "deip_dao",
+                call: "set_timelock",
+                args: &DeipDaoSetTimelockCallArgs { delay },
+            }
+            .serialize(serializer),
+
+            cancel_authority_change { change_id } => CallObject {
+                module: "deip_dao",
+                call: "cancel_authority_change",
+                args: &DeipDaoCancelAuthorityChangeCallArgs { change_id },
+            }
+            .serialize(serializer),
+
+            apply_authority_change { name, change_id } => CallObject {
+                module: "deip_dao",
+                call: "apply_authority_change",
+                args: &DeipDaoApplyAuthorityChangeCallArgs { name, change_id },
+            }
+            .serialize(serializer),
+
+            create_sub_dao { name, authority_key, authority, metadata } => CallObject {
+                module: "deip_dao",
+                call: "create_sub_dao",
+                args: &DeipDaoCreateSubDaoCallArgs { name, authority_key, authority, metadata },
+            }
+            .serialize(serializer),
+
+            freeze_sub_dao { sub_dao, frozen } => CallObject {
+                module: "deip_dao",
+                call: "freeze_sub_dao",
+                args: &DeipDaoFreezeSubDaoCallArgs { sub_dao, frozen },
+            }
+            .serialize(serializer),
+
+            reclaim_sub_dao { sub_dao } => CallObject {
+                module: "deip_dao",
+                call: "reclaim_sub_dao",
+                args: &DeipDaoReclaimSubDaoCallArgs { sub_dao },
+            }
+            .serialize(serializer),
+
+            dissolve { distribution, assets } => CallObject {
+                module: "deip_dao",
+                call: "dissolve",
+                args: &DeipDaoDissolveCallArgs { distribution, assets },
+            }
+            .serialize(serializer),
+
             update_dao { new_metadata } => CallObject {
                 module: "deip_dao",
                 call: "update_dao",
@@ -568,6 +793,58 @@ struct DeipDaoAlterAuthorityCallArgs<A> {
     alter_authority: A,
 }
 
+#[derive(Serialize)]
+struct DeipDaoAlterRolesCallArgs<A> {
+    alter_roles: A,
+}
+
+#[derive(Serialize)]
+struct DeipDaoSetSpendingLimitCallArgs<A, B> {
+    asset: A,
+    limit: B,
+}
+
+#[derive(Serialize)]
+struct DeipDaoSetTimelockCallArgs<A> {
+    delay: A,
+}
+
+#[derive(Serialize)]
+struct DeipDaoCancelAuthorityChangeCallArgs<A> {
+    change_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipDaoApplyAuthorityChangeCallArgs<A, B> {
+    name: A,
+    change_id: B,
+}
+
+#[derive(Serialize)]
+struct DeipDaoCreateSubDaoCallArgs<A, B, C, D> {
+    name: A,
+    authority_key: B,
+    authority: C,
+    metadata: D,
+}
+
+#[derive(Serialize)]
+struct DeipDaoFreezeSubDaoCallArgs<A, B> {
+    sub_dao: A,
+    frozen: B,
+}
+
+#[derive(Serialize)]
+struct DeipDaoReclaimSubDaoCallArgs<A> {
+    sub_dao: A,
+}
+
+#[derive(Serialize)]
+struct DeipDaoDissolveCallArgs<A, B> {
+    distribution: A,
+    assets: B,
+}
+
 #[derive(Serialize)]
 struct DeipDaoUpdateCallArgs<A> {
     metadata: A,
@@ -598,8 +875,39 @@ struct DeipProposalProposeCallArgs<A, B> {
 }
 
 #[derive(Serialize)]
-struct DeipAddDomainCallArgs<A> {
-    domain: A,
+struct DeipAddDomainCallArgs<A, B, C> {
+    external_id: A,
+    name: B,
+    parent: C,
+}
+
+#[derive(Serialize)]
+struct DeipRenameDomainCallArgs<A, B> {
+    domain_id: A,
+    name: B,
+}
+
+#[derive(Serialize)]
+struct DeipSetDomainParentCallArgs<A, B> {
+    domain_id: A,
+    parent: B,
+}
+
+#[derive(Serialize)]
+struct DeipDeprecateDomainCallArgs<A> {
+    domain_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipAddContentTypeCallArgs<A, B, C> {
+    external_id: A,
+    name: B,
+    finalizes_project: C,
+}
+
+#[derive(Serialize)]
+struct DeipDeprecateContentTypeCallArgs<A> {
+    external_id: A,
 }
 
 #[derive(Serialize)]
@@ -626,14 +934,39 @@ struct DeipRejectContractAgreementCallArgs<A, B> {
 }
 
 #[derive(Serialize)]
-struct DeipCreateReviewCallArgs<A, B, C, D, E, F, G> {
+struct DeipApproveContractMilestoneCallArgs<A, B> {
+    id: A,
+    milestone: B,
+}
+
+#[derive(Serialize)]
+struct DeipExpireContractAgreementsCallArgs<A> {
+    ids: A,
+}
+
+#[derive(Serialize)]
+struct DeipMintProjectNftCallArgs<A, B> {
+    project_id: A,
+    collection: B,
+}
+
+#[derive(Serialize)]
+struct DeipAddAssessmentModelCallArgs<A, B, C> {
+    external_id: A,
+    name: B,
+    criteria: C,
+}
+
+#[derive(Serialize)]
+struct DeipCreateReviewCallArgs<A, B, C, D, E, F, G, H> {
     external_id: A,
     author: B,
     content: C,
     domains: D,
     assessment_model: E,
-    weight: F,
+    scores: F,
     project_content_external_id: G,
+    project_content_version: H,
 }
 
 #[derive(Serialize)]
@@ -642,6 +975,49 @@ struct DeipUpvoteReviewCallArgs<A, B> {
     domain_id: B,
 }
 
+#[derive(Serialize)]
+struct DeipCreateReviewRequestCallArgs<A, B, C, D: Clone + AtLeast32BitUnsigned, E, F> {
+    external_id: A,
+    project_content_id: B,
+    domain_id: C,
+    reward: SerializableAtLeast32BitUnsigned<D>,
+    required_upvotes: E,
+    deadline: F,
+}
+
+impl<A, B, C, D: Clone + AtLeast32BitUnsigned, E, F>
+    DeipCreateReviewRequestCallArgs<A, B, C, D, E, F>
+{
+    fn new(
+        external_id: A,
+        project_content_id: B,
+        domain_id: C,
+        reward: &D,
+        required_upvotes: E,
+        deadline: F,
+    ) -> Self {
+        Self {
+            external_id,
+            project_content_id,
+            domain_id,
+            reward: SerializableAtLeast32BitUnsigned(reward.clone()),
+            required_upvotes,
+            deadline,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DeipClaimReviewRewardCallArgs<A, B> {
+    request_id: A,
+    review_id: B,
+}
+
+#[derive(Serialize)]
+struct DeipRefundReviewRequestCallArgs<A> {
+    request_id: A,
+}
+
 #[derive(Serialize)]
 struct DeipRejectNdaAccessRequestCallArgs<A> {
     external_id: A,
@@ -673,7 +1049,7 @@ struct DeipCreateProjectNdaCallArgs<A, B, C, D, E, F> {
 }
 
 #[derive(Serialize)]
-struct DeipCreateProjectContentCallArgs<A, B, C, D, E, F, G, H> {
+struct DeipCreateProjectContentCallArgs<A, B, C, D, E, F, G, H, I, J> {
     external_id: A,
     project_external_id: B,
     team_id: C,
@@ -681,7 +1057,24 @@ struct DeipCreateProjectContentCallArgs<A, B, C, D, E, F, G, H> {
     description: E,
     content: F,
     authors: G,
-    references: H,
+    author_shares: H,
+    references: I,
+    license: J,
+}
+
+#[derive(Serialize)]
+struct DeipUpdateProjectContentCallArgs<A, B, C, D, E> {
+    external_id: A,
+    description: B,
+    content: C,
+    authors: D,
+    author_shares: E,
+}
+
+#[derive(Serialize)]
+struct DeipSetProjectContentLicenseCallArgs<A, B> {
+    external_id: A,
+    license: B,
 }
 
 #[derive(Serialize)]
@@ -733,6 +1126,35 @@ struct DeipUpdateProjectCallArgs<A, B, C> {
     is_private: C,
 }
 
+#[derive(Serialize)]
+struct DeipArchiveProjectCallArgs<A> {
+    project_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipRemoveProjectCallArgs<A, B, C, D> {
+    project_id: A,
+    contents: B,
+    reviews: C,
+    versions: D,
+}
+
+#[derive(Serialize)]
+struct DeipOfferProjectTransferCallArgs<A, B> {
+    project_id: A,
+    team_id: B,
+}
+
+#[derive(Serialize)]
+struct DeipCancelProjectTransferCallArgs<A> {
+    project_id: A,
+}
+
+#[derive(Serialize)]
+struct DeipAcceptProjectTransferCallArgs<A> {
+    project_id: A,
+}
+
 #[derive(Serialize)]
 struct CrowdfundingReadyCallArgs<A, B, C, D, E> {
     id: A,
@@ -52,6 +52,12 @@ pub enum Error {
     DeipClassIdInverseIndexFailed = 43,
     ClassInstanceDecodeFailed = 44,
     ClassDetailsDecodeFailed = 45,
+    ContentCitationsApiGetFailed = 46,
+    DaoApiGetPendingAuthorityChangesFailed = 47,
+    DaoApiGetSubDaosFailed = 48,
+    DaoApiVersionUnsupported = 49,
+    DeipApiVersionUnsupported = 50,
+    UnknownListStartId = 51,
 }
 
 impl From<Error> for RpcErrorCode {
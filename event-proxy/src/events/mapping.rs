@@ -76,6 +76,46 @@ where
             // deip_dao::DaoMetadataUpdatedEvent
             unimplemented!()
         },
+        DaoAlterRoles(..) => {
+            // deip_dao::DaoAlterRolesEvent
+            unimplemented!()
+        },
+        DaoSpendingLimitSet(..) => {
+            // deip_dao::DaoSpendingLimitSetEvent
+            unimplemented!()
+        },
+        DaoTimelockSet(..) => {
+            // deip_dao::DaoTimelockSetEvent
+            unimplemented!()
+        },
+        DaoAuthorityChangeQueued(..) => {
+            // deip_dao::DaoAuthorityChangeQueuedEvent
+            unimplemented!()
+        },
+        DaoAuthorityChangeCancelled(..) => {
+            // deip_dao::DaoAuthorityChangeCancelledEvent
+            unimplemented!()
+        },
+        DaoAuthorityChangeApplied(..) => {
+            // deip_dao::DaoAuthorityChangeAppliedEvent
+            unimplemented!()
+        },
+        DaoDissolved(..) => {
+            // deip_dao::DaoDissolvedEvent
+            unimplemented!()
+        },
+        DaoSubDaoCreated(..) => {
+            // deip_dao::DaoSubDaoCreatedEvent
+            unimplemented!()
+        },
+        DaoSubDaoFrozen(..) => {
+            // deip_dao::DaoSubDaoFrozenEvent
+            unimplemented!()
+        },
+        DaoSubDaoReclaimed(..) => {
+            // deip_dao::DaoSubDaoReclaimedEvent
+            unimplemented!()
+        },
         __Ignore(..) => unreachable!(),
     }
 }
@@ -211,6 +251,58 @@ where
             // deip::ProjectUpdatedEvent
             unimplemented!()
         },
+        ProjectTransferOffered(..) => {
+            // deip::ProjectTransferOfferedEvent
+            unimplemented!()
+        },
+        ProjectTransferCancelled(..) => {
+            // deip::ProjectTransferCancelledEvent
+            unimplemented!()
+        },
+        ProjectTransferred(..) => {
+            // deip::ProjectTransferredEvent
+            unimplemented!()
+        },
+        ProjectNftMinted(..) => {
+            // deip::ProjectNftMintedEvent
+            unimplemented!()
+        },
+        ProjectArchived(..) => {
+            // deip::ProjectArchivedEvent
+            unimplemented!()
+        },
+        ProjectContentUpdated(..) => {
+            // deip::ProjectContentUpdatedEvent
+            unimplemented!()
+        },
+        ProjectContentLicenseSet(..) => {
+            // deip::ProjectContentLicenseSetEvent
+            unimplemented!()
+        },
+        ContentRoyaltyPaid(..) => {
+            // deip::ContentRoyaltyPaidEvent
+            unimplemented!()
+        },
+        AssessmentModelAdded(..) => {
+            // deip::AssessmentModelAddedEvent
+            unimplemented!()
+        },
+        ExpertiseUpdated(..) => {
+            // deip::ExpertiseUpdatedEvent
+            unimplemented!()
+        },
+        ReviewRequestCreated(..) => {
+            // deip::ReviewRequestCreatedEvent
+            unimplemented!()
+        },
+        ReviewRewardPaid(..) => {
+            // deip::ReviewRewardPaidEvent
+            unimplemented!()
+        },
+        ReviewRequestRefunded(..) => {
+            // deip::ReviewRequestRefundedEvent
+            unimplemented!()
+        },
         ProjectContnetCreated(..) => {
             // deip::ProjectContentCreatedEvent
             unimplemented!()
@@ -235,6 +327,26 @@ where
             // deip::DomainAddedEvent
             unimplemented!()
         },
+        DomainRenamed(..) => {
+            // deip::DomainRenamedEvent
+            unimplemented!()
+        },
+        DomainParentChanged(..) => {
+            // deip::DomainParentChangedEvent
+            unimplemented!()
+        },
+        DomainDeprecated(..) => {
+            // deip::DomainDeprecatedEvent
+            unimplemented!()
+        },
+        ContentTypeAdded(..) => {
+            // deip::ContentTypeAddedEvent
+            unimplemented!()
+        },
+        ContentTypeDeprecated(..) => {
+            // deip::ContentTypeDeprecatedEvent
+            unimplemented!()
+        },
         ReviewCreated(..) => {
             // deip::ReviewCreatedEvent
             unimplemented!()
@@ -255,10 +367,26 @@ where
             // deip::ContractAgreementFinalizedEvent
             unimplemented!()
         },
+        ContractAgreementExpired(..) => {
+            // deip::ContractAgreementExpiredEvent
+            unimplemented!()
+        },
+        ContractMilestoneApproved(..) => {
+            // deip::ContractMilestoneApprovedEvent
+            unimplemented!()
+        },
+        ContractMilestoneReleased(..) => {
+            // deip::ContractMilestoneReleasedEvent
+            unimplemented!()
+        },
         ContractAgreementRejected(..) => {
             // deip::ContractAgreementRejectedEvent
             unimplemented!()
         },
+        ContractEscrowRefundShortfall(..) => {
+            // deip::ContractEscrowRefundShortfallEvent
+            unimplemented!()
+        },
     }
 }
 
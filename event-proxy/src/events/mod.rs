@@ -141,6 +141,7 @@ where
             ProjectCreated(e) => e.serialize(serializer),
             ProjectRemoved(e) => e.serialize(serializer),
             ProjectUpdated(e) => e.serialize(serializer),
+            ProjectTransferred(e) => e.serialize(serializer),
             ProjectContentCreated(e) => e.serialize(serializer),
             NdaCreated(e) => e.serialize(serializer),
             NdaAccessRequestCreated(e) => e.serialize(serializer),
@@ -246,6 +247,7 @@ pub enum DomainEventData<T: DeipProposal> {
     ProjectCreated(deip_events::ProjectCreated),
     ProjectRemoved(deip_events::ProjectRemoved),
     ProjectUpdated(deip_events::ProjectUpdated),
+    ProjectTransferred(deip_events::ProjectTransferred),
     ProjectContentCreated(deip_events::ProjectContnetCreated),
     NdaCreated(deip_events::NdaCreated),
     NdaAccessRequestCreated(deip_events::NdaAccessRequestCreated),
@@ -385,6 +387,12 @@ where
             data: decode_event_data(raw).map(DomainEventData::ProjectUpdated)?,
             meta,
         },
+        (deip_events::ProjectTransferred::PALLET, deip_events::ProjectTransferred::EVENT) =>
+            DomainEvent {
+                name: "project_transferred".to_string(),
+                data: decode_event_data(raw).map(DomainEventData::ProjectTransferred)?,
+                meta,
+            },
         (deip_events::ProjectContnetCreated::PALLET, deip_events::ProjectContnetCreated::EVENT) =>
             DomainEvent {
                 name: "project_contentCreated".to_string(),
@@ -7,7 +7,8 @@ use crate::appchain_deip::deip::events::{
     ContractAgreementAccepted, ContractAgreementCreated, ContractAgreementFinalized,
     ContractAgreementRejected, DomainAdded, NdaAccessRequestCreated,
     NdaAccessRequestFulfilled, NdaAccessRequestRejected, NdaCreated, ProjectContnetCreated,
-    ProjectCreated, ProjectRemoved, ProjectUpdated, ReviewCreated, ReviewUpvoted,
+    ProjectCreated, ProjectRemoved, ProjectTransferred, ProjectUpdated, ReviewCreated,
+    ReviewUpvoted,
 };
 
 const ACCOUNT_ID_KEY: &str = "account_id";
@@ -48,6 +49,19 @@ impl Serialize for ProjectUpdated {
     }
 }
 
+impl Serialize for ProjectTransferred {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("ProjectTransferredEvent", 3)?;
+        s.serialize_field("project_id", &self.0)?;
+        s.serialize_field("from_team_id", &self.1)?;
+        s.serialize_field("to_team_id", &self.2)?;
+        s.end()
+    }
+}
+
 impl Serialize for ProjectContnetCreated {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
     where
@@ -5100,6 +5100,24 @@ pub mod api {
                 PartialEq,
                 scale_info :: TypeInfo,
             )]
+            pub struct ProjectTransferred(
+                pub runtime_types::primitive_types::H160,
+                pub ::subxt::sp_core::crypto::AccountId32,
+                pub ::subxt::sp_core::crypto::AccountId32,
+            );
+            impl ::subxt::Event for ProjectTransferred {
+                const PALLET: &'static str = "Deip";
+                const EVENT: &'static str = "ProjectTransferred";
+            }
+            #[derive(
+                :: subxt :: codec :: Encode,
+                :: subxt :: codec :: Decode,
+                Debug,
+                Clone,
+                Eq,
+                PartialEq,
+                scale_info :: TypeInfo,
+            )]
             pub struct ProjectContnetCreated(
                 pub ::subxt::sp_core::crypto::AccountId32,
                 pub runtime_types::primitive_types::H160,
@@ -304,7 +304,11 @@ fn genesis(
         },
         octopus_lpos: OctopusLposConfig { era_payout: appchain_config.2, ..Default::default() },
         deip: DeipConfig {
-            domains: domains.iter().cloned().map(|k| (k, Domain { external_id: k })).collect(),
+            domains: domains
+                .iter()
+                .cloned()
+                .map(|k| (k, Domain { external_id: k, ..Default::default() }))
+                .collect(),
             domain_count: domains.len() as u32,
         },
         deip_proposal: DeipProposalConfig {},
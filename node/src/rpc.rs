@@ -115,7 +115,7 @@ where
     C::Api: BabeApi<Block>,
     C::Api: BlockBuilder<Block>,
     C::Api: Metadata<Block>,
-    C::Api: deip_dao_rpc::DeipDaoRuntimeApi<Block, AccountId>,
+    C::Api: deip_dao_rpc::DeipDaoRuntimeApi<Block, AccountId, BlockNumber>,
     C::Api: deip_rpc::DeipStorageRuntimeApi<
         Block,
         AccountId,
@@ -124,6 +124,7 @@ where
         AssetBalance,
         Hash,
         TransactionCtxId,
+        Balance,
     >,
     P: TransactionPool + 'static,
     SC: SelectChain<Block> + 'static,
@@ -8,7 +8,11 @@ use sp_runtime::traits::AtLeast32BitUnsigned;
 // Here we declare the runtime API. It is implemented it the `impl` block in
 // runtime amalgamator file (the `runtime/src/lib.rs`)
 sp_api::decl_runtime_apis! {
-    pub trait DeipApi<AccountId, Moment, AssetId, AssetBalance, Hash, TransactionCtx>
+    // Version 2 also changed the encoding of `Project`, `Domain`, `ProjectContent`,
+    // `Review` and `contract::Agreement`, so only `get_nda` is readable from a v1 runtime.
+    // The `*_list_by_*` getters return `None` if `start_id` is not in the index.
+    #[api_version(2)]
+    pub trait DeipApi<AccountId, Moment, AssetId, AssetBalance, Hash, TransactionCtx, Balance>
         where
             AccountId: Codec,
             Moment: Codec,
@@ -16,12 +20,39 @@ sp_api::decl_runtime_apis! {
             AssetBalance: Codec + Clone + AtLeast32BitUnsigned,
             Hash: Codec,
             TransactionCtx: Codec,
+            Balance: Codec + Clone + AtLeast32BitUnsigned,
     {
         fn get_project(project_id: &ProjectId) -> Option<Project<Hash, AccountId>>;
         fn get_domain(domain_id: &DomainId) -> Option<Domain>;
         fn get_project_content(id: &ProjectContentId) -> Option<ProjectContent<Hash, AccountId>>;
+        /// Since version 2
+        fn get_project_content_version(id: &ProjectContentId, version: u32) -> Option<ProjectContentVersion<Hash, AccountId>>;
+        /// Since version 2
+        fn get_content_citations(id: &ProjectContentId, start_id: Option<ProjectContentId>, count: u32) -> Option<Vec<ProjectContentId>>;
+        /// Since version 2
+        fn get_content_citation_count(id: &ProjectContentId) -> u32;
         fn get_nda(nda_id: &NdaId) -> Option<Nda<Hash, AccountId, Moment>>;
         fn get_review(review_id: &ReviewId) -> Option<Review<Hash, AccountId>>;
-        fn get_contract_agreement(id: &ContractAgreementId) -> Option<contract::Agreement<AccountId, Hash, Moment>>;
+        /// Since version 2
+        fn get_assessment_model(id: &AssessmentModelId) -> Option<AssessmentModel>;
+        /// Since version 2
+        fn get_expertise(account: &AccountId, domain_id: &DomainId) -> u64;
+        /// Since version 2
+        fn get_account_expertise(account: &AccountId) -> Vec<(DomainId, u64)>;
+        fn get_contract_agreement(id: &ContractAgreementId) -> Option<contract::Agreement<AccountId, Hash, Moment, Balance>>;
+        /// Since version 2
+        fn get_project_list_by_team(team_id: &AccountId, start_id: Option<ProjectId>, count: u32) -> Option<Vec<(ProjectId, Project<Hash, AccountId>)>>;
+        /// Since version 2
+        fn get_project_content_list_by_project(project_id: &ProjectId, start_id: Option<ProjectContentId>, count: u32) -> Option<Vec<(ProjectContentId, ProjectContent<Hash, AccountId>)>>;
+        /// Since version 2
+        fn get_review_list_by_project_content(id: &ProjectContentId, start_id: Option<ReviewId>, count: u32) -> Option<Vec<(ReviewId, Review<Hash, AccountId>)>>;
+        /// Since version 2
+        fn get_project_list_by_domain(domain_id: &DomainId, start_id: Option<ProjectId>, count: u32) -> Option<Vec<(ProjectId, Project<Hash, AccountId>)>>;
+        /// Since version 2
+        fn get_project_content_list_by_domain(domain_id: &DomainId, start_id: Option<ProjectContentId>, count: u32) -> Option<Vec<(ProjectContentId, ProjectContent<Hash, AccountId>)>>;
+        /// Since version 2
+        fn get_review_list_by_domain(domain_id: &DomainId, start_id: Option<ReviewId>, count: u32) -> Option<Vec<(ReviewId, Review<Hash, AccountId>)>>;
+        /// Since version 2
+        fn get_contract_agreement_list_by_type(terms: &ContractAgreementIndexTerms, start_id: Option<ContractAgreementId>, count: u32) -> Option<Vec<(ContractAgreementId, contract::Agreement<AccountId, Hash, Moment, Balance>)>>;
     }
 }
@@ -0,0 +1,84 @@
+use super::*;
+
+/// Unique Assessment Model reference
+pub type Id = u32;
+
+/// Criterion of the Assessment Model with the allowed range of scores
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Criterion {
+    /// Human readable name of the criterion
+    pub name: Vec<u8>,
+    /// Minimal allowed score
+    pub min: Permill,
+    /// Maximal allowed score
+    pub max: Permill,
+}
+
+/// Model by which Reviews evaluate Project Content. Every Review carries
+/// a score for each criterion of the model.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Model {
+    /// Reference for external world and uniques control
+    pub external_id: Id,
+    /// Human readable name of the model
+    pub name: Vec<u8>,
+    /// Scored criteria
+    pub criteria: Vec<Criterion>,
+}
+
+impl<T: Config> Module<T> {
+    pub(super) fn add_assessment_model_impl(
+        external_id: Id,
+        name: Vec<u8>,
+        criteria: Vec<Criterion>,
+    ) -> DispatchResult {
+        ensure!(
+            !AssessmentModelMapV1::contains_key(external_id),
+            Error::<T>::AssessmentModelAlreadyExists
+        );
+        ensure!(!criteria.is_empty(), Error::<T>::AssessmentModelNoCriteria);
+        ensure!(
+            criteria.len() <= T::MaxAssessmentCriteria::get() as usize,
+            Error::<T>::AssessmentModelTooMuchCriteria
+        );
+
+        let max_name_length = T::MaxAssessmentNameLength::get() as usize;
+        ensure!(name.len() <= max_name_length, Error::<T>::AssessmentModelNameTooLong);
+        for criterion in &criteria {
+            ensure!(
+                criterion.name.len() <= max_name_length,
+                Error::<T>::AssessmentModelNameTooLong
+            );
+            ensure!(criterion.min <= criterion.max, Error::<T>::AssessmentModelWrongScoreRange);
+        }
+
+        AssessmentModelMapV1::insert(external_id, Model { external_id, name, criteria });
+
+        Self::deposit_event(RawEvent::AssessmentModelAdded(external_id));
+
+        Ok(())
+    }
+
+    pub(super) fn ensure_review_scores(model_id: Id, scores: &[Permill]) -> DispatchResult {
+        let model = AssessmentModelMapV1::try_get(model_id)
+            .map_err(|_| Error::<T>::NoSuchAssessmentModel)?;
+
+        ensure!(
+            model.criteria.len() == scores.len(),
+            Error::<T>::ReviewScoresMismatchAssessmentModel
+        );
+
+        let is_all_scores_in_range = model
+            .criteria
+            .iter()
+            .zip(scores)
+            .all(|(criterion, score)| criterion.min <= *score && *score <= criterion.max);
+        ensure!(is_all_scores_in_range, Error::<T>::ReviewScoreOutOfRange);
+
+        Ok(())
+    }
+}
@@ -48,13 +48,12 @@ fn init_team<T: Config>() -> T::DeipAccountId {
 }
 
 fn init_domain(idx: u8) -> Domain {
-    Domain { external_id: DomainId::from([idx; 20]) }
+    Domain { external_id: DomainId::from([idx; 20]), ..Default::default() }
 }
 
 fn create_domain<T: Config>(domain: Domain) -> Domain {
-    let id = domain.external_id;
-    let caller = whitelisted_caller::<T::AccountId>();
-    Pallet::<T>::add_domain(RawOrigin::Signed(caller).into(), domain).unwrap();
+    let Domain { external_id: id, name, parent, .. } = domain;
+    Pallet::<T>::add_domain(RawOrigin::Root.into(), id, name, parent).unwrap();
     Domains::get(id)
 }
 
@@ -66,11 +65,11 @@ fn init_project<T: Config>(idx: u8, domains: u8) -> ProjectOf<T> {
     let domains: Vec<DomainId> = (0..domains)
         .map(|idx| create_domain::<T>(init_domain(idx + 1)).external_id)
         .collect();
-    ProjectOf::<T> { is_private, external_id, team_id, description, domains }
+    ProjectOf::<T> { is_private, external_id, team_id, description, domains, is_archived: false }
 }
 
 fn _create_project<T: Config>(project: ProjectOf<T>) -> ProjectOf<T> {
-    let ProjectOf::<T> { is_private, external_id, team_id, description, domains } = project;
+    let ProjectOf::<T> { is_private, external_id, team_id, description, domains, .. } = project;
     Pallet::<T>::create_project(
         RawOrigin::Signed(team_id.clone()).into(),
         is_private,
@@ -111,7 +110,7 @@ fn init_project_content<T: Config>(
     let external_id: ProjectContentId = project.external_id;
     let project_external_id: ProjectId = project.external_id;
     let team_id: T::AccountId = project.team_id.clone();
-    let content_type: ProjectContentType = ProjectContentType::Announcement;
+    let content_type: ContentTypeId = ProjectContentType::Announcement.into();
     let description: T::Hash = T::Hashing::hash("project content description".as_bytes());
     let content: T::Hash = T::Hashing::hash("project content".as_bytes());
     let authors: Vec<T::AccountId> = (0..authors).map(|idx| init_member::<T>(idx as u32)).collect();
@@ -126,6 +125,9 @@ fn init_project_content<T: Config>(
         content,
         authors,
         references,
+        version: 0,
+        author_shares: Vec::new(),
+        license: None,
     }
 }
 
@@ -139,6 +141,7 @@ fn _create_project_content<T: Config>(project_content: ProjectContentOf<T>) -> P
         content,
         authors,
         references,
+        ..
     } = project_content;
     let authors = authors.into_iter().map(Into::into).collect();
     Pallet::<T>::create_project_content(
@@ -150,7 +153,9 @@ fn _create_project_content<T: Config>(project_content: ProjectContentOf<T>) -> P
         description,
         content,
         authors,
+        None,
         references,
+        None,
     )
     .unwrap();
     ProjectContentMapV1::<T>::get(external_id)
@@ -262,17 +267,31 @@ fn init_review<T: Config>(
     let author: T::AccountId = whitelisted_caller();
     let content: T::Hash = T::Hashing::hash("review content".as_bytes());
     let domains: Vec<DomainId> = domains.iter().copied().collect();
-    let assessment_model: u32 = 10;
-    let weight: Vec<u8> = vec![];
+    let assessment_model: AssessmentModelId = 10;
     let project_content_external_id: ProjectContentId = project_content.external_id;
+    if !AssessmentModelMapV1::contains_key(assessment_model) {
+        Pallet::<T>::add_assessment_model(
+            RawOrigin::Root.into(),
+            assessment_model,
+            b"model".to_vec(),
+            vec![AssessmentCriterion {
+                name: b"novelty".to_vec(),
+                min: Permill::zero(),
+                max: Permill::one(),
+            }],
+        )
+        .unwrap();
+    }
     ReviewOf::<T> {
         external_id,
         author,
         content,
         domains,
         assessment_model,
-        weight,
+        weight: vec![],
         project_content_external_id,
+        project_content_version: 0,
+        scores: vec![Permill::from_percent(50)],
     }
 }
 
@@ -283,8 +302,10 @@ fn _create_review<T: Config>(review: ReviewOf<T>) -> ReviewOf<T> {
         content,
         domains,
         assessment_model,
-        weight,
         project_content_external_id,
+        project_content_version,
+        scores,
+        ..
     } = review;
     Pallet::<T>::create_review(
         RawOrigin::Signed(author.clone()).into(),
@@ -293,8 +314,9 @@ fn _create_review<T: Config>(review: ReviewOf<T>) -> ReviewOf<T> {
         content,
         domains,
         assessment_model,
-        weight,
+        scores,
         project_content_external_id,
+        Some(project_content_version),
     )
     .unwrap();
     ReviewMapV1::<T>::get(external_id)
@@ -359,6 +381,7 @@ benchmarks! {
             content,
             authors,
             references,
+            ..
         } = project_content;
 
         let authors: Vec<DeipAccountIdOf<T>> = authors.into_iter()
@@ -373,7 +396,9 @@ benchmarks! {
             description,
             content,
             authors,
-            references)
+            None,
+            references,
+            None)
     verify {
         assert_last_event::<T>(Event::<T>::ProjectContnetCreated(
             team_id,
@@ -496,8 +521,9 @@ benchmarks! {
             review.content,
             review.domains.clone(),
             review.assessment_model,
-            review.weight.clone(),
-            review.project_content_external_id)
+            review.scores.clone(),
+            review.project_content_external_id,
+            None)
     verify {
         assert_last_event::<T>(Event::<T>::ReviewCreated(
             review.author.clone(),
@@ -0,0 +1,123 @@
+use super::*;
+use frame_support::{traits::ExistenceRequirement, transactional};
+use sp_runtime::traits::Saturating;
+
+impl<T: Config> Module<T> {
+    pub(super) fn update_project_content_impl(
+        account: T::AccountId,
+        external_id: ProjectContentId,
+        description: T::Hash,
+        content: T::Hash,
+        authors: Vec<T::DeipAccountId>,
+        author_shares: Option<Vec<Perbill>>,
+    ) -> DispatchResult {
+        ensure!(authors.len() <= T::MaxContentAuthors::get() as usize, Error::<T>::TooManyAuthors);
+        let author_shares = Self::ensure_author_shares(authors.len(), author_shares)?;
+
+        let version = ProjectContentMapV1::<T>::try_mutate_exists(
+            external_id,
+            |maybe_content| -> Result<u32, DispatchError> {
+                let project_content =
+                    maybe_content.as_mut().ok_or(Error::<T>::NoSuchProjectContent)?;
+
+                // The project may have been transferred to another team since the content was created
+                let project = ProjectMapV1::<T>::try_get(project_content.project_external_id)
+                    .map_err(|_| Error::<T>::NoSuchProject)?;
+                ensure!(Self::project_owner(&project) == account, Error::<T>::NoPermission);
+                ensure!(!project.is_archived, Error::<T>::ProjectIsArchived);
+
+                project_content.version = project_content.version.saturating_add(1);
+                project_content.description = description;
+                project_content.content = content;
+                project_content.authors = authors.into_iter().map(Into::into).collect();
+                project_content.author_shares = author_shares;
+
+                let version = ProjectContentVersionOf::<T> {
+                    description,
+                    content,
+                    authors: project_content.authors.clone(),
+                };
+                Self::reserve_deposit(
+                    &account,
+                    Deposited::ProjectContent(external_id, project_content.version),
+                    version.encoded_size(),
+                )?;
+
+                ProjectContentVersionMapV1::<T>::insert(external_id, project_content.version, version);
+
+                Ok(project_content.version)
+            },
+        )?;
+
+        Self::deposit_event(RawEvent::ProjectContentUpdated(account, external_id, version));
+
+        Ok(())
+    }
+
+    /// Checks that the shares match the authors and sum up to 100%.
+    /// No shares mean the authors contributed equally.
+    pub(super) fn ensure_author_shares(
+        authors: usize,
+        author_shares: Option<Vec<Perbill>>,
+    ) -> Result<Vec<Perbill>, DispatchError> {
+        let shares = match author_shares {
+            None => return Ok(Vec::new()),
+            Some(shares) => shares,
+        };
+
+        ensure!(shares.len() == authors, Error::<T>::AuthorSharesMismatchAuthors);
+        let total = shares.iter().map(|share| share.deconstruct() as u64).sum::<u64>();
+        ensure!(total == Perbill::one().deconstruct() as u64, Error::<T>::AuthorSharesNotComplete);
+
+        Ok(shares)
+    }
+
+    /// Splits the `amount` among the content authors by their shares.
+    /// The rounding remainder goes to the first author.
+    pub fn split_content_payment(
+        content_id: &ProjectContentId,
+        amount: BalanceOf<T>,
+    ) -> Result<Vec<(T::AccountId, BalanceOf<T>)>, DispatchError> {
+        let content = ProjectContentMapV1::<T>::try_get(content_id)
+            .map_err(|_| Error::<T>::NoSuchProjectContent)?;
+        ensure!(!content.authors.is_empty(), Error::<T>::ProjectContentHasNoAuthors);
+
+        let equal_share = Perbill::from_rational(1, content.authors.len() as u32);
+        let mut split: Vec<(T::AccountId, BalanceOf<T>)> = content
+            .authors
+            .into_iter()
+            .enumerate()
+            .map(|(i, author)| {
+                let share = content.author_shares.get(i).copied().unwrap_or(equal_share);
+                (author, share * amount)
+            })
+            .collect();
+
+        let distributed = split
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |total, (_, part)| total.saturating_add(*part));
+        split[0].1 = split[0].1.saturating_add(amount.saturating_sub(distributed));
+
+        Ok(split)
+    }
+}
+
+impl<T: Config> ContentRoyaltyT<T::AccountId, BalanceOf<T>> for Module<T> {
+    #[transactional]
+    fn pay_content_royalty(
+        content_id: &ProjectContentId,
+        payer: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        for (author, part) in Self::split_content_payment(content_id, amount)? {
+            if part.is_zero() || &author == payer {
+                continue
+            }
+            T::Currency::transfer(payer, &author, part, ExistenceRequirement::AllowDeath)?;
+        }
+
+        Self::deposit_event(RawEvent::ContentRoyaltyPaid(*content_id, payer.clone(), amount));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use super::*;
+
+/// Well-known licenses by their SPDX identifiers
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum KnownLicense {
+    /// CC0-1.0
+    Cc0,
+    /// CC-BY-4.0
+    CcBy,
+    /// CC-BY-SA-4.0
+    CcBySa,
+    /// CC-BY-ND-4.0
+    CcByNd,
+    /// CC-BY-NC-4.0
+    CcByNc,
+    /// CC-BY-NC-SA-4.0
+    CcByNcSa,
+    /// CC-BY-NC-ND-4.0
+    CcByNcNd,
+    /// MIT
+    Mit,
+    /// Apache-2.0
+    Apache2,
+    /// GPL-3.0-only
+    Gpl3,
+}
+
+/// What the license lets others do with the Project Content
+#[derive(Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct LicenseRights {
+    /// The content may be copied and redistributed
+    pub reuse: bool,
+    /// Derivative works (remixes) may be created
+    pub remix: bool,
+    /// The content may be used commercially, e.g. sold
+    pub commercial: bool,
+    /// Credit to the authors is required
+    pub attribution: bool,
+    /// Derivative works must be distributed under the same terms
+    pub share_alike: bool,
+}
+
+impl LicenseRights {
+    /// Grants everything `other` grants and requires nothing `other` doesn't require
+    pub fn is_at_least_as_permissive_as(&self, other: &Self) -> bool {
+        (self.reuse || !other.reuse) &&
+            (self.remix || !other.remix) &&
+            (self.commercial || !other.commercial) &&
+            (!self.attribution || other.attribution) &&
+            (!self.share_alike || other.share_alike)
+    }
+}
+
+/// Machine-readable license of the Project Content
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum ContentLicense<Hash> {
+    Known(KnownLicense),
+    /// Custom terms with the rights they grant
+    Custom { terms: Hash, rights: LicenseRights },
+}
+
+pub type ContentLicenseOf<T> = ContentLicense<HashOf<T>>;
+
+impl<Hash> ContentLicense<Hash> {
+    pub fn rights(&self) -> LicenseRights {
+        use KnownLicense::*;
+
+        let (reuse, remix, commercial, attribution, share_alike) = match self {
+            ContentLicense::Custom { rights, .. } => return *rights,
+            ContentLicense::Known(Cc0) => (true, true, true, false, false),
+            ContentLicense::Known(CcBy | Mit | Apache2) => (true, true, true, true, false),
+            ContentLicense::Known(CcBySa | Gpl3) => (true, true, true, true, true),
+            ContentLicense::Known(CcByNd) => (true, false, true, true, false),
+            ContentLicense::Known(CcByNc) => (true, true, false, true, false),
+            ContentLicense::Known(CcByNcSa) => (true, true, false, true, true),
+            ContentLicense::Known(CcByNcNd) => (true, false, false, true, false),
+        };
+        LicenseRights { reuse, remix, commercial, attribution, share_alike }
+    }
+}
+
+impl<T: Config> Module<T> {
+    pub(super) fn set_project_content_license_impl(
+        account: T::AccountId,
+        external_id: ProjectContentId,
+        license: ContentLicenseOf<T>,
+    ) -> DispatchResult {
+        ProjectContentMapV1::<T>::try_mutate_exists(external_id, |maybe_content| -> DispatchResult {
+            let project_content = maybe_content.as_mut().ok_or(Error::<T>::NoSuchProjectContent)?;
+
+            let project = ProjectMapV1::<T>::try_get(project_content.project_external_id)
+                .map_err(|_| Error::<T>::NoSuchProject)?;
+            ensure!(Self::project_owner(&project) == account, Error::<T>::NoPermission);
+            ensure!(!project.is_archived, Error::<T>::ProjectIsArchived);
+
+            // Rights already granted to the licensees can't be revoked
+            if let Some(current) = &project_content.license {
+                ensure!(
+                    license.rights().is_at_least_as_permissive_as(&current.rights()),
+                    Error::<T>::ContentLicenseLessPermissive
+                );
+            }
+            project_content.license = Some(license);
+            Ok(())
+        })?;
+
+        Self::deposit_event(RawEvent::ProjectContentLicenseSet(external_id));
+
+        Ok(())
+    }
+}
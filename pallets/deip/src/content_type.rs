@@ -0,0 +1,108 @@
+use super::*;
+
+/// Unique Content Type reference
+pub type Id = u16;
+
+/// Type of the Project Content. Portals register their own types, e.g. tracks
+/// and albums for music or releases for software.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ContentType {
+    /// Reference for external world and uniques control
+    pub external_id: Id,
+    /// Human readable name of the type
+    pub name: Vec<u8>,
+    /// Content of this type finishes the Project: no content can be added afterwards
+    pub finalizes_project: bool,
+    /// Deprecated type can't be assigned to new Project Content
+    pub is_deprecated: bool,
+}
+
+impl From<ProjectContentType> for Id {
+    fn from(content_type: ProjectContentType) -> Self {
+        content_type as Id
+    }
+}
+
+/// Content types of the former `ProjectContentType` enum. They are registered
+/// at genesis and by the V10 migration with the enum indexes as ids.
+pub(super) fn builtin() -> Vec<ContentType> {
+    use ProjectContentType::*;
+
+    [
+        (Announcement, &b"Announcement"[..]),
+        (FinalResult, b"FinalResult"),
+        (MilestoneArticle, b"MilestoneArticle"),
+        (MilestoneBook, b"MilestoneBook"),
+        (MilestoneChapter, b"MilestoneChapter"),
+        (MilestoneCode, b"MilestoneCode"),
+        (MilestoneConferencePaper, b"MilestoneConferencePaper"),
+        (MilestoneCoverPage, b"MilestoneCoverPage"),
+        (MilestoneData, b"MilestoneData"),
+        (MilestoneExperimentFindings, b"MilestoneExperimentFindings"),
+        (MilestoneMethod, b"MilestoneMethod"),
+        (MilestoneNegativeResults, b"MilestoneNegativeResults"),
+        (MilestonePatent, b"MilestonePatent"),
+        (MilestonePoster, b"MilestonePoster"),
+        (MilestonePreprint, b"MilestonePreprint"),
+        (MilestonePresentation, b"MilestonePresentation"),
+        (MilestoneRawData, b"MilestoneRawData"),
+        (MilestoneResearchProposal, b"MilestoneResearchProposal"),
+        (MilestoneTechnicalReport, b"MilestoneTechnicalReport"),
+        (MilestoneThesis, b"MilestoneThesis"),
+    ]
+    .iter()
+    .map(|(content_type, name)| ContentType {
+        external_id: content_type.clone().into(),
+        name: name.to_vec(),
+        finalizes_project: *content_type == FinalResult,
+        is_deprecated: false,
+    })
+    .collect()
+}
+
+impl<T: Config> Module<T> {
+    pub(super) fn add_content_type_impl(
+        external_id: Id,
+        name: Vec<u8>,
+        finalizes_project: bool,
+    ) -> DispatchResult {
+        ensure!(
+            !ContentTypeMapV1::contains_key(external_id),
+            Error::<T>::ContentTypeAlreadyExists
+        );
+        ensure!(
+            name.len() <= T::MaxContentTypeNameLength::get() as usize,
+            Error::<T>::ContentTypeNameTooLong
+        );
+
+        ContentTypeMapV1::insert(
+            external_id,
+            ContentType { external_id, name, finalizes_project, is_deprecated: false },
+        );
+
+        Self::deposit_event(RawEvent::ContentTypeAdded(external_id));
+        Ok(())
+    }
+
+    pub(super) fn deprecate_content_type_impl(external_id: Id) -> DispatchResult {
+        ContentTypeMapV1::try_mutate_exists(external_id, |maybe_type| -> DispatchResult {
+            let content_type = maybe_type.as_mut().ok_or(Error::<T>::NoSuchContentType)?;
+            ensure!(!content_type.is_deprecated, Error::<T>::ContentTypeIsDeprecated);
+            content_type.is_deprecated = true;
+            Ok(())
+        })?;
+
+        Self::deposit_event(RawEvent::ContentTypeDeprecated(external_id));
+        Ok(())
+    }
+
+    /// Checks that the Content Type exists and can be assigned to new content
+    pub(super) fn ensure_content_type_is_active(external_id: Id) -> Result<ContentType, DispatchError> {
+        let content_type =
+            ContentTypeMapV1::try_get(external_id).map_err(|_| Error::<T>::NoSuchContentType)?;
+        ensure!(!content_type.is_deprecated, Error::<T>::ContentTypeIsDeprecated);
+        Ok(content_type)
+    }
+}
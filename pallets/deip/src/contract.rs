@@ -1,14 +1,39 @@
 use crate::*;
 
+use deip_serializable_u128::SerializableAtLeast32BitUnsigned;
+use frame_support::traits::{BalanceStatus, ExistenceRequirement};
+use frame_system::offchain::SubmitTransaction;
+use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, SaturatedConversion, Saturating, Zero};
 use sp_std::vec;
 
 pub type Id = H160;
 
+/// Fee paid by the licensee. Serialized as a string to keep `u128` precision.
+pub type Price<Balance> = SerializableAtLeast32BitUnsigned<Balance>;
+
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
-pub enum Terms {
+pub enum Terms<AccountId, Balance: Clone + AtLeast32BitUnsigned> {
     GenericContractAgreement,
+    /// License to use the `source` project. The project team is the licenser,
+    /// the other party is the licensee who pays `price` on acceptance.
+    LicenseAgreement { source: ProjectId, price: Price<Balance> },
+    /// Contract with escrowed payments. The creator is the payer who locks
+    /// the total amount of the `milestones` on creation.
+    EscrowContractAgreement { milestones: Vec<Milestone<AccountId, Price<Balance>>> },
+}
+
+pub type TermsOf<T> = Terms<AccountIdOf<T>, BalanceOf<T>>;
+
+/// Payment released to the `payee` once all `approvers` approve the milestone
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Milestone<AccountId, Asset> {
+    pub payee: AccountId,
+    pub amount: Asset,
+    pub approvers: Vec<AccountId>,
 }
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
@@ -16,19 +41,25 @@ pub enum Terms {
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum IndexTerms {
     GenericContractAgreement,
+    LicenseAgreement,
+    EscrowContractAgreement,
 }
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
-pub enum Agreement<AccountId, Hash, Moment> {
+pub enum Agreement<AccountId, Hash, Moment, Balance: Clone + AtLeast32BitUnsigned> {
     None,
     GenericContract(GenericContractStatus<AccountId, Hash, Moment>),
+    License(LicenseStatus<AccountId, Hash, Moment, Price<Balance>>),
+    Escrow(EscrowStatus<AccountId, Hash, Moment, Price<Balance>>),
 }
 
-pub type AgreementOf<T> = Agreement<AccountIdOf<T>, HashOf<T>, MomentOf<T>>;
+pub type AgreementOf<T> = Agreement<AccountIdOf<T>, HashOf<T>, MomentOf<T>, BalanceOf<T>>;
 
-impl<AccountId, Hash, Moment> Default for Agreement<AccountId, Hash, Moment> {
+impl<AccountId, Hash, Moment, Balance: Clone + AtLeast32BitUnsigned> Default
+    for Agreement<AccountId, Hash, Moment, Balance>
+{
     fn default() -> Self {
         Agreement::None
     }
@@ -49,7 +80,9 @@ pub struct License<AccountId, Hash, Moment, Asset> {
     pub(crate) price: Asset,
 }
 
-pub type LicenseOf<T> = License<AccountIdOf<T>, HashOf<T>, MomentOf<T>, /*DeipAsset<T>*/ ()>;
+pub type LicenseOf<T> = License<AccountIdOf<T>, HashOf<T>, MomentOf<T>, Price<BalanceOf<T>>>;
+pub type LicenseStatusOf<T> =
+    LicenseStatus<AccountIdOf<T>, HashOf<T>, MomentOf<T>, Price<BalanceOf<T>>>;
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -59,6 +92,8 @@ pub enum LicenseStatus<AccountId, Hash, Moment, Asset> {
     SignedByLicenser(License<AccountId, Hash, Moment, Asset>),
     Signed(License<AccountId, Hash, Moment, Asset>),
     Rejected(License<AccountId, Hash, Moment, Asset>),
+    /// Not signed by both parties before the expiration time
+    Expired(License<AccountId, Hash, Moment, Asset>),
 }
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
@@ -85,6 +120,101 @@ pub enum GenericContractStatus<AccountId, Hash, Moment> {
     },
     Accepted(GenericContract<AccountId, Hash, Moment>),
     Rejected(GenericContract<AccountId, Hash, Moment>),
+    /// Not accepted by all parties before the expiration time
+    Expired(GenericContract<AccountId, Hash, Moment>),
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct EscrowMilestone<AccountId, Asset> {
+    pub(crate) milestone: Milestone<AccountId, Asset>,
+    pub(crate) approved_by: Vec<AccountId>,
+    pub(crate) is_released: bool,
+}
+
+/// Generic contract which `creator` pays for the milestones
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct EscrowContract<AccountId, Hash, Moment, Asset> {
+    pub(crate) contract: GenericContract<AccountId, Hash, Moment>,
+    pub(crate) milestones: Vec<EscrowMilestone<AccountId, Asset>>,
+}
+
+pub type EscrowContractOf<T> =
+    EscrowContract<AccountIdOf<T>, HashOf<T>, MomentOf<T>, Price<BalanceOf<T>>>;
+pub type EscrowStatusOf<T> =
+    EscrowStatus<AccountIdOf<T>, HashOf<T>, MomentOf<T>, Price<BalanceOf<T>>>;
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum EscrowStatus<AccountId, Hash, Moment, Asset> {
+    PartiallyAccepted {
+        contract: EscrowContract<AccountId, Hash, Moment, Asset>,
+        accepted_by: Vec<AccountId>,
+    },
+    /// Accepted by all parties, the milestones are in progress
+    Accepted(EscrowContract<AccountId, Hash, Moment, Asset>),
+    /// All milestones are released
+    Completed(EscrowContract<AccountId, Hash, Moment, Asset>),
+    Rejected(EscrowContract<AccountId, Hash, Moment, Asset>),
+    /// Expired before all milestones were released
+    Expired(EscrowContract<AccountId, Hash, Moment, Asset>),
+}
+
+impl<AccountId, Hash, Moment: PartialOrd, Balance: Clone + AtLeast32BitUnsigned>
+    Agreement<AccountId, Hash, Moment, Balance>
+{
+    /// Expiration time of the agreement that still waits for the parties:
+    /// for acceptance or, in the escrow, for the milestones
+    pub(crate) fn pending_expiration_time(&self) -> Option<&Moment> {
+        match self {
+            Agreement::GenericContract(GenericContractStatus::PartiallyAccepted {
+                contract, ..
+            }) => contract.expiration_time.as_ref(),
+            Agreement::License(LicenseStatus::Unsigned(license)) |
+            Agreement::License(LicenseStatus::SignedByLicenser(license)) =>
+                license.expiration_time.as_ref(),
+            Agreement::Escrow(EscrowStatus::PartiallyAccepted { contract, .. }) |
+            Agreement::Escrow(EscrowStatus::Accepted(contract)) =>
+                contract.contract.expiration_time.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Expiration time set on creation, whatever the state of the agreement
+    pub(crate) fn expiration_time(&self) -> Option<&Moment> {
+        match self {
+            Agreement::None => None,
+            Agreement::GenericContract(status) => match status {
+                GenericContractStatus::PartiallyAccepted { contract, .. } |
+                GenericContractStatus::Accepted(contract) |
+                GenericContractStatus::Rejected(contract) |
+                GenericContractStatus::Expired(contract) => contract.expiration_time.as_ref(),
+            },
+            Agreement::License(status) => match status {
+                LicenseStatus::Unsigned(license) |
+                LicenseStatus::SignedByLicenser(license) |
+                LicenseStatus::Signed(license) |
+                LicenseStatus::Rejected(license) |
+                LicenseStatus::Expired(license) => license.expiration_time.as_ref(),
+            },
+            Agreement::Escrow(status) => match status {
+                EscrowStatus::PartiallyAccepted { contract, .. } |
+                EscrowStatus::Accepted(contract) |
+                EscrowStatus::Completed(contract) |
+                EscrowStatus::Rejected(contract) |
+                EscrowStatus::Expired(contract) => contract.contract.expiration_time.as_ref(),
+            },
+        }
+    }
+
+    /// Pending agreement which expiration time has come.
+    pub(crate) fn is_expired(&self, now: &Moment) -> bool {
+        matches!(self.pending_expiration_time(), Some(e) if e <= now)
+    }
 }
 
 impl<T: Config> Module<T> {
@@ -96,7 +226,7 @@ impl<T: Config> Module<T> {
         hash: HashOf<T>,
         activation_time: Option<MomentOf<T>>,
         expiration_time: Option<MomentOf<T>>,
-        terms: Terms,
+        terms: TermsOf<T>,
     ) -> DispatchResultWithPostInfo {
         ensure!(account == creator, Error::<T>::NoPermission);
         ensure!(!parties.is_empty(), Error::<T>::ContractAgreementNoParties);
@@ -135,6 +265,25 @@ impl<T: Config> Module<T> {
                 activation_time,
                 expiration_time,
             ),
+            Terms::LicenseAgreement { source, price } => Self::create_project_license(
+                id,
+                creator,
+                parties,
+                hash,
+                activation_time,
+                expiration_time,
+                source,
+                price,
+            ),
+            Terms::EscrowContractAgreement { milestones } => Self::create_escrow_contract(
+                id,
+                creator,
+                parties,
+                hash,
+                activation_time,
+                expiration_time,
+                milestones,
+            ),
         }
     }
 
@@ -150,6 +299,8 @@ impl<T: Config> Module<T> {
 
         match agreement {
             Agreement::GenericContract(status) => Self::accept_generic_contract(party, status),
+            Agreement::License(status) => Self::accept_project_license(party, status),
+            Agreement::Escrow(status) => Self::accept_escrow_contract(party, status),
             Agreement::None => Err(Error::<T>::ContractAgreementWrongAgreement.into()),
         }
     }
@@ -167,6 +318,8 @@ impl<T: Config> Module<T> {
         match agreement {
             Agreement::None => Err(Error::<T>::ContractAgreementWrongAgreement.into()),
             Agreement::GenericContract(status) => Self::reject_generic_contract(party, status),
+            Agreement::License(status) => Self::reject_project_license(party, status),
+            Agreement::Escrow(status) => Self::reject_escrow_contract(party, status),
         }
     }
 
@@ -181,7 +334,7 @@ impl<T: Config> Module<T> {
         let contract =
             GenericContract { id, creator, parties, hash, activation_time, expiration_time };
 
-        ContractAgreementMap::<T>::insert(
+        Self::put_contract_agreement(
             id,
             Agreement::GenericContract(GenericContractStatus::PartiallyAccepted {
                 contract,
@@ -203,6 +356,7 @@ impl<T: Config> Module<T> {
             GenericContractStatus::Rejected(_) => Err(Error::<T>::ContractAgreementRejected.into()),
             GenericContractStatus::Accepted(_) =>
                 Err(Error::<T>::ContractAgreementAlreadyAccepted.into()),
+            GenericContractStatus::Expired(_) => Err(Error::<T>::ContractAgreementExpired.into()),
             GenericContractStatus::PartiallyAccepted { contract, accepted_by } =>
                 Self::accept_generic_contract_impl(party, contract, accepted_by),
         }
@@ -217,10 +371,15 @@ impl<T: Config> Module<T> {
 
         ensure!(contract.parties.contains(&party), Error::<T>::ContractAgreementPartyIsNotListed);
 
+        if let Some(e) = contract.expiration_time {
+            let now = pallet_timestamp::Pallet::<T>::get();
+            ensure!(now < e, Error::<T>::ContractAgreementExpired);
+        }
+
         accepted_by.push(party.clone());
         let id = contract.id;
         if accepted_by.len() == contract.parties.len() {
-            ContractAgreementMap::<T>::insert(
+            Self::put_contract_agreement(
                 id,
                 Agreement::GenericContract(GenericContractStatus::Accepted(contract)),
             );
@@ -230,7 +389,7 @@ impl<T: Config> Module<T> {
             Ok(Some(T::DeipWeightInfo::accept_contract_agreement_generic_contract_finalized())
                 .into())
         } else {
-            ContractAgreementMap::<T>::insert(
+            Self::put_contract_agreement(
                 id,
                 Agreement::GenericContract(GenericContractStatus::PartiallyAccepted {
                     contract,
@@ -254,6 +413,7 @@ impl<T: Config> Module<T> {
             GenericContractStatus::Rejected(_) => Err(Error::<T>::ContractAgreementRejected.into()),
             GenericContractStatus::Accepted(_) =>
                 Err(Error::<T>::ContractAgreementAlreadyAccepted.into()),
+            GenericContractStatus::Expired(_) => Err(Error::<T>::ContractAgreementExpired.into()),
 
             GenericContractStatus::PartiallyAccepted { contract, accepted_by } => {
                 ensure!(
@@ -267,7 +427,7 @@ impl<T: Config> Module<T> {
                 );
 
                 let id = contract.id;
-                ContractAgreementMap::<T>::insert(
+                Self::put_contract_agreement(
                     id,
                     Agreement::GenericContract(GenericContractStatus::Rejected(contract)),
                 );
@@ -278,4 +438,463 @@ impl<T: Config> Module<T> {
             },
         }
     }
+
+    fn create_project_license(
+        id: Id,
+        creator: AccountIdOf<T>,
+        parties: Vec<T::AccountId>,
+        hash: HashOf<T>,
+        activation_time: Option<MomentOf<T>>,
+        expiration_time: Option<MomentOf<T>>,
+        project_id: ProjectId,
+        price: Price<BalanceOf<T>>,
+    ) -> DispatchResultWithPostInfo {
+        let project =
+            ProjectMapV1::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+
+        ensure!(!project.is_archived, Error::<T>::ProjectIsArchived);
+        ensure!(!price.0.is_zero(), Error::<T>::ContractAgreementFeeMustBePositive);
+        ensure!(parties.len() == 2, Error::<T>::ContractAgreementLicenseTwoPartiesRequired);
+
+        let licenser = Self::project_owner(&project);
+        ensure!(
+            parties.contains(&licenser),
+            Error::<T>::ContractAgreementLicenseProjectTeamIsNotListedInParties
+        );
+        let licensee = parties
+            .into_iter()
+            .find(|p| p != &licenser)
+            .ok_or(Error::<T>::ContractAgreementLicenseTwoPartiesRequired)?;
+
+        let license = License {
+            id,
+            creator,
+            licenser,
+            licensee,
+            hash,
+            activation_time,
+            expiration_time,
+            project_id,
+            price,
+        };
+
+        Self::put_contract_agreement(id, Agreement::License(LicenseStatus::Unsigned(license)));
+        ContractAgreementIdByType::insert(IndexTerms::LicenseAgreement, id, ());
+        ContractAgreementIdByProjectIdV1::insert(project_id, id, ());
+
+        Self::deposit_event(RawEvent::ContractAgreementCreated(id));
+
+        Ok(Some(T::DeipWeightInfo::create_contract_agreement_project_license()).into())
+    }
+
+    fn ensure_license_is_active(license: &LicenseOf<T>) -> DispatchResult {
+        let now = pallet_timestamp::Pallet::<T>::get();
+
+        if let Some(s) = license.activation_time {
+            ensure!(s <= now, Error::<T>::ContractAgreementLicenseIsNotActive);
+        }
+
+        if let Some(e) = license.expiration_time {
+            ensure!(now < e, Error::<T>::ContractAgreementLicenseExpired);
+        }
+
+        Ok(())
+    }
+
+    fn accept_project_license(
+        party: AccountIdOf<T>,
+        status: LicenseStatusOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        match status {
+            LicenseStatus::Rejected(_) => Err(Error::<T>::ContractAgreementRejected.into()),
+            LicenseStatus::Signed(_) => Err(Error::<T>::ContractAgreementAlreadyAccepted.into()),
+            LicenseStatus::Expired(_) => Err(Error::<T>::ContractAgreementExpired.into()),
+            LicenseStatus::Unsigned(license) => {
+                ensure!(
+                    license.licenser == party,
+                    Error::<T>::ContractAgreementLicensePartyIsNotLicenser
+                );
+                Self::ensure_license_is_active(&license)?;
+
+                let id = license.id;
+                Self::put_contract_agreement(
+                    id,
+                    Agreement::License(LicenseStatus::SignedByLicenser(license)),
+                );
+
+                Self::deposit_event(RawEvent::ContractAgreementAccepted(id, party));
+
+                Ok(Some(T::DeipWeightInfo::accept_contract_agreement_project_license_unsigned())
+                    .into())
+            },
+            LicenseStatus::SignedByLicenser(license) => {
+                ensure!(
+                    license.licensee == party,
+                    Error::<T>::ContractAgreementLicensePartyIsNotLicensee
+                );
+                Self::ensure_license_is_active(&license)?;
+
+                let fee = license.price.0.clone();
+                ensure!(
+                    T::Currency::free_balance(&license.licensee) >= fee,
+                    Error::<T>::ContractAgreementLicenseNotEnoughBalance
+                );
+                T::Currency::transfer(
+                    &license.licensee,
+                    &license.licenser,
+                    fee,
+                    ExistenceRequirement::AllowDeath,
+                )
+                .map_err(|_| Error::<T>::ContractAgreementLicenseFailedToChargeFee)?;
+
+                let id = license.id;
+                Self::put_contract_agreement(
+                    id,
+                    Agreement::License(LicenseStatus::Signed(license)),
+                );
+
+                Self::deposit_event(RawEvent::ContractAgreementAccepted(id, party));
+                Self::deposit_event(RawEvent::ContractAgreementFinalized(id));
+
+                Ok(Some(
+                    T::DeipWeightInfo::accept_contract_agreement_project_license_signed_by_licenser(),
+                )
+                .into())
+            },
+        }
+    }
+
+    fn reject_project_license(party: AccountIdOf<T>, status: LicenseStatusOf<T>) -> DispatchResult {
+        match status {
+            LicenseStatus::Rejected(_) => Err(Error::<T>::ContractAgreementRejected.into()),
+            LicenseStatus::Signed(_) => Err(Error::<T>::ContractAgreementAlreadyAccepted.into()),
+            LicenseStatus::Expired(_) => Err(Error::<T>::ContractAgreementExpired.into()),
+            LicenseStatus::Unsigned(license) | LicenseStatus::SignedByLicenser(license) => {
+                ensure!(
+                    license.licenser == party || license.licensee == party,
+                    Error::<T>::ContractAgreementPartyIsNotListed
+                );
+
+                let id = license.id;
+                ContractAgreementIdByProjectIdV1::remove(license.project_id, id);
+                Self::put_contract_agreement(
+                    id,
+                    Agreement::License(LicenseStatus::Rejected(license)),
+                );
+
+                Self::deposit_event(RawEvent::ContractAgreementRejected(id, party));
+
+                Ok(())
+            },
+        }
+    }
+
+    fn expire_contract_agreement_impl(id: Id) -> DispatchResult {
+        let agreement = ContractAgreementMap::<T>::try_get(id)
+            .map_err(|_| Error::<T>::ContractAgreementNotFound)?;
+
+        let now = pallet_timestamp::Pallet::<T>::get();
+        ensure!(agreement.is_expired(&now), Error::<T>::ContractAgreementNotExpired);
+
+        let expired = match agreement {
+            Agreement::GenericContract(GenericContractStatus::PartiallyAccepted {
+                contract, ..
+            }) => Agreement::GenericContract(GenericContractStatus::Expired(contract)),
+            Agreement::License(LicenseStatus::Unsigned(license)) |
+            Agreement::License(LicenseStatus::SignedByLicenser(license)) => {
+                ContractAgreementIdByProjectIdV1::remove(license.project_id, id);
+                Agreement::License(LicenseStatus::Expired(license))
+            },
+            Agreement::Escrow(EscrowStatus::PartiallyAccepted { contract, .. }) |
+            Agreement::Escrow(EscrowStatus::Accepted(contract)) => {
+                Self::refund_escrow(&contract);
+                Agreement::Escrow(EscrowStatus::Expired(contract))
+            },
+            _ => return Err(Error::<T>::ContractAgreementNotExpired.into()),
+        };
+        Self::put_contract_agreement(id, expired);
+
+        Self::deposit_event(RawEvent::ContractAgreementExpired(id));
+
+        Ok(())
+    }
+
+    pub(super) fn expire_contract_agreements_impl(ids: Vec<Id>) -> DispatchResult {
+        ensure!(
+            ids.len() <= T::MaxExpiredContractAgreements::get() as usize,
+            Error::<T>::ContractAgreementTooManyToExpire
+        );
+        for id in ids {
+            Self::expire_contract_agreement_impl(id)?;
+        }
+        Ok(())
+    }
+
+    /// Submits the pending agreements which expiration time has come. The expiration index
+    /// is ordered by time, so only the due entries are read.
+    pub(super) fn expire_contract_agreements_offchain() {
+        let now = Self::expiration_key(&pallet_timestamp::Pallet::<T>::get());
+
+        let ids = ContractAgreementIdByExpirationTime::iter()
+            .take_while(|(expiration_time, ..)| *expiration_time <= now)
+            .map(|(_, id, _)| id)
+            .take(T::MaxExpiredContractAgreements::get() as usize)
+            .collect::<Vec<_>>();
+        if ids.is_empty() {
+            return
+        }
+
+        let call = Call::<T>::expire_contract_agreements(ids);
+        let submit = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into());
+        frame_support::log::debug!("submit expire_contract_agreements: {}", submit.is_ok());
+    }
+
+    /// Key of the expiration index. Big-endian, so the index is iterated in time order
+    pub(crate) fn expiration_key(moment: &MomentOf<T>) -> [u8; 8] {
+        (*moment).saturated_into::<u64>().to_be_bytes()
+    }
+
+    /// Stores the agreement and keeps it in the expiration index while it's pending
+    fn put_contract_agreement(id: Id, agreement: AgreementOf<T>) {
+        if let Some(e) = agreement.expiration_time() {
+            let key = Self::expiration_key(e);
+            if agreement.pending_expiration_time().is_some() {
+                ContractAgreementIdByExpirationTime::insert(key, id, ());
+            } else {
+                ContractAgreementIdByExpirationTime::remove(key, id);
+            }
+        }
+        ContractAgreementMap::<T>::insert(id, agreement);
+    }
+
+    fn create_escrow_contract(
+        id: Id,
+        creator: AccountIdOf<T>,
+        parties: Vec<T::AccountId>,
+        hash: HashOf<T>,
+        activation_time: Option<MomentOf<T>>,
+        expiration_time: Option<MomentOf<T>>,
+        milestones: Vec<Milestone<AccountIdOf<T>, Price<BalanceOf<T>>>>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(!milestones.is_empty(), Error::<T>::ContractAgreementEscrowNoMilestones);
+        ensure!(
+            milestones.len() <= T::MaxContractMilestones::get() as usize,
+            Error::<T>::ContractAgreementEscrowTooMuchMilestones
+        );
+        ensure!(parties.contains(&creator), Error::<T>::ContractAgreementPartyIsNotListed);
+
+        let mut total = BalanceOf::<T>::zero();
+        for milestone in &milestones {
+            ensure!(!milestone.amount.0.is_zero(), Error::<T>::ContractAgreementFeeMustBePositive);
+            ensure!(
+                parties.contains(&milestone.payee),
+                Error::<T>::ContractAgreementPartyIsNotListed
+            );
+            ensure!(
+                !milestone.approvers.is_empty() &&
+                    milestone.approvers.iter().all(|a| parties.contains(a)),
+                Error::<T>::ContractAgreementEscrowWrongApprovers
+            );
+            total = total
+                .checked_add(&milestone.amount.0)
+                .ok_or(Error::<T>::ContractAgreementEscrowNotEnoughBalance)?;
+        }
+
+        T::Currency::reserve(&creator, total)
+            .map_err(|_| Error::<T>::ContractAgreementEscrowNotEnoughBalance)?;
+
+        let milestones_count = milestones.len() as u32;
+        let milestones = milestones
+            .into_iter()
+            .map(|mut milestone| {
+                milestone.approvers.sort();
+                milestone.approvers.dedup();
+                EscrowMilestone { milestone, approved_by: vec![], is_released: false }
+            })
+            .collect();
+        let contract = EscrowContract {
+            contract: GenericContract {
+                id,
+                creator,
+                parties,
+                hash,
+                activation_time,
+                expiration_time,
+            },
+            milestones,
+        };
+
+        Self::put_contract_agreement(
+            id,
+            Agreement::Escrow(EscrowStatus::PartiallyAccepted { contract, accepted_by: vec![] }),
+        );
+        ContractAgreementIdByType::insert(IndexTerms::EscrowContractAgreement, id, ());
+
+        Self::deposit_event(RawEvent::ContractAgreementCreated(id));
+
+        Ok(Some(T::DeipWeightInfo::create_contract_agreement_escrow_contract(milestones_count))
+            .into())
+    }
+
+    fn accept_escrow_contract(
+        party: AccountIdOf<T>,
+        status: EscrowStatusOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let (contract, mut accepted_by) = match status {
+            EscrowStatus::PartiallyAccepted { contract, accepted_by } => (contract, accepted_by),
+            EscrowStatus::Rejected(_) => return Err(Error::<T>::ContractAgreementRejected.into()),
+            EscrowStatus::Expired(_) => return Err(Error::<T>::ContractAgreementExpired.into()),
+            EscrowStatus::Accepted(_) | EscrowStatus::Completed(_) =>
+                return Err(Error::<T>::ContractAgreementAlreadyAccepted.into()),
+        };
+
+        ensure!(!accepted_by.contains(&party), Error::<T>::ContractAgreementAlreadyAcceptedByParty);
+        ensure!(
+            contract.contract.parties.contains(&party),
+            Error::<T>::ContractAgreementPartyIsNotListed
+        );
+        if let Some(e) = contract.contract.expiration_time {
+            let now = pallet_timestamp::Pallet::<T>::get();
+            ensure!(now < e, Error::<T>::ContractAgreementExpired);
+        }
+
+        accepted_by.push(party.clone());
+        let id = contract.contract.id;
+        let is_finalized = accepted_by.len() == contract.contract.parties.len();
+        let status = if is_finalized {
+            EscrowStatus::Accepted(contract)
+        } else {
+            EscrowStatus::PartiallyAccepted { contract, accepted_by }
+        };
+        Self::put_contract_agreement(id, Agreement::Escrow(status));
+
+        Self::deposit_event(RawEvent::ContractAgreementAccepted(id, party));
+        if is_finalized {
+            Self::deposit_event(RawEvent::ContractAgreementFinalized(id));
+        }
+
+        Ok(Some(T::DeipWeightInfo::accept_contract_agreement_escrow_contract()).into())
+    }
+
+    fn reject_escrow_contract(party: AccountIdOf<T>, status: EscrowStatusOf<T>) -> DispatchResult {
+        match status {
+            EscrowStatus::Rejected(_) => Err(Error::<T>::ContractAgreementRejected.into()),
+            EscrowStatus::Expired(_) => Err(Error::<T>::ContractAgreementExpired.into()),
+            EscrowStatus::Accepted(_) | EscrowStatus::Completed(_) =>
+                Err(Error::<T>::ContractAgreementAlreadyAccepted.into()),
+            EscrowStatus::PartiallyAccepted { contract, accepted_by } => {
+                ensure!(
+                    !accepted_by.contains(&party),
+                    Error::<T>::ContractAgreementAlreadyAcceptedByParty
+                );
+                ensure!(
+                    contract.contract.parties.contains(&party),
+                    Error::<T>::ContractAgreementPartyIsNotListed
+                );
+
+                let id = contract.contract.id;
+                Self::refund_escrow(&contract);
+                Self::put_contract_agreement(
+                    id,
+                    Agreement::Escrow(EscrowStatus::Rejected(contract)),
+                );
+
+                Self::deposit_event(RawEvent::ContractAgreementRejected(id, party));
+
+                Ok(())
+            },
+        }
+    }
+
+    pub(super) fn approve_contract_milestone_impl(
+        account: AccountIdOf<T>,
+        id: Id,
+        milestone_idx: u32,
+    ) -> DispatchResult {
+        let agreement = ContractAgreementMap::<T>::try_get(id)
+            .map_err(|_| Error::<T>::ContractAgreementNotFound)?;
+
+        let mut contract = match agreement {
+            Agreement::Escrow(EscrowStatus::Accepted(contract)) => contract,
+            Agreement::Escrow(EscrowStatus::PartiallyAccepted { .. }) =>
+                return Err(Error::<T>::ContractAgreementEscrowNotAccepted.into()),
+            Agreement::Escrow(EscrowStatus::Expired(_)) =>
+                return Err(Error::<T>::ContractAgreementExpired.into()),
+            _ => return Err(Error::<T>::ContractAgreementWrongAgreement.into()),
+        };
+        if let Some(e) = contract.contract.expiration_time {
+            let now = pallet_timestamp::Pallet::<T>::get();
+            ensure!(now < e, Error::<T>::ContractAgreementExpired);
+        }
+
+        let payer = contract.contract.creator.clone();
+        let escrow_milestone = contract
+            .milestones
+            .get_mut(milestone_idx as usize)
+            .ok_or(Error::<T>::ContractAgreementEscrowNoSuchMilestone)?;
+
+        ensure!(!escrow_milestone.is_released, Error::<T>::ContractAgreementEscrowMilestoneReleased);
+        ensure!(
+            escrow_milestone.milestone.approvers.contains(&account),
+            Error::<T>::ContractAgreementEscrowNotApprover
+        );
+        ensure!(
+            !escrow_milestone.approved_by.contains(&account),
+            Error::<T>::ContractAgreementEscrowMilestoneAlreadyApproved
+        );
+
+        escrow_milestone.approved_by.push(account.clone());
+        let is_released =
+            escrow_milestone.approved_by.len() == escrow_milestone.milestone.approvers.len();
+        if is_released {
+            let amount = escrow_milestone.milestone.amount.0.clone();
+            // The reserve isn't named, so check it upfront to not release a part of the milestone
+            ensure!(
+                T::Currency::reserved_balance(&payer) >= amount,
+                Error::<T>::ContractAgreementEscrowReserveShortfall
+            );
+            let remainder = T::Currency::repatriate_reserved(
+                &payer,
+                &escrow_milestone.milestone.payee,
+                amount,
+                BalanceStatus::Free,
+            )?;
+            ensure!(remainder.is_zero(), Error::<T>::ContractAgreementEscrowReserveShortfall);
+            escrow_milestone.is_released = true;
+        }
+
+        let is_completed = contract.milestones.iter().all(|m| m.is_released);
+        let status = if is_completed {
+            EscrowStatus::Completed(contract)
+        } else {
+            EscrowStatus::Accepted(contract)
+        };
+        Self::put_contract_agreement(id, Agreement::Escrow(status));
+
+        Self::deposit_event(RawEvent::ContractMilestoneApproved(id, milestone_idx, account));
+        if is_released {
+            Self::deposit_event(RawEvent::ContractMilestoneReleased(id, milestone_idx));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the unreleased funds to the payer and reports the part
+    /// that is no longer reserved
+    fn refund_escrow(contract: &EscrowContractOf<T>) {
+        let unreleased = contract
+            .milestones
+            .iter()
+            .filter(|m| !m.is_released)
+            .fold(BalanceOf::<T>::zero(), |total, m| {
+                total.saturating_add(m.milestone.amount.0.clone())
+            });
+        let shortfall = T::Currency::unreserve(&contract.contract.creator, unreleased);
+        if !shortfall.is_zero() {
+            Self::deposit_event(RawEvent::ContractEscrowRefundShortfall(
+                contract.contract.id,
+                shortfall,
+            ));
+        }
+    }
 }
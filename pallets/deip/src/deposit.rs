@@ -0,0 +1,45 @@
+use super::*;
+use sp_runtime::traits::Saturating;
+
+/// Entity the storage deposit is reserved for
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum Deposited {
+    Project(ProjectId),
+    /// Version of the Project Content, the first version covers the content itself
+    ProjectContent(ProjectContentId, u32),
+    Review(ReviewId),
+}
+
+impl<T: Config> Module<T> {
+    /// Deposit for `bytes` of the stored entity
+    pub fn deposit_of(bytes: usize) -> BalanceOf<T> {
+        let bytes: BalanceOf<T> = (bytes as u32).into();
+        T::DepositBase::get().saturating_add(T::DepositPerByte::get().saturating_mul(bytes))
+    }
+
+    /// Reserves the deposit for the `entity` of the `bytes` encoded length from the `depositor`
+    pub(super) fn reserve_deposit(
+        depositor: &T::AccountId,
+        entity: Deposited,
+        bytes: usize,
+    ) -> DispatchResult {
+        let amount = Self::deposit_of(bytes);
+        if amount.is_zero() {
+            return Ok(())
+        }
+
+        T::Currency::reserve(depositor, amount).map_err(|_| Error::<T>::InsufficientDeposit)?;
+        StorageDepositV1::<T>::insert(entity, (depositor.clone(), amount));
+
+        Ok(())
+    }
+
+    /// Returns the deposit of the removed `entity` to its depositor
+    pub(super) fn release_deposit(entity: Deposited) {
+        if let Some((depositor, amount)) = StorageDepositV1::<T>::take(entity) {
+            T::Currency::unreserve(&depositor, amount);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::*;
+
+impl<T: Config> Module<T> {
+    pub(super) fn add_domain_impl(
+        external_id: DomainId,
+        name: Vec<u8>,
+        parent: Option<DomainId>,
+    ) -> DispatchResult {
+        let domain_count = DomainCount::get();
+        ensure!(domain_count < T::MaxDomains::get(), Error::<T>::DomainLimitReached);
+
+        // We don't want to add duplicate domains, so we check whether the potential new
+        // domain is already present in the list. Because the domains is stored as a hash
+        // map this check is constant time O(1)
+        ensure!(!Domains::contains_key(&external_id), Error::<T>::DomainAlreadyExists);
+        Self::ensure_domain_name(&name)?;
+
+        if let Some(parent_id) = &parent {
+            let parent = Domains::try_get(parent_id).map_err(|_| Error::<T>::NoSuchParentDomain)?;
+            ensure!(!parent.is_deprecated, Error::<T>::DomainIsDeprecated);
+            DomainIdByParentIdV1::insert(parent_id, external_id, ());
+        }
+
+        // Insert the new domain and emit the event
+        Domains::insert(&external_id, Domain { external_id, name, parent, is_deprecated: false });
+        DomainCount::put(domain_count + 1); // overflow check not necessary because of maximum
+
+        Self::deposit_event(RawEvent::DomainAdded(external_id));
+        Ok(())
+    }
+
+    pub(super) fn rename_domain_impl(domain_id: DomainId, name: Vec<u8>) -> DispatchResult {
+        Self::ensure_domain_name(&name)?;
+
+        Domains::try_mutate_exists(domain_id, |maybe_domain| -> DispatchResult {
+            let domain = maybe_domain.as_mut().ok_or(Error::<T>::DomainNotExists)?;
+            domain.name = name;
+            Ok(())
+        })?;
+
+        Self::deposit_event(RawEvent::DomainRenamed(domain_id));
+        Ok(())
+    }
+
+    pub(super) fn set_domain_parent_impl(
+        domain_id: DomainId,
+        parent: Option<DomainId>,
+    ) -> DispatchResultWithPostInfo {
+        let mut domain = Domains::try_get(domain_id).map_err(|_| Error::<T>::DomainNotExists)?;
+
+        // Walk up from the new parent to make sure the domain is not its ancestor
+        let mut depth: u32 = 0;
+        if let Some(parent_id) = parent {
+            let new_parent =
+                Domains::try_get(parent_id).map_err(|_| Error::<T>::NoSuchParentDomain)?;
+            ensure!(!new_parent.is_deprecated, Error::<T>::DomainIsDeprecated);
+
+            let mut ancestor = Some(new_parent);
+            while let Some(current) = ancestor {
+                ensure!(current.external_id != domain_id, Error::<T>::DomainHierarchyCycle);
+                depth += 1;
+                ancestor = current.parent.and_then(|id| Domains::try_get(id).ok());
+            }
+        }
+
+        if let Some(old_parent) = domain.parent {
+            DomainIdByParentIdV1::remove(old_parent, domain_id);
+        }
+        if let Some(new_parent) = parent {
+            DomainIdByParentIdV1::insert(new_parent, domain_id, ());
+        }
+        domain.parent = parent;
+        Domains::insert(domain_id, domain);
+
+        Self::deposit_event(RawEvent::DomainParentChanged(domain_id, parent));
+        Ok(Some(T::DeipWeightInfo::set_domain_parent(depth)).into())
+    }
+
+    pub(super) fn deprecate_domain_impl(domain_id: DomainId) -> DispatchResultWithPostInfo {
+        let mut children: u32 = 0;
+        for (child, _) in DomainIdByParentIdV1::iter_prefix(domain_id) {
+            children += 1;
+            let is_active = Domains::try_get(child).map(|c| !c.is_deprecated).unwrap_or(false);
+            ensure!(!is_active, Error::<T>::DomainHasActiveChildren);
+        }
+
+        Domains::try_mutate_exists(domain_id, |maybe_domain| -> DispatchResult {
+            let domain = maybe_domain.as_mut().ok_or(Error::<T>::DomainNotExists)?;
+            ensure!(!domain.is_deprecated, Error::<T>::DomainIsDeprecated);
+            domain.is_deprecated = true;
+            Ok(())
+        })?;
+
+        Self::deposit_event(RawEvent::DomainDeprecated(domain_id));
+        Ok(Some(T::DeipWeightInfo::deprecate_domain(children)).into())
+    }
+
+    /// Checks that the Domain exists and can be assigned to new entities
+    pub(super) fn ensure_domain_is_active(domain_id: &DomainId) -> DispatchResult {
+        let domain = Domains::try_get(domain_id).map_err(|_| Error::<T>::DomainNotExists)?;
+        ensure!(!domain.is_deprecated, Error::<T>::DomainIsDeprecated);
+        Ok(())
+    }
+
+    fn ensure_domain_name(name: &[u8]) -> DispatchResult {
+        ensure!(
+            name.len() <= T::MaxDomainNameLength::get() as usize,
+            Error::<T>::DomainNameTooLong
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use super::*;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    Perbill, SaturatedConversion,
+};
+
+/// Expertise of an account in a domain.
+///
+/// The score decays by `ExpertiseDecay` every full `ExpertiseDecayPeriod`
+/// elapsed since `updated_at`, the decay is applied lazily on read and write.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Expertise<Moment> {
+    /// Score at the `updated_at` moment
+    pub score: u64,
+    /// Start of the current decay period
+    pub updated_at: Moment,
+}
+
+impl<T: Config> Module<T> {
+    /// Decayed expertise score at the `now` moment.
+    /// Returns the score together with the start of the current decay period.
+    pub(super) fn decay_expertise(expertise: ExpertiseOf<T>, now: MomentOf<T>) -> (u64, MomentOf<T>) {
+        let period = T::ExpertiseDecayPeriod::get();
+        if period.is_zero() || now <= expertise.updated_at {
+            return (expertise.score, expertise.updated_at)
+        }
+
+        let periods = (now - expertise.updated_at) / period;
+        let retention = Perbill::one()
+            .saturating_sub(T::ExpertiseDecay::get())
+            .saturating_pow(periods.saturated_into::<u32>() as usize);
+
+        (retention * expertise.score, expertise.updated_at + periods * period)
+    }
+
+    pub(super) fn expertise_at(
+        account: &AccountIdOf<T>,
+        domain_id: &DomainId,
+        now: MomentOf<T>,
+    ) -> u64 {
+        ExpertiseV1::<T>::try_get(account, domain_id)
+            .map(|expertise| Self::decay_expertise(expertise, now).0)
+            .unwrap_or_default()
+    }
+
+    /// Accrues expertise of the review author in the upvoted domain.
+    /// The reward is increased by the `VoterExpertiseShare` of the voter's expertise.
+    pub(super) fn accrue_expertise(
+        author: &AccountIdOf<T>,
+        voter: &AccountIdOf<T>,
+        domain_id: DomainId,
+        now: MomentOf<T>,
+    ) {
+        let voter_share = T::VoterExpertiseShare::get();
+        let reward = if voter_share.is_zero() {
+            T::ExpertiseUpvoteReward::get()
+        } else {
+            let voter_expertise = Self::expertise_at(voter, &domain_id, now);
+            T::ExpertiseUpvoteReward::get().saturating_add(voter_share * voter_expertise)
+        };
+
+        let score = ExpertiseV1::<T>::mutate_exists(author, domain_id, |expertise| {
+            let (score, updated_at) = match expertise.take() {
+                Some(old) => Self::decay_expertise(old, now),
+                None => (0, now),
+            };
+            let score = score.saturating_add(reward);
+            *expertise = Some(Expertise { score, updated_at });
+            score
+        });
+
+        Self::deposit_event(RawEvent::ExpertiseUpdated(author.clone(), domain_id, score));
+    }
+}
@@ -24,16 +24,31 @@
 //! * [`create_investment_opportunity`](./enum.Call.html#variant.create_investment_opportunity)
 //! * [`invest`](./enum.Call.html#variant.invest)
 //! * `update_project` - Update Project info
+//! * [`archive_project`](./enum.Call.html#variant.archive_project)
+//! * [`offer_project_transfer`](./enum.Call.html#variant.offer_project_transfer)
+//! * [`cancel_project_transfer`](./enum.Call.html#variant.cancel_project_transfer)
+//! * [`accept_project_transfer`](./enum.Call.html#variant.accept_project_transfer)
+//! * [`mint_project_nft`](./enum.Call.html#variant.mint_project_nft)
+//! * [`remove_project`](./enum.Call.html#variant.remove_project)
+//! * [`add_domain`](./enum.Call.html#variant.add_domain)
+//! * [`rename_domain`](./enum.Call.html#variant.rename_domain)
+//! * [`set_domain_parent`](./enum.Call.html#variant.set_domain_parent)
+//! * [`deprecate_domain`](./enum.Call.html#variant.deprecate_domain)
 //! * `create_project_content` - Create Project Content (Digital Asset)
+//! * [`update_project_content`](./enum.Call.html#variant.update_project_content)
 //! * `create_project_nda` - Create NDA contract between sides
 //! * `create_nda_content_access_request` - Some side request access to the data of contract
 //! * `fulfill_nda_content_access_request` - Granter fulfill access request to the data
 //! * `reject_nda_content_access_request` - Granter reject access request to the data
+//! * [`add_assessment_model`](./enum.Call.html#variant.add_assessment_model)
 //! * [`create_review`](./enum.Call.html#variant.create_review)
 //! * [`upvote_review`](./enum.Call.html#variant.upvote_review)
 //! * [`create_contract_agreement`](./enum.Call.html#variant.create_contract_agreement)
 //! * [`accept_contract_agreement`](./enum.Call.html#variant.accept_contract_agreement)
 //! * [`reject_contract_agreement`](./enum.Call.html#variant.reject_contract_agreement)
+//! * [`approve_contract_milestone`](./enum.Call.html#variant.approve_contract_milestone)
+//! * [`expire_contract_agreements`](./enum.Call.html#variant.expire_contract_agreements) -
+//!   Unsigned, submitted by the offchain worker
 //!
 //! [`Call`]: ./enum.Call.html
 //! [`Config`]: ./trait.Config.html
@@ -41,21 +56,26 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-    codec::{Decode, Encode},
+    codec::{Decode, Encode, FullCodec},
+    BoundedVec,
     decl_error, decl_event, decl_module, decl_storage,
     dispatch::{DispatchResult, Parameter},
     ensure,
     pallet_prelude::*,
-    storage::IterableStorageDoubleMap,
+    storage::{IterableStorageDoubleMap, IterableStorageMap},
     traits::{Currency, ReservableCurrency},
+    transactional,
     weights::Weight,
     StorageMap,
 };
-use frame_system::{self as system, ensure_signed, offchain::SendTransactionTypes};
+use frame_system::{self as system, ensure_none, ensure_signed, offchain::SendTransactionTypes};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 pub use sp_core::{H160, H256};
-use sp_runtime::{traits::Member, RuntimeDebug};
+use sp_runtime::{
+    traits::{Member, Zero},
+    Perbill, Permill, RuntimeDebug,
+};
 use sp_std::vec::Vec;
 
 #[cfg(test)]
@@ -69,23 +89,45 @@ pub mod api;
 mod review;
 pub use review::{Id as ReviewId, Review, Vote as DeipReviewVote};
 
+mod review_request;
+pub use review_request::{
+    Id as ReviewRequestId, Request as ReviewRequest, RequestOf as ReviewRequestOf,
+};
+
+mod assessment;
+pub use assessment::{
+    Criterion as AssessmentCriterion, Id as AssessmentModelId, Model as AssessmentModel,
+};
+
+mod content;
+mod content_license;
+pub use content_license::{ContentLicense, ContentLicenseOf, KnownLicense, LicenseRights};
+mod content_type;
+pub use content_type::{ContentType, Id as ContentTypeId};
+mod deposit;
+pub use deposit::Deposited;
+mod domain;
+mod expertise;
+pub use expertise::Expertise;
+mod nda;
+mod project;
+pub use project::ProjectNft;
+
 pub mod contract;
 pub use contract::{
     AgreementOf as ContractAgreementOf, Id as ContractAgreementId,
-    IndexTerms as ContractAgreementIndexTerms, Terms,
+    IndexTerms as ContractAgreementIndexTerms, Milestone as ContractMilestone, Terms,
+    TermsOf as ContractAgreementTermsOf,
 };
 
 use deip_transaction_ctx::PortalCtxT;
 
 // pub mod benchmarking;
 pub mod weights;
-use system::pallet_prelude::OriginFor;
 pub use weights::{WeightInfo, Weights};
 
-/// A maximum number of Domains. When trait Condomains reaches this number, no new domains can be added.
-pub const MAX_DOMAINS: u32 = 100;
-
-/// Possible statuses of Project inherited from Project Content type
+/// Content types built into the registry before it became extensible.
+/// The enum indexes are the ids of these types in the registry.
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
@@ -118,6 +160,52 @@ impl Default for ProjectContentType {
     }
 }
 
+/// Lets other pallets (e.g. the market) pay royalties to the Project Content authors.
+pub trait ContentRoyaltyT<AccountId, Balance> {
+    /// Transfers `amount` from the `payer` to the authors of the content by their shares
+    fn pay_content_royalty(
+        content_id: &ProjectContentId,
+        payer: &AccountId,
+        amount: Balance,
+    ) -> DispatchResult;
+}
+
+/// Lets other pallets (e.g. crowdfundings) prevent removal of the Project they depend on.
+pub trait ProjectReferencesT {
+    fn is_referenced(project_id: &ProjectId) -> bool;
+}
+
+impl ProjectReferencesT for () {
+    fn is_referenced(_project_id: &ProjectId) -> bool {
+        false
+    }
+}
+
+/// Tokenization of Projects with F-NFT items. The holder of the Project item
+/// has the ownership rights of the Project instead of its team.
+pub trait ProjectNftT<AccountId> {
+    /// Mints the Project item into the `collection` owned by `owner`
+    fn mint(project_id: ProjectId, collection: H160, owner: &AccountId) -> DispatchResult;
+
+    /// Current holder of the Project item, `None` if the Project isn't tokenized
+    fn holder(project_id: ProjectId) -> Option<AccountId>;
+}
+
+impl<AccountId> ProjectNftT<AccountId> for () {
+    fn mint(_project_id: ProjectId, _collection: H160, _owner: &AccountId) -> DispatchResult {
+        Err(DispatchError::Other("Project tokenization is disabled"))
+    }
+
+    fn holder(_project_id: ProjectId) -> Option<AccountId> {
+        None
+    }
+}
+
+/// Tells the DAO accounts apart from the plain ones, e.g. by the `pallet_deip_dao` key lookup.
+pub trait DaoLookupT<AccountId> {
+    fn is_dao(account: &AccountId) -> bool;
+}
+
 /// Configuration trait. Pallet depends on frame_system and pallet_timestamp.
 pub trait Config:
     frame_system::Config
@@ -136,6 +224,82 @@ pub trait Config:
     type DeipWeightInfo: WeightInfo;
 
     type MaxNdaParties: Get<u16>;
+
+    /// Maximum number of access requests that may be created for a single NDA
+    type MaxNdaAccessRequests: Get<u32>;
+
+    /// Origin allowed to manage the Domains registry (e.g. root or a DAO)
+    type DomainAdminOrigin: EnsureOrigin<Self::Origin>;
+
+    /// Maximum number of Domains. When the registry reaches this number, no new domains can be added.
+    type MaxDomains: Get<u32>;
+
+    /// Maximum length of the Domain name
+    type MaxDomainNameLength: Get<u32>;
+
+    /// Origin allowed to manage the Content Types registry
+    type ContentTypeAdminOrigin: EnsureOrigin<Self::Origin>;
+
+    /// Maximum length of the Content Type name
+    type MaxContentTypeNameLength: Get<u32>;
+
+    /// Projects referenced outside of this pallet can't be removed
+    type ProjectReferences: ProjectReferencesT;
+
+    /// F-NFT items backing the Projects
+    type ProjectNft: ProjectNftT<Self::AccountId>;
+
+    /// Origin allowed to manage the Assessment Models registry
+    type AssessmentModelAdminOrigin: EnsureOrigin<Self::Origin>;
+
+    /// Maximum number of criteria in the Assessment Model
+    type MaxAssessmentCriteria: Get<u32>;
+
+    /// Maximum length of the Assessment Model and criterion names
+    type MaxAssessmentNameLength: Get<u32>;
+
+    /// Review upvotes are counted and accrue expertise only when cast by the DAOs
+    type DaoLookup: DaoLookupT<Self::AccountId>;
+
+    /// Expertise accrued by the review author in the domain the review was upvoted with
+    type ExpertiseUpvoteReward: Get<u64>;
+
+    /// Share of the voter's expertise in the domain added to the upvote reward.
+    /// Zero disables weighting of upvotes by the voter's expertise
+    type VoterExpertiseShare: Get<Perbill>;
+
+    /// Share of the expertise lost every `ExpertiseDecayPeriod`
+    type ExpertiseDecay: Get<Perbill>;
+
+    /// Period of the expertise decay. Zero disables the decay
+    type ExpertiseDecayPeriod: Get<Self::Moment>;
+
+    /// Period of check for expired contract agreements. Zero disables the check
+    type ContractAgreementExpirePeriod: Get<Self::BlockNumber>;
+
+    /// Maximum number of milestones in the escrow contract agreement
+    type MaxContractMilestones: Get<u32>;
+
+    /// Maximum number of contract agreements expired by one offchain worker transaction
+    type MaxExpiredContractAgreements: Get<u32>;
+
+    /// Deposit reserved for every stored Project, Project Content version and Review
+    type DepositBase: Get<BalanceOf<Self>>;
+
+    /// Deposit reserved per byte of the stored Project, Project Content version and Review
+    type DepositPerByte: Get<BalanceOf<Self>>;
+
+    /// Maximum number of Domains of the Project
+    type MaxProjectDomains: Get<u32>;
+
+    /// Maximum number of Domains of the Review
+    type MaxReviewDomains: Get<u32>;
+
+    /// Maximum number of the Project Content authors
+    type MaxContentAuthors: Get<u32>;
+
+    /// Maximum number of the Project Content references
+    type MaxContentReferences: Get<u32>;
 }
 
 /// Unique Project ID reference
@@ -158,8 +322,10 @@ pub type ReviewOf<T> = Review<HashOf<T>, AccountIdOf<T>>;
 pub type NdaOf<T> = Nda<HashOf<T>, AccountIdOf<T>, MomentOf<T>>;
 pub type NdaAccessRequestOf<T> = NdaAccessRequest<HashOf<T>, AccountIdOf<T>>;
 pub type ProjectContentOf<T> = ProjectContent<HashOf<T>, AccountIdOf<T>>;
+pub type ProjectContentVersionOf<T> = ProjectContentVersion<HashOf<T>, AccountIdOf<T>>;
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 type DeipReviewVoteOf<T> = DeipReviewVote<AccountIdOf<T>, MomentOf<T>>;
+pub type ExpertiseOf<T> = Expertise<MomentOf<T>>;
 
 /// PPossible project domains
 #[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
@@ -168,6 +334,12 @@ type DeipReviewVoteOf<T> = DeipReviewVote<AccountIdOf<T>, MomentOf<T>>;
 pub struct Domain {
     /// Reference for external world and uniques control
     pub external_id: DomainId,
+    /// Human readable name of the Domain
+    pub name: Vec<u8>,
+    /// Parent Domain in the hierarchy, if any
+    pub parent: Option<DomainId>,
+    /// Deprecated Domain can't be assigned to new Projects and Reviews
+    pub is_deprecated: bool,
 }
 
 /// Core entity of pallet. Everything connected to Project.
@@ -186,6 +358,8 @@ pub struct Project<Hash, AccountId> {
     description: Hash,
     /// List of Domains aka tags Project matches
     domains: Vec<DomainId>,
+    /// Archived Project is read-only: it can't be updated or receive new content
+    is_archived: bool,
 }
 
 /// Digital asset. Contains information of content and authors of Digital asset.
@@ -199,8 +373,8 @@ pub struct ProjectContent<Hash, AccountId> {
     project_external_id: ProjectId,
     /// Reference to the Team
     team_id: AccountId,
-    /// Type of content. Determine status of Project
-    content_type: ProjectContentType,
+    /// Type of content from the registry. Determine status of Project
+    content_type: ContentTypeId,
     /// Hash of the content ddescription
     description: Hash,
     /// Hast of digital asset
@@ -209,6 +383,26 @@ pub struct ProjectContent<Hash, AccountId> {
     authors: Vec<AccountId>,
     /// List of References to other digital assets whith will be used in current digital asset.
     references: Option<Vec<ProjectContentId>>,
+    /// Number of the latest version. The first version is 0
+    version: u32,
+    /// Contribution shares of the authors in the same order, sum up to 100%.
+    /// Empty if the authors contributed equally
+    author_shares: Vec<Perbill>,
+    /// License the content is distributed under. All rights are reserved if not set
+    license: Option<ContentLicense<Hash>>,
+}
+
+/// Version of the Project Content. Every version stays queryable by its number.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ProjectContentVersion<Hash, AccountId> {
+    /// Hash of the content ddescription
+    description: Hash,
+    /// Hast of digital asset
+    content: Hash,
+    /// Authors of Digital asset
+    authors: Vec<AccountId>,
 }
 
 /// NDA contract between parties. Usually about dislocating or not dislocating some confidential info
@@ -277,6 +471,7 @@ decl_event! {
         AccountId = <T as frame_system::Config>::AccountId,
         Project = ProjectOf<T>,
         Review = ReviewOf<T>,
+        Balance = BalanceOf<T>,
     {
         // ==== ProjectMapV1 ====
 
@@ -286,11 +481,27 @@ decl_event! {
         ProjectRemoved(AccountId, Project),
         /// Event emitted when a project is removed by the owner. [BelongsTo, ProjectId]
         ProjectUpdated(AccountId, ProjectId),
+        /// Event emitted when a project is archived by the owner. [BelongsTo, ProjectId]
+        ProjectArchived(AccountId, ProjectId),
+        /// The project team offered the project to another team. [ProjectId, From, To]
+        ProjectTransferOffered(ProjectId, AccountId, AccountId),
+        /// The project transfer offer has been cancelled. [ProjectId]
+        ProjectTransferCancelled(ProjectId),
+        /// The project has been transferred to another team. [ProjectId, From, To]
+        ProjectTransferred(ProjectId, AccountId, AccountId),
+        /// The project item has been minted into the team collection. [ProjectId, Collection, BelongsTo]
+        ProjectNftMinted(ProjectId, H160, AccountId),
 
         // ==== Project Content ====
 
         /// Event emitted when a project contnet has been created. [BelongsTo, ProjectContentId]
         ProjectContnetCreated(AccountId, ProjectContentId),
+        /// Event emitted when a new version of project content has been added. [BelongsTo, ProjectContentId, Version]
+        ProjectContentUpdated(AccountId, ProjectContentId, u32),
+        /// License of the project content has been set. [ProjectContentId]
+        ProjectContentLicenseSet(ProjectContentId),
+        /// Royalty has been paid to the project content authors. [ProjectContentId, Payer, Amount]
+        ContentRoyaltyPaid(ProjectContentId, AccountId, Balance),
 
         // ==== NDA ====
 
@@ -303,18 +514,49 @@ decl_event! {
         //  /// Event emitted when a NDA Access request has been rejected. [BelongsTo, NdaAccessRequestId]
         NdaAccessRequestRejected(AccountId, NdaAccessRequestId),
 
-        /// Added a domain. [Creator, DomainId]
-        DomainAdded(AccountId, DomainId),
+        /// Added a domain. [DomainId]
+        DomainAdded(DomainId),
+        /// Domain name has been changed. [DomainId]
+        DomainRenamed(DomainId),
+        /// Domain has been moved in the hierarchy. [DomainId, Parent]
+        DomainParentChanged(DomainId, Option<DomainId>),
+        /// Domain has been deprecated. [DomainId]
+        DomainDeprecated(DomainId),
+
+        /// Added a content type. [ContentTypeId]
+        ContentTypeAdded(ContentTypeId),
+        /// Content type has been deprecated. [ContentTypeId]
+        ContentTypeDeprecated(ContentTypeId),
+
+        /// Added an assessment model. [AssessmentModelId]
+        AssessmentModelAdded(AssessmentModelId),
 
         /// Event emitted when a review has been created. [BelongsTo, Review]
         ReviewCreated(AccountId, Review),
         /// Emitted when a DAO votes for a review
         ReviewUpvoted(ReviewId, AccountId, DomainId),
+        /// Expertise of the account in the domain has been updated. [Account, DomainId, Score]
+        ExpertiseUpdated(AccountId, DomainId, u64),
+        /// Review reward has been reserved by the requester. [ReviewRequestId, Requester]
+        ReviewRequestCreated(ReviewRequestId, AccountId),
+        /// Review reward has been paid to the review author. [ReviewRequestId, ReviewId, Author, Reward]
+        ReviewRewardPaid(ReviewRequestId, ReviewId, AccountId, Balance),
+        /// Unclaimed review reward has been returned to the requester. [ReviewRequestId]
+        ReviewRequestRefunded(ReviewRequestId),
 
         ContractAgreementCreated(ContractAgreementId),
         ContractAgreementAccepted(ContractAgreementId, AccountId),
         ContractAgreementFinalized(ContractAgreementId),
         ContractAgreementRejected(ContractAgreementId, AccountId),
+        /// Pending contract agreement has expired. [ContractAgreementId]
+        ContractAgreementExpired(ContractAgreementId),
+        /// Escrow contract milestone has been approved. [ContractAgreementId, Milestone, Approver]
+        ContractMilestoneApproved(ContractAgreementId, u32, AccountId),
+        /// Escrow contract milestone has been paid to the payee. [ContractAgreementId, Milestone]
+        ContractMilestoneReleased(ContractAgreementId, u32),
+        /// Escrow contract refund fell short of the unreleased amount as the payer's
+        /// reserve was reduced elsewhere. [ContractAgreementId, Shortfall]
+        ContractEscrowRefundShortfall(ContractAgreementId, Balance),
     }
 }
 
@@ -331,6 +573,28 @@ decl_error! {
         DomainNotExists,
         /// Cannot add a project because a project with this ID is already a exists
         ProjectAlreadyExists,
+        /// The project is archived and can't be changed
+        ProjectIsArchived,
+        /// The project can't be removed while contract agreements reference it
+        ProjectHasContractAgreements,
+        /// The project can't be removed while NDAs reference it
+        ProjectHasNdas,
+        /// The project can't be removed while review requests for its content are open
+        ProjectHasReviewRequests,
+        /// The project can't be removed while it's referenced by other pallets (e.g. crowdfundings)
+        ProjectIsReferenced,
+        /// The number of project contents, reviews or content versions is greater than the witness
+        ProjectRemoveWitnessTooLow,
+        /// The project is offered to its own team
+        ProjectTransferToSameTeam,
+        /// There is no transfer offer for the project
+        NoSuchProjectTransferOffer,
+        /// The project transfer is offered to another team
+        ProjectTransferOfferedToAnotherTeam,
+        /// The project item has already been minted
+        ProjectAlreadyTokenized,
+        /// The project is backed by the item, so it can't be transferred between teams or removed
+        ProjectIsTokenized,
 
         // ==== Project Content ====
 
@@ -344,6 +608,16 @@ decl_error! {
         NoSuchReference,
         /// Cannot add a project content because a project with this ID is already a finished
         ProjectAlreadyFinished,
+        /// The project content version does not exist.
+        NoSuchProjectContentVersion,
+        /// Number of the author shares doesn't match the number of authors
+        AuthorSharesMismatchAuthors,
+        /// Author shares don't sum up to 100%
+        AuthorSharesNotComplete,
+        /// Project content has no authors to pay royalties to
+        ProjectContentHasNoAuthors,
+        /// The new license revokes rights granted by the current license of the project content
+        ContentLicenseLessPermissive,
 
 
         // ==== Domains ====
@@ -352,6 +626,27 @@ decl_error! {
         DomainLimitReached,
         /// Cannot add domain because this domain is already a exists
         DomainAlreadyExists,
+        /// Domain name is longer than `MaxDomainNameLength`
+        DomainNameTooLong,
+        /// Parent domain does not exist
+        NoSuchParentDomain,
+        /// Domain can't become a descendant of itself
+        DomainHierarchyCycle,
+        /// Domain is deprecated and can't be used for new entities
+        DomainIsDeprecated,
+        /// Domain can't be deprecated while it has active children
+        DomainHasActiveChildren,
+
+        // ==== Content types ====
+
+        /// The content type does not exist
+        NoSuchContentType,
+        /// Cannot add a content type because a type with this ID already exists
+        ContentTypeAlreadyExists,
+        /// Content type name is longer than `MaxContentTypeNameLength`
+        ContentTypeNameTooLong,
+        /// Content type is deprecated and can't be used for new content
+        ContentTypeIsDeprecated,
 
         // ==== NDA ====
 
@@ -376,6 +671,12 @@ decl_error! {
         /// Nda access request already finalized
         NdaAccessRequestAlreadyFinalized,
         TooMuchNdaParties,
+        /// The NDA has already expired, so no access can be requested or granted
+        NdaContractIsExpired,
+        /// Cannot add another access request because the NDA limit is already reached
+        TooMuchNdaAccessRequests,
+        /// Only NDA parties can fulfill or reject access requests
+        NdaAccessRequestGrantorIsNotParty,
 
         /// Cannot add a review because a review with this ID already exists
         ReviewAlreadyExists,
@@ -385,11 +686,61 @@ decl_error! {
         ReviewVoteNoSuchReview,
         ReviewVoteUnrelatedDomain,
         ReviewAlreadyVotedWithDomain,
+        /// Number of the review scores doesn't match the number of the assessment model criteria
+        ReviewScoresMismatchAssessmentModel,
+        /// The review score is out of the criterion range
+        ReviewScoreOutOfRange,
+
+        // ==== Review requests ====
+
+        /// The review does not exist
+        NoSuchReview,
+        /// The review request does not exist
+        NoSuchReviewRequest,
+        /// Cannot add a review request because a request with this ID already exists
+        ReviewRequestAlreadyExists,
+        /// Review request must have a non-zero reward and number of upvotes
+        ReviewRequestWrongTerms,
+        /// Review request deadline must be later current moment
+        ReviewRequestDeadlineMustBeLaterCurrentMoment,
+        /// Not enough free balance to reserve the review reward
+        ReviewRequestNotEnoughBalance,
+        /// The review request deadline has passed, so the reward can't be claimed
+        ReviewRequestExpired,
+        /// The review request can't be refunded before the deadline
+        ReviewRequestNotExpiredYet,
+        /// The review is not of the requested content and domain or is written by the requester
+        ReviewMismatchReviewRequest,
+        /// The review has less upvotes in the requested domain than required
+        ReviewNotEnoughUpvotes,
+
+        // ==== Assessment models ====
+
+        /// The assessment model does not exist
+        NoSuchAssessmentModel,
+        /// Cannot add an assessment model because a model with this ID already exists
+        AssessmentModelAlreadyExists,
+        /// Assessment model must have at least one criterion
+        AssessmentModelNoCriteria,
+        /// Assessment model has more than `MaxAssessmentCriteria` criteria
+        AssessmentModelTooMuchCriteria,
+        /// Name is longer than `MaxAssessmentNameLength`
+        AssessmentModelNameTooLong,
+        /// Criterion minimal score is greater than maximal
+        AssessmentModelWrongScoreRange,
 
         // ==== General =====
 
         /// Access Forbidden
         NoPermission,
+        /// Not enough free balance to reserve the storage deposit
+        InsufficientDeposit,
+        /// More domains than `MaxProjectDomains` or `MaxReviewDomains`
+        TooManyDomains,
+        /// More authors than `MaxContentAuthors`
+        TooManyAuthors,
+        /// More references than `MaxContentReferences`
+        TooManyReferences,
 
         ContractAgreementNoParties,
         ContractAgreementStartTimeMustBeLaterOrEqualCurrentMoment,
@@ -410,6 +761,32 @@ decl_error! {
         ContractAgreementPartyIsNotListed,
         ContractAgreementAlreadyAcceptedByParty,
         ContractAgreementRejected,
+        /// Contract agreement has expired
+        ContractAgreementExpired,
+        /// Contract agreement is not pending or its expiration time has not come yet
+        ContractAgreementNotExpired,
+        /// Escrow contract agreement must have at least one milestone
+        ContractAgreementEscrowNoMilestones,
+        /// Escrow contract agreement has more than `MaxContractMilestones` milestones
+        ContractAgreementEscrowTooMuchMilestones,
+        /// Milestone approvers must be a non-empty subset of the parties
+        ContractAgreementEscrowWrongApprovers,
+        /// The payer can't lock the total amount of the milestones
+        ContractAgreementEscrowNotEnoughBalance,
+        /// Milestones can be approved only after all parties accepted the contract agreement
+        ContractAgreementEscrowNotAccepted,
+        /// The milestone does not exist
+        ContractAgreementEscrowNoSuchMilestone,
+        /// The account is not an approver of the milestone
+        ContractAgreementEscrowNotApprover,
+        /// The milestone is already approved by the account
+        ContractAgreementEscrowMilestoneAlreadyApproved,
+        /// The milestone is already released
+        ContractAgreementEscrowMilestoneReleased,
+        /// The payer's reserve is less than the milestone amount
+        ContractAgreementEscrowReserveShortfall,
+        /// More contract agreements to expire than `MaxExpiredContractAgreements`
+        ContractAgreementTooManyToExpire,
     }
 }
 
@@ -420,6 +797,32 @@ pub enum StorageVersion {
     V0,
     /// Separate investment_opportunity.
     V1,
+    /// Replace NDA lists with per-NDA indexes.
+    V2,
+    /// Domains hierarchy and deprecation.
+    V3,
+    /// Project archival and the project index of contract agreements.
+    V4,
+    /// Versioned project content.
+    V5,
+    /// Reverse index of project content references.
+    V6,
+    /// Typed review scores.
+    V7,
+    /// Contribution shares of the project content authors.
+    V8,
+    /// Indexed finished status of projects.
+    V9,
+    /// Registry of project content types.
+    V10,
+    /// Domain indexes of projects, project contents and reviews.
+    V11,
+    /// License of project content.
+    V12,
+    /// Review upvote counters for review requests.
+    V13,
+    /// Expiration index of pending contract agreements.
+    V14,
 }
 
 decl_storage! {
@@ -427,48 +830,114 @@ decl_storage! {
         /// Should be used in conjunction with `on_runtime_upgrade` to ensure an upgrade is executed
         /// once, even if the code is not removed in time.
         pub PalletStorageVersion get(fn pallet_storage_version)
-            build(|_| StorageVersion::V1): StorageVersion = StorageVersion::V0;
+            build(|_| StorageVersion::V14): StorageVersion = StorageVersion::V0;
 
         ProjectMapV1: map hasher(blake2_128_concat) ProjectId => ProjectOf<T>;
 
         ProjectIdByTeamIdV1: double_map hasher(blake2_128_concat) AccountIdOf<T>, hasher(blake2_128_concat) ProjectId => ();
+        /// Projects tagged with the Domain
+        ProjectIdByDomainIdV1: double_map hasher(blake2_128_concat) DomainId, hasher(blake2_128_concat) ProjectId => ();
+        /// Team the project is offered to
+        ProjectTransferOfferV1 get(fn project_transfer_offer): map hasher(blake2_128_concat) ProjectId => Option<AccountIdOf<T>>;
 
         ProjectContentMapV1: map hasher(blake2_128_concat) ProjectContentId => ProjectContentOf<T>;
         ContentIdByProjectIdV1: double_map hasher(blake2_128_concat) ProjectId, hasher(blake2_128_concat) ProjectContentId => ();
+        /// Project Contents of the Projects tagged with the Domain
+        ContentIdByDomainIdV1: double_map hasher(blake2_128_concat) DomainId, hasher(blake2_128_concat) ProjectContentId => ();
+        /// Projects with the content of a finalizing type. Finished projects don't accept new content
+        FinishedProjectV1: map hasher(blake2_128_concat) ProjectId => ();
+        /// Registry of the Project Content types
+        ContentTypeMapV1 get(fn content_types) build(|_| {
+            content_type::builtin().into_iter().map(|t| (t.external_id, t)).collect::<Vec<_>>()
+        }): map hasher(twox_64_concat) ContentTypeId => ContentType;
+
+        /// Depositor and amount of the storage deposit of the entity
+        StorageDepositV1 get(fn storage_deposit): map hasher(blake2_128_concat) Deposited => Option<(AccountIdOf<T>, BalanceOf<T>)>;
+        /// Project Contents referencing (citing) the Project Content
+        CitedByV1 get(fn cited_by): double_map hasher(blake2_128_concat) ProjectContentId, hasher(blake2_128_concat) ProjectContentId => ();
+        /// Number of Project Contents referencing (citing) the Project Content
+        CitationCountV1 get(fn citation_count): map hasher(blake2_128_concat) ProjectContentId => u32;
+        /// All versions of the Project Content including the latest one
+        ProjectContentVersionMapV1: double_map hasher(blake2_128_concat) ProjectContentId, hasher(twox_64_concat) u32 => ProjectContentVersionOf<T>;
 
-        /// NDA list, guarantees uniquest and provides NDA listing
-        Ndas get(fn nda_list): Vec<(ProjectId, T::AccountId)>;
         /// Map to NDA Info
         NdaMapV1 get(fn nda): map hasher(blake2_128_concat) NdaId => NdaOf<T>;
+        /// NDAs involving the Project
+        NdaIdByProjectIdV1: double_map hasher(blake2_128_concat) ProjectId, hasher(blake2_128_concat) NdaId => ();
 
-        /// NDA Access Requests list, guarantees uniquest and provides NDA Access Requests listing
-        NdaAccessRequests get(fn nda_requests): Vec<(NdaAccessRequestId, NdaId, T::AccountId)>;
         /// Map to NDA Access Requests Info
         NdaAccessRequestMapV1 get(fn nda_request): map hasher(blake2_128_concat) NdaAccessRequestId => NdaAccessRequestOf<T>;
+        /// Access Requests created for the NDA, limited by `MaxNdaAccessRequests`
+        NdaAccessRequestIdsByNdaIdV1 get(fn nda_access_requests): map hasher(blake2_128_concat) NdaId => BoundedVec<NdaAccessRequestId, T::MaxNdaAccessRequests>;
 
         ReviewMapV1: map hasher(blake2_128_concat) ReviewId => ReviewOf<T>;
 
+        /// Assessment Models the Reviews are scored by
+        AssessmentModelMapV1 get(fn assessment_models): map hasher(twox_64_concat) AssessmentModelId => AssessmentModel;
+
         ReviewIdByProjectIdV1: double_map hasher(blake2_128_concat) ProjectId, hasher(blake2_128_concat) ReviewId => ();
 
         ReviewIdByContentIdV1: double_map hasher(blake2_128_concat) ProjectContentId, hasher(blake2_128_concat) ReviewId => ();
 
         ReviewIdByAccountIdV1: double_map hasher(blake2_128_concat) AccountIdOf<T>, hasher(blake2_128_concat) ReviewId => ();
+        /// Reviews tagged with the Domain
+        ReviewIdByDomainIdV1: double_map hasher(blake2_128_concat) DomainId, hasher(blake2_128_concat) ReviewId => ();
 
         ReviewVoteMap: map hasher(blake2_128_concat) (ReviewId, AccountIdOf<T>, DomainId) => DeipReviewVoteOf<T>;
 
+        /// Number of upvotes of the Review in the Domain cast by DAOs, not counting the author's own
+        ReviewUpvoteCountV1 get(fn review_upvote_count): double_map hasher(blake2_128_concat) ReviewId, hasher(blake2_128_concat) DomainId => u32;
+
+        /// Rewards escrowed for reviews of the Project Contents
+        ReviewRequestMapV1 get(fn review_request): map hasher(blake2_128_concat) ReviewRequestId => Option<ReviewRequestOf<T>>;
+        ReviewRequestIdByContentIdV1: double_map hasher(blake2_128_concat) ProjectContentId, hasher(blake2_128_concat) ReviewRequestId => ();
+
         VoteIdByReviewIdV1: double_map hasher(blake2_128_concat) ReviewId, hasher(blake2_128_concat) (ReviewId, AccountIdOf<T>, DomainId) => ();
 
+        /// Expertise of the accounts in the domains accrued from the review upvotes
+        ExpertiseV1: double_map hasher(blake2_128_concat) AccountIdOf<T>, hasher(blake2_128_concat) DomainId => Option<ExpertiseOf<T>>;
+
         VoteIdByAccountId: double_map hasher(blake2_128_concat) AccountIdOf<T>, hasher(blake2_128_concat) (ReviewId, AccountIdOf<T>, DomainId) => ();
 
         // The set of all Domains.
         Domains get(fn domains) config(): map hasher(blake2_128_concat) DomainId => Domain;
+        /// Children of the Domain
+        DomainIdByParentIdV1: double_map hasher(blake2_128_concat) DomainId, hasher(blake2_128_concat) DomainId => ();
         // The total number of domains stored in the map.
         // Because the map does not store its size, we must store it separately
         DomainCount get(fn domain_count) config(): u32;
 
         ContractAgreementMap: map hasher(blake2_128_concat) ContractAgreementId => ContractAgreementOf<T>;
         ContractAgreementIdByType: double_map hasher(twox_64_concat) ContractAgreementIndexTerms, hasher(blake2_128_concat) ContractAgreementId => ();
+        /// Not rejected contract agreements referencing the Project
+        ContractAgreementIdByProjectIdV1: double_map hasher(blake2_128_concat) ProjectId, hasher(blake2_128_concat) ContractAgreementId => ();
+        /// Pending contract agreements by the big-endian expiration time.
+        /// Not hashed to be iterated in time order
+        ContractAgreementIdByExpirationTime: double_map hasher(identity) [u8; 8], hasher(blake2_128_concat) ContractAgreementId => ();
+    }
+}
+
+/// Rewrites every value of the pallet's `Blake2_128Concat` map `item` and returns their number.
+/// Unlike `translate` of the typed map, the new value isn't bound to the current layout,
+/// so a migration writes the layout of its own storage version.
+fn translate_map<K, O, V>(item: &[u8], mut f: impl FnMut(&K, O) -> V) -> Weight
+where
+    K: Encode + Decode,
+    O: Decode,
+    V: Encode,
+{
+    use frame_support::{
+        storage::migration::{put_storage_value, storage_key_iter},
+        Blake2_128Concat, StorageHasher,
+    };
+
+    let entries = storage_key_iter::<K, O, Blake2_128Concat>(b"Deip", item).collect::<Vec<_>>();
+    let count = entries.len() as Weight;
+    for (key, old) in entries {
+        let new = f(&key, old);
+        put_storage_value(b"Deip", item, &Blake2_128Concat::hash(&key.encode()), new);
     }
+    count
 }
 
 mod v1 {
@@ -485,6 +954,513 @@ mod v1 {
     }
 }
 
+mod v2 {
+    use super::*;
+    use frame_support::storage::{migration::remove_storage_prefix, StorageValue};
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        remove_storage_prefix(b"Deip", b"Ndas", &[]);
+        remove_storage_prefix(b"Deip", b"NdaAccessRequests", &[]);
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 2;
+
+        for (id, nda) in NdaMapV1::<T>::iter() {
+            reads += 1;
+            for project_id in nda.projects {
+                NdaIdByProjectIdV1::insert(project_id, id, ());
+                writes += 1;
+            }
+        }
+
+        for (id, request) in NdaAccessRequestMapV1::<T>::iter() {
+            reads += 1;
+            let pushed = NdaAccessRequestIdsByNdaIdV1::<T>::mutate(request.nda_external_id, |ids| {
+                ids.try_push(id).is_ok()
+            });
+            if !pushed {
+                frame_support::log::warn!("NDA {:?} exceeds MaxNdaAccessRequests, request {:?} is not indexed", request.nda_external_id, id);
+            }
+            reads += 1;
+            writes += 1;
+        }
+
+        PalletStorageVersion::put(StorageVersion::V2);
+        writes += 1;
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v3 {
+    use super::*;
+    use frame_support::storage::StorageValue;
+
+    #[derive(Decode)]
+    struct OldDomain {
+        external_id: DomainId,
+    }
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        let mut count: Weight = 0;
+
+        Domains::translate::<OldDomain, _>(|_, old| {
+            count += 1;
+            Some(Domain { external_id: old.external_id, ..Default::default() })
+        });
+
+        PalletStorageVersion::put(StorageVersion::V3);
+
+        T::DbWeight::get().reads_writes(count, count + 1)
+    }
+}
+
+mod v4 {
+    use super::*;
+    use frame_support::storage::StorageValue;
+
+    #[derive(Decode)]
+    struct OldProject<Hash, AccountId> {
+        is_private: bool,
+        external_id: ProjectId,
+        team_id: AccountId,
+        description: Hash,
+        domains: Vec<DomainId>,
+    }
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 1;
+
+        ProjectMapV1::<T>::translate::<OldProject<HashOf<T>, AccountIdOf<T>>, _>(|_, old| {
+            reads += 1;
+            writes += 1;
+            Some(ProjectOf::<T> {
+                is_private: old.is_private,
+                external_id: old.external_id,
+                team_id: old.team_id,
+                description: old.description,
+                domains: old.domains,
+                is_archived: false,
+            })
+        });
+
+        for (id, agreement) in ContractAgreementMap::<T>::iter() {
+            reads += 1;
+            if let contract::Agreement::License(status) = agreement {
+                use contract::LicenseStatus::*;
+                match status {
+                    Unsigned(l) | SignedByLicenser(l) | Signed(l) => {
+                        ContractAgreementIdByProjectIdV1::insert(l.project_id, id, ());
+                        writes += 1;
+                    },
+                    Rejected(_) | Expired(_) => {},
+                }
+            }
+        }
+
+        PalletStorageVersion::put(StorageVersion::V4);
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v5 {
+    use super::*;
+    use frame_support::storage::StorageValue;
+
+    #[derive(Decode)]
+    struct OldProjectContent<Hash, AccountId> {
+        external_id: ProjectContentId,
+        project_external_id: ProjectId,
+        team_id: AccountId,
+        content_type: ProjectContentType,
+        description: Hash,
+        content: Hash,
+        authors: Vec<AccountId>,
+        references: Option<Vec<ProjectContentId>>,
+    }
+
+    #[derive(Decode)]
+    struct OldReview<Hash, AccountId> {
+        external_id: ReviewId,
+        author: AccountId,
+        content: Hash,
+        domains: Vec<DomainId>,
+        assessment_model: u32,
+        weight: Vec<u8>,
+        project_content_external_id: ProjectContentId,
+    }
+
+    /// Review as stored since V5
+    #[derive(Encode, Decode)]
+    pub(super) struct Review<Hash, AccountId> {
+        pub(super) external_id: ReviewId,
+        pub(super) author: AccountId,
+        pub(super) content: Hash,
+        pub(super) domains: Vec<DomainId>,
+        pub(super) assessment_model: u32,
+        pub(super) weight: Vec<u8>,
+        pub(super) project_content_external_id: ProjectContentId,
+        pub(super) project_content_version: u32,
+    }
+
+    /// Project Content as stored since V5
+    #[derive(Encode, Decode)]
+    pub(super) struct ProjectContent<Hash, AccountId> {
+        pub(super) external_id: ProjectContentId,
+        pub(super) project_external_id: ProjectId,
+        pub(super) team_id: AccountId,
+        pub(super) content_type: ProjectContentType,
+        pub(super) description: Hash,
+        pub(super) content: Hash,
+        pub(super) authors: Vec<AccountId>,
+        pub(super) references: Option<Vec<ProjectContentId>>,
+        pub(super) version: u32,
+    }
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 1;
+
+        let count = translate_map(
+            b"ProjectContentMapV1",
+            |id: &ProjectContentId, old: OldProjectContent<HashOf<T>, AccountIdOf<T>>| {
+                ProjectContentVersionMapV1::<T>::insert(id, 0, ProjectContentVersionOf::<T> {
+                    description: old.description,
+                    content: old.content,
+                    authors: old.authors.clone(),
+                });
+                ProjectContent {
+                    external_id: old.external_id,
+                    project_external_id: old.project_external_id,
+                    team_id: old.team_id,
+                    content_type: old.content_type,
+                    description: old.description,
+                    content: old.content,
+                    authors: old.authors,
+                    references: old.references,
+                    version: 0,
+                }
+            },
+        );
+        reads += count;
+        writes += count * 2;
+
+        let count = translate_map(b"ReviewMapV1", |_: &ReviewId, old: OldReview<HashOf<T>, AccountIdOf<T>>| {
+            Review {
+                external_id: old.external_id,
+                author: old.author,
+                content: old.content,
+                domains: old.domains,
+                assessment_model: old.assessment_model,
+                weight: old.weight,
+                project_content_external_id: old.project_content_external_id,
+                project_content_version: 0,
+            }
+        });
+        reads += count;
+        writes += count;
+
+        PalletStorageVersion::put(StorageVersion::V5);
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v6 {
+    use super::*;
+    use frame_support::{storage::{migration::storage_key_iter, StorageValue}, Blake2_128Concat};
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 1;
+
+        let contents = storage_key_iter::<
+            ProjectContentId,
+            v5::ProjectContent<HashOf<T>, AccountIdOf<T>>,
+            Blake2_128Concat,
+        >(b"Deip", b"ProjectContentMapV1");
+        for (id, content) in contents {
+            reads += 1;
+            for reference in content.references.unwrap_or_default() {
+                reads += 1;
+                writes += Module::<T>::add_citation(reference, id) as Weight * 2;
+            }
+        }
+
+        PalletStorageVersion::put(StorageVersion::V6);
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v7 {
+    use super::*;
+    use frame_support::storage::StorageValue;
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        let mut count: Weight = 0;
+
+        ReviewMapV1::<T>::translate::<v5::Review<HashOf<T>, AccountIdOf<T>>, _>(|_, old| {
+            count += 1;
+            Some(ReviewOf::<T> {
+                external_id: old.external_id,
+                author: old.author,
+                content: old.content,
+                domains: old.domains,
+                assessment_model: old.assessment_model,
+                weight: old.weight,
+                project_content_external_id: old.project_content_external_id,
+                project_content_version: old.project_content_version,
+                scores: Vec::new(),
+            })
+        });
+
+        PalletStorageVersion::put(StorageVersion::V7);
+
+        T::DbWeight::get().reads_writes(count, count + 1)
+    }
+}
+
+mod v8 {
+    use super::*;
+    use frame_support::storage::StorageValue;
+
+    /// Project Content as stored since V8
+    #[derive(Encode, Decode)]
+    pub(super) struct ProjectContent<Hash, AccountId> {
+        pub(super) external_id: ProjectContentId,
+        pub(super) project_external_id: ProjectId,
+        pub(super) team_id: AccountId,
+        pub(super) content_type: ProjectContentType,
+        pub(super) description: Hash,
+        pub(super) content: Hash,
+        pub(super) authors: Vec<AccountId>,
+        pub(super) references: Option<Vec<ProjectContentId>>,
+        pub(super) version: u32,
+        pub(super) author_shares: Vec<Perbill>,
+    }
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        let count = translate_map(
+            b"ProjectContentMapV1",
+            |_: &ProjectContentId, old: v5::ProjectContent<HashOf<T>, AccountIdOf<T>>| {
+                ProjectContent {
+                    external_id: old.external_id,
+                    project_external_id: old.project_external_id,
+                    team_id: old.team_id,
+                    content_type: old.content_type,
+                    description: old.description,
+                    content: old.content,
+                    authors: old.authors,
+                    references: old.references,
+                    version: old.version,
+                    author_shares: Vec::new(),
+                }
+            },
+        );
+
+        PalletStorageVersion::put(StorageVersion::V8);
+
+        T::DbWeight::get().reads_writes(count, count + 1)
+    }
+}
+
+mod v9 {
+    use super::*;
+    use frame_support::{storage::{migration::storage_key_iter, StorageValue}, Blake2_128Concat};
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 1;
+
+        let contents = storage_key_iter::<
+            ProjectContentId,
+            v8::ProjectContent<HashOf<T>, AccountIdOf<T>>,
+            Blake2_128Concat,
+        >(b"Deip", b"ProjectContentMapV1");
+        for (_, content) in contents {
+            reads += 1;
+            if content.content_type == ProjectContentType::FinalResult {
+                writes += 1;
+                FinishedProjectV1::insert(content.project_external_id, ());
+            }
+        }
+
+        PalletStorageVersion::put(StorageVersion::V9);
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v10 {
+    use super::*;
+    use frame_support::storage::StorageValue;
+
+    /// Project Content as stored since V10
+    #[derive(Encode, Decode)]
+    pub(super) struct ProjectContent<Hash, AccountId> {
+        pub(super) external_id: ProjectContentId,
+        pub(super) project_external_id: ProjectId,
+        pub(super) team_id: AccountId,
+        pub(super) content_type: ContentTypeId,
+        pub(super) description: Hash,
+        pub(super) content: Hash,
+        pub(super) authors: Vec<AccountId>,
+        pub(super) references: Option<Vec<ProjectContentId>>,
+        pub(super) version: u32,
+        pub(super) author_shares: Vec<Perbill>,
+    }
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 1;
+
+        for content_type in content_type::builtin() {
+            writes += 1;
+            ContentTypeMapV1::insert(content_type.external_id, content_type);
+        }
+
+        let count = translate_map(
+            b"ProjectContentMapV1",
+            |_: &ProjectContentId, old: v8::ProjectContent<HashOf<T>, AccountIdOf<T>>| {
+                ProjectContent {
+                    external_id: old.external_id,
+                    project_external_id: old.project_external_id,
+                    team_id: old.team_id,
+                    content_type: old.content_type.into(),
+                    description: old.description,
+                    content: old.content,
+                    authors: old.authors,
+                    references: old.references,
+                    version: old.version,
+                    author_shares: old.author_shares,
+                }
+            },
+        );
+        reads += count;
+        writes += count;
+
+        PalletStorageVersion::put(StorageVersion::V10);
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v11 {
+    use super::*;
+    use frame_support::storage::StorageValue;
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 1;
+
+        for (id, project) in ProjectMapV1::<T>::iter() {
+            reads += 1;
+            for domain in &project.domains {
+                writes += 1;
+                ProjectIdByDomainIdV1::insert(domain, id, ());
+            }
+            for (content_id, _) in ContentIdByProjectIdV1::iter_prefix(id) {
+                reads += 1;
+                for domain in &project.domains {
+                    writes += 1;
+                    ContentIdByDomainIdV1::insert(domain, content_id, ());
+                }
+            }
+        }
+
+        for (id, review) in ReviewMapV1::<T>::iter() {
+            reads += 1;
+            for domain in review.domains {
+                writes += 1;
+                ReviewIdByDomainIdV1::insert(domain, id, ());
+            }
+        }
+
+        PalletStorageVersion::put(StorageVersion::V11);
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v12 {
+    use super::*;
+    use frame_support::storage::StorageValue;
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        let mut count: Weight = 0;
+
+        ProjectContentMapV1::<T>::translate::<v10::ProjectContent<HashOf<T>, AccountIdOf<T>>, _>(|_, old| {
+            count += 1;
+            Some(ProjectContentOf::<T> {
+                external_id: old.external_id,
+                project_external_id: old.project_external_id,
+                team_id: old.team_id,
+                content_type: old.content_type,
+                description: old.description,
+                content: old.content,
+                authors: old.authors,
+                references: old.references,
+                version: old.version,
+                author_shares: old.author_shares,
+                license: None,
+            })
+        });
+
+        PalletStorageVersion::put(StorageVersion::V12);
+
+        T::DbWeight::get().reads_writes(count, count + 1)
+    }
+}
+
+mod v13 {
+    use super::*;
+    use frame_support::storage::StorageValue;
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 1;
+
+        for ((review_id, voter, domain_id), _) in ReviewVoteMap::<T>::iter() {
+            reads += 2;
+            let is_own = ReviewMapV1::<T>::try_get(review_id)
+                .map(|review| review.author == voter)
+                .unwrap_or(true);
+            if !is_own && T::DaoLookup::is_dao(&voter) {
+                writes += 1;
+                ReviewUpvoteCountV1::mutate(review_id, domain_id, |count| *count += 1);
+            }
+        }
+
+        PalletStorageVersion::put(StorageVersion::V13);
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+mod v14 {
+    use super::*;
+    use frame_support::storage::StorageValue;
+
+    pub(crate) fn migrate<T: Config>() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 1;
+
+        for (id, agreement) in ContractAgreementMap::<T>::iter() {
+            reads += 1;
+            if let Some(e) = agreement.pending_expiration_time() {
+                writes += 1;
+                ContractAgreementIdByExpirationTime::insert(Module::<T>::expiration_key(e), id, ());
+            }
+        }
+
+        PalletStorageVersion::put(StorageVersion::V14);
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
 // Dispatchable functions allows users to interact with the pallet and invoke state changes.
 // These functions materialize as "extrinsics", which are often compared to transactions.
 // Dispatchable functions must be annotated with a weight and must return a DispatchResult.
@@ -497,10 +1473,50 @@ decl_module! {
         fn deposit_event() = default;
 
         fn on_runtime_upgrade() -> Weight {
+            let mut weight = 0;
             if Module::<T>::pallet_storage_version() == StorageVersion::V0 {
-                return v1::set_storage_version::<T>();
+                weight = weight.saturating_add(v1::set_storage_version::<T>());
+            }
+            if Module::<T>::pallet_storage_version() == StorageVersion::V1 {
+                weight = weight.saturating_add(v2::migrate::<T>());
+            }
+            if Module::<T>::pallet_storage_version() == StorageVersion::V2 {
+                weight = weight.saturating_add(v3::migrate::<T>());
+            }
+            if Module::<T>::pallet_storage_version() == StorageVersion::V3 {
+                weight = weight.saturating_add(v4::migrate::<T>());
+            }
+            if Module::<T>::pallet_storage_version() == StorageVersion::V4 {
+                weight = weight.saturating_add(v5::migrate::<T>());
+            }
+            if Module::<T>::pallet_storage_version() == StorageVersion::V5 {
+                weight = weight.saturating_add(v6::migrate::<T>());
             }
-            0
+            if Module::<T>::pallet_storage_version() == StorageVersion::V6 {
+                weight = weight.saturating_add(v7::migrate::<T>());
+            }
+            if Module::<T>::pallet_storage_version() == StorageVersion::V7 {
+                weight = weight.saturating_add(v8::migrate::<T>());
+            }
+            if Module::<T>::pallet_storage_version() == StorageVersion::V8 {
+                weight = weight.saturating_add(v9::migrate::<T>());
+            }
+            if Module::<T>::pallet_storage_version() == StorageVersion::V9 {
+                weight = weight.saturating_add(v10::migrate::<T>());
+            }
+            if Module::<T>::pallet_storage_version() == StorageVersion::V10 {
+                weight = weight.saturating_add(v11::migrate::<T>());
+            }
+            if Module::<T>::pallet_storage_version() == StorageVersion::V11 {
+                weight = weight.saturating_add(v12::migrate::<T>());
+            }
+            if Module::<T>::pallet_storage_version() == StorageVersion::V12 {
+                weight = weight.saturating_add(v13::migrate::<T>());
+            }
+            if Module::<T>::pallet_storage_version() == StorageVersion::V13 {
+                weight = weight.saturating_add(v14::migrate::<T>());
+            }
+            weight
         }
 
         /// Allow a user to create project.
@@ -529,19 +1545,26 @@ decl_module! {
                 external_id,
                 team_id: team_id.into(),
                 description,
-                domains
+                domains,
+                is_archived: false,
             };
 
             ensure!(account == project.team_id, Error::<T>::NoPermission);
+            ensure!(project.domains.len() <= T::MaxProjectDomains::get() as usize, Error::<T>::TooManyDomains);
 
             for domain in &project.domains {
-                ensure!(Domains::contains_key(&domain), Error::<T>::DomainNotExists);
+                Self::ensure_domain_is_active(domain)?;
             }
 
             ensure!(!ProjectMapV1::<T>::contains_key(project.external_id), Error::<T>::ProjectAlreadyExists);
 
+            Self::reserve_deposit(&account, Deposited::Project(project.external_id), project.encoded_size())?;
+
             ProjectMapV1::<T>::insert(project.external_id, project.clone());
             ProjectIdByTeamIdV1::<T>::insert(project.team_id.clone(), project.external_id, ());
+            for domain in &project.domains {
+                ProjectIdByDomainIdV1::insert(domain, project.external_id, ());
+            }
 
             Self::deposit_event(RawEvent::ProjectCreated(account, project));
         }
@@ -565,7 +1588,8 @@ decl_module! {
             ProjectMapV1::<T>::mutate_exists(project_id, |maybe_project| -> DispatchResult {
                 let project = maybe_project.as_mut().ok_or(Error::<T>::NoSuchProject)?;
 
-                ensure!(project.team_id == account, Error::<T>::NoPermission);
+                ensure!(Self::project_owner(project) == account, Error::<T>::NoPermission);
+                ensure!(!project.is_archived, Error::<T>::ProjectIsArchived);
 
                 // TODO make sure that we don't lose first 2 bytes of the hash
                 if let Some(value) = description  {
@@ -590,6 +1614,9 @@ decl_module! {
         /// The origin for this call must be _Signed_.
         ///
         /// - `content`: [Content](./struct.ProjectContent.html) to be created
+        /// - `author_shares`: Optional. Contribution shares of the `authors` in the same order,
+        ///   must sum up to 100%. The authors share royalties equally if not set
+        /// - `license`: Optional. License the content is distributed under
         #[weight = {
             let _a = authors.len() as u32;
             let r = references.as_ref().map(|x| x.len()).unwrap_or(0) as u32;
@@ -599,14 +1626,23 @@ decl_module! {
             external_id: ProjectContentId,
             project_external_id: ProjectId,
             team_id: T::DeipAccountId,
-            content_type: ProjectContentType,
+            content_type: ContentTypeId,
             description: T::Hash,
             content: T::Hash,
             authors: Vec<T::DeipAccountId>,
-            references: Option<Vec<ProjectContentId>>
+            author_shares: Option<Vec<Perbill>>,
+            references: Option<Vec<ProjectContentId>>,
+            license: Option<ContentLicenseOf<T>>
         ) {
             let account = ensure_signed(origin)?;
 
+            ensure!(authors.len() <= T::MaxContentAuthors::get() as usize, Error::<T>::TooManyAuthors);
+            ensure!(
+                references.as_ref().map_or(0, |x| x.len()) <= T::MaxContentReferences::get() as usize,
+                Error::<T>::TooManyReferences
+            );
+            let author_shares = Self::ensure_author_shares(authors.len(), author_shares)?;
+
             let content = ProjectContentOf::<T> {
                 external_id,
                 project_external_id,
@@ -615,7 +1651,10 @@ decl_module! {
                 description,
                 content,
                 authors: authors.into_iter().map(Into::into).collect(),
-                references
+                references,
+                version: 0,
+                author_shares,
+                license,
             };
 
             ensure!(!ProjectContentMapV1::<T>::contains_key(&content.external_id), Error::<T>::ProjectContentAlreadyExists);
@@ -623,8 +1662,10 @@ decl_module! {
             let project = ProjectMapV1::<T>::get(content.project_external_id);
 
             ensure!(!project.external_id.is_zero(), Error::<T>::NoSuchProject);
-            ensure!(project.team_id == content.team_id, Error::<T>::ProjectNotBelongToTeam);
+            ensure!(Self::project_owner(&project) == content.team_id, Error::<T>::ProjectNotBelongToTeam);
+            ensure!(!project.is_archived, Error::<T>::ProjectIsArchived);
             ensure!(!Self::is_project_finished(&project.external_id), Error::<T>::ProjectAlreadyFinished);
+            let content_type = Self::ensure_content_type_is_active(content.content_type)?;
 
             if let Some(references) = &content.references {
                 let is_all_references_exists = references
@@ -634,214 +1675,41 @@ decl_module! {
                 ensure!(is_all_references_exists, Error::<T>::NoSuchReference);
             }
 
+            let version = ProjectContentVersionOf::<T> {
+                description: content.description,
+                content: content.content,
+                authors: content.authors.clone(),
+            };
+            Self::reserve_deposit(
+                &account,
+                Deposited::ProjectContent(content.external_id, content.version),
+                content.encoded_size() + version.encoded_size(),
+            )?;
+
+            ProjectContentVersionMapV1::<T>::insert(content.external_id, content.version, version);
+            for reference in content.references.iter().flatten() {
+                Self::add_citation(*reference, content.external_id);
+            }
+            if content_type.finalizes_project {
+                FinishedProjectV1::insert(content.project_external_id, ());
+            }
             ProjectContentMapV1::<T>::insert(content.external_id, content.clone());
             ContentIdByProjectIdV1::insert(content.project_external_id, content.external_id, ());
+            for domain in &project.domains {
+                ContentIdByDomainIdV1::insert(domain, content.external_id, ());
+            }
 
             Self::deposit_event(RawEvent::ProjectContnetCreated(account, content.external_id));
         }
 
-        // /// Allow a user to create [NDA](./struct.Nda.html).
-        // ///
-        // /// The origin for this call must be _Signed_.
-        // ///
-        // /// - `end_date`: Unix Timestamp. Exparation date of contract
-        // /// - `contract_hash`: Hash of the contract
-        // /// - `maybe_start_date`: Optional. Unix Timestamp. Entry into force of the contract
-        // /// - `parties`: List of involved Parties
-        // /// - `projects`: List of involved ProjectMapV1
-        // #[weight = {
-        //     let p = parties.len() as u32;
-        //     T::DeipWeightInfo::create_project_nda(p)
-        // }]
-        // fn create_project_nda(origin,
-        //     external_id: NdaId,
-        //     end_date: T::Moment,
-        //     contract_hash: T::Hash,
-        //     maybe_start_date: Option<T::Moment>,
-        //     parties: Vec<T::DeipAccountId>,
-        //     projects: Vec<ProjectId>
-        // ) {
-        //     let mut projects = projects;
-        //     projects.dedup();
-        //     let contract_creator = ensure_signed(origin)?;
-        //     let timestamp = pallet_timestamp::Pallet::<T>::get();
-        //
-        //     ensure!(end_date > timestamp, Error::<T>::NdaEndDateMustBeLaterCurrentMoment);
-        //
-        //     ensure!(projects.len() <= T::MaxNdaParties::get() as usize, Error::<T>::TooMuchNdaParties);
-        //     ensure!(parties.len() <= T::MaxNdaParties::get() as usize, Error::<T>::TooMuchNdaParties);
-        //
-        //     if let Some(start_date) = maybe_start_date {
-        //         ensure!(start_date >= timestamp, Error::<T>::NdaStartDateMustBeLaterOrEqualCurrentMoment);
-        //         ensure!(end_date > start_date, Error::<T>::NdaStartDateMustBeLessThanEndDate);
-        //     }
-        //
-        //     let parties: Vec<T::AccountId> = parties.into_iter().map(Into::into).collect();
-        //
-        //     projects.iter()
-        //         .try_for_each(|id| -> DispatchResult {
-        //             let project = ProjectMapV1::<T>::get(id);
-        //
-        //             ensure!(!project.external_id.is_zero(), Error::<T>::NoSuchProject);
-        //             ensure!(parties.contains(&project.team_id), Error::<T>::TeamOfAllProjectsMustSpecifiedAsParty);
-        //
-        //             Ok(())
-        //         })?;
-        //
-        //     let mut nda_list = Ndas::<T>::get();
-        //
-        //     let index_to_insert_nda = nda_list.binary_search_by_key(&external_id, |&(external_id, ..)| external_id)
-        //         .err().ok_or(Error::<T>::NdaAlreadyExists)?;
-        //
-        //
-        //     let nda = Nda {
-        //         contract_creator: contract_creator.clone(),
-        //         external_id,
-        //         end_date,
-        //         start_date: maybe_start_date,
-        //         contract_hash,
-        //         parties,
-        //         projects
-        //     };
-        //
-        //     nda_list.insert(index_to_insert_nda, (nda.external_id, contract_creator.clone()));
-        //     Ndas::<T>::put(nda_list);
-        //
-        //     NdaMapV1::<T>::insert(nda.external_id, nda);
-        //
-        //     // Emit an event that the NDA was created.
-        //     Self::deposit_event(RawEvent::NdaCreated(contract_creator, external_id));
-        //
-        // }
-
-
-        // /// Create [request](./struct.NdaAccessRequest.html) to access NDA content
-        // ///
-        // /// The origin for this call must be _Signed_.
-        // ///
-        // /// - `external_id`: Reference for external world and uniques control
-        // /// - `nda_external_id`: Reference to NDA
-        // /// - `encrypted_payload_hash`: Payload witch need to be decrypted
-        // /// - `encrypted_payload_iv`: IV of encrypted payload
-        // #[weight = {
-        //     T::DeipWeightInfo::create_nda_content_access_request()
-        // }]
-        // fn create_nda_content_access_request(
-        //     origin,
-        //     external_id: NdaAccessRequestId,
-        //     nda_external_id: NdaId,
-        //     encrypted_payload_hash: T::Hash,
-        //     encrypted_payload_iv: Vec<u8>,
-        // ) {
-        //     let account = ensure_signed(origin)?;
-        //     let timestamp = pallet_timestamp::Pallet::<T>::get();
-        //
-        //     let nda = NdaMapV1::<T>::get(nda_external_id);
-        //
-        //     ensure!(!nda.external_id.is_zero(), Error::<T>::NoSuchNda);
-        //     ensure!(nda.start_date <= Some(timestamp), Error::<T>::NdaContractIsNotActiveYet);
-        //
-        //     let mut nda_requests = NdaAccessRequests::<T>::get();
-        //
-        //     let index_to_insert_nda_request = nda_requests.binary_search_by_key(&external_id, |&(external_id, ..)| external_id)
-        //         .err().ok_or(Error::<T>::NdaAccessRequestAlreadyExists)?;
-        //
-        //     let nda_request = NdaAccessRequest {
-        //         external_id,
-        //         nda_external_id,
-        //
-        //         requester: account.clone(),
-        //         encrypted_payload_hash,
-        //         encrypted_payload_iv,
-        //         status: NdaAccessRequestStatus::Pending,
-        //         grantor: None,
-        //         encrypted_payload_encryption_key: None,
-        //         proof_of_encrypted_payload_encryption_key: None,
-        //     };
-        //     nda_requests.insert(index_to_insert_nda_request, (external_id, nda_external_id, account.clone()));
-        //     NdaAccessRequests::<T>::put(nda_requests);
-        //
-        //     NdaAccessRequestMapV1::<T>::insert(nda_request.external_id, nda_request);
-        //
-        //     // Emit an event that the NDA was created.
-        //     Self::deposit_event(RawEvent::NdaAccessRequestCreated(account, external_id));
-        //
-        //
-        // }
-
-        // /// Fulfill NDA access request
-        // ///
-        // /// The origin for this call must be _Signed_.
-        // ///
-        // /// - `external_id`: Reference for external world and uniques control
-        // /// - `encrypted_payload_encryption_key`: Ecrypted key witch can decrypt payload
-        // /// - `proof_of_encrypted_payload_encryption_key`: Proof that requester has access to the encrypted data with his key
-        // #[weight = {
-        //     T::DeipWeightInfo::fulfill_nda_content_access_request()
-        // }]
-        // fn fulfill_nda_content_access_request(
-        //     origin,
-        //     external_id: NdaAccessRequestId,
-        //     encrypted_payload_encryption_key: Vec<u8>,
-        //     proof_of_encrypted_payload_encryption_key: Vec<u8>,
-        // ) {
-        //     let account = ensure_signed(origin)?;
-        //
-        //     NdaAccessRequestMapV1::<T>::mutate_exists(external_id, |maybe_nda_access_request| -> DispatchResult {
-        //         let mut nda_access_request = maybe_nda_access_request.as_mut().ok_or(Error::<T>::NoSuchNdaAccessRequest)?;
-        //
-        //         ensure!(nda_access_request.status == NdaAccessRequestStatus::Pending, Error::<T>::NdaAccessRequestAlreadyFinalized);
-        //         ensure!(NdaMapV1::<T>::contains_key(nda_access_request.nda_external_id), Error::<T>::NoSuchNda);
-        //
-        //         nda_access_request.status = NdaAccessRequestStatus::Fulfilled;
-        //         nda_access_request.grantor = Some(account.clone());
-        //         nda_access_request.encrypted_payload_encryption_key = Some(encrypted_payload_encryption_key);
-        //         nda_access_request.proof_of_encrypted_payload_encryption_key = Some(proof_of_encrypted_payload_encryption_key);
-        //
-        //         Ok(())
-        //     })?;
-        //
-        //     // Emit an event that the NDA was fulfilled.
-        //     Self::deposit_event(RawEvent::NdaAccessRequestFulfilled(account, external_id));
-        //
-        // }
-
-        // /// Reject NDA access request
-        // ///
-        // /// The origin for this call must be _Signed_.
-        // ///
-        // /// - `external_id`: Reference for external world and uniques control
-        // #[weight = {
-        //     T::DeipWeightInfo::reject_nda_content_access_request()
-        // }]
-        // fn reject_nda_content_access_request(
-        //      origin,
-        //      external_id: NdaAccessRequestId,
-        //  ) {
-        //      let account = ensure_signed(origin)?;
-        //
-        //      NdaAccessRequestMapV1::<T>::mutate_exists(external_id, |maybe_nda_access_request| -> DispatchResult {
-        //         let mut nda_access_request = maybe_nda_access_request.as_mut().ok_or(Error::<T>::NoSuchNdaAccessRequest)?;
-        //
-        //
-        //         ensure!(nda_access_request.status == NdaAccessRequestStatus::Pending, Error::<T>::NdaAccessRequestAlreadyFinalized);
-        //         ensure!(NdaMapV1::<T>::contains_key(nda_access_request.nda_external_id), Error::<T>::NoSuchNda);
-        //MomentOf
-        //         nda_access_request.status = NdaAccessRequestStatus::Rejected;
-        //
-        //         Ok(())
-        //      })?;
-        //
-        //      // Emit an event that the NDA was rejected.
-        //      Self::deposit_event(RawEvent::NdaAccessRequestRejected(account, external_id));
-        //
-        // }
-
         /// Allow a user to create review.
         ///
         /// The origin for this call must be _Signed_.
         ///
         /// - `review`: [Review](./struct.Review.html) to be created
+        /// - `assessment_model`: [Assessment Model](./struct.AssessmentModel.html) the review is scored by
+        /// - `scores`: Score for each criterion of the assessment model
+        /// - `project_content_version`: Optional. Reviewed version of the content, the latest by default
         #[weight = {
             let d = domains.len() as u32;
             T::DeipWeightInfo::create_review(d)
@@ -851,12 +1719,13 @@ decl_module! {
             author: T::DeipAccountId,
             content: T::Hash,
             domains: Vec<DomainId>,
-            assessment_model: u32,
-            weight: Vec<u8>,
+            assessment_model: AssessmentModelId,
+            scores: Vec<Permill>,
             project_content_external_id: ProjectContentId,
+            project_content_version: Option<u32>,
         ) -> DispatchResult {
             let account = ensure_signed(origin)?;
-            Self::create_review_impl(account, external_id, author, content, domains, assessment_model, weight, project_content_external_id)
+            Self::create_review_impl(account, external_id, author, content, domains, assessment_model, scores, project_content_external_id, project_content_version)
         }
 
         /// Allows DAO to vote for a review.
@@ -884,8 +1753,13 @@ decl_module! {
         /// - `activation_time`/`expiration_time`
         /// - `terms` - specifies type of the contract agreement. For details see [`ContractAgreementTerms`].
         #[weight = {
+            let m = match terms {
+                Terms::EscrowContractAgreement { milestones } => milestones.len() as u32,
+                _ => 0,
+            };
             T::DeipWeightInfo::create_contract_agreement_project_license()
                 .max(T::DeipWeightInfo::create_contract_agreement_generic_contract())
+                .max(T::DeipWeightInfo::create_contract_agreement_escrow_contract(m))
         }]
         fn create_contract_agreement(origin,
             id: ContractAgreementId,
@@ -894,7 +1768,7 @@ decl_module! {
             hash: HashOf<T>,
             activation_time: Option<MomentOf<T>>,
             expiration_time: Option<MomentOf<T>>,
-            terms: Terms,
+            terms: ContractAgreementTermsOf<T>,
         ) -> DispatchResultWithPostInfo {
             let account = ensure_signed(origin)?;
             let parties = parties.into_iter().map(Into::into).collect();
@@ -911,6 +1785,7 @@ decl_module! {
                 .max(T::DeipWeightInfo::accept_contract_agreement_project_license_signed_by_licenser())
                 .max(T::DeipWeightInfo::accept_contract_agreement_generic_contract_partially_accepted())
                 .max(T::DeipWeightInfo::accept_contract_agreement_generic_contract_finalized())
+                .max(T::DeipWeightInfo::accept_contract_agreement_escrow_contract())
         }]
         fn accept_contract_agreement(origin,
             id: ContractAgreementId,
@@ -927,7 +1802,7 @@ decl_module! {
         /// The origin for this call must be _Signed_.
         /// - `id` - identifies the contract to accept. Check [`ContractAgreementTerms`] for
         ///     supported types
-        #[weight = 10_000]
+        #[weight = T::DeipWeightInfo::reject_contract_agreement()]
         fn reject_contract_agreement(origin,
             id: ContractAgreementId,
             party: T::DeipAccountId,
@@ -936,19 +1811,464 @@ decl_module! {
             Self::reject_contract_agreement_impl(account, id, party.into())
         }
 
-        fn offchain_worker(_n: T::BlockNumber) {
+        // The position of a call sets its index in the encoded `Call`:
+        // new calls are appended at the end, never inserted between existing ones.
+
+        /// Allow a user to create [NDA](./struct.Nda.html).
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `end_date`: Unix Timestamp. Exparation date of contract
+        /// - `contract_hash`: Hash of the contract
+        /// - `maybe_start_date`: Optional. Unix Timestamp. Entry into force of the contract
+        /// - `parties`: List of involved Parties
+        /// - `projects`: List of involved ProjectMapV1
+        #[weight = {
+            let p = parties.len().max(projects.len()) as u32;
+            T::DeipWeightInfo::create_project_nda(p)
+        }]
+        fn create_project_nda(origin,
+            external_id: NdaId,
+            end_date: T::Moment,
+            contract_hash: T::Hash,
+            maybe_start_date: Option<T::Moment>,
+            parties: Vec<T::DeipAccountId>,
+            projects: Vec<ProjectId>
+        ) {
+            let contract_creator = ensure_signed(origin)?;
+            Self::create_project_nda_impl(contract_creator, external_id, end_date, contract_hash, maybe_start_date, parties, projects)?;
+        }
+
+        /// Create [request](./struct.NdaAccessRequest.html) to access NDA content
+        ///
+        /// The origin for this call must be _Signed_.
+        ///
+        /// - `external_id`: Reference for external world and uniques control
+        /// - `nda_external_id`: Reference to NDA
+        /// - `encrypted_payload_hash`: Payload witch need to be decrypted
+        /// - `encrypted_payload_iv`: IV of encrypted payload
+        #[weight = {
+            T::DeipWeightInfo::create_nda_content_access_request()
+        }]
+        fn create_nda_content_access_request(
+            origin,
+            external_id: NdaAccessRequestId,
+            nda_external_id: NdaId,
+            encrypted_payload_hash: T::Hash,
+            encrypted_payload_iv: Vec<u8>,
+        ) {
+            let account = ensure_signed(origin)?;
+            Self::create_nda_content_access_request_impl(account, external_id, nda_external_id, encrypted_payload_hash, encrypted_payload_iv)?;
+        }
+
+        /// Fulfill NDA access request
+        ///
+        /// The origin for this call must be _Signed_ by one of the NDA parties.
+        ///
+        /// - `external_id`: Reference for external world and uniques control
+        /// - `encrypted_payload_encryption_key`: Ecrypted key witch can decrypt payload
+        /// - `proof_of_encrypted_payload_encryption_key`: Proof that requester has access to the encrypted data with his key
+        #[weight = {
+            T::DeipWeightInfo::fulfill_nda_content_access_request()
+        }]
+        fn fulfill_nda_content_access_request(
+            origin,
+            external_id: NdaAccessRequestId,
+            encrypted_payload_encryption_key: Vec<u8>,
+            proof_of_encrypted_payload_encryption_key: Vec<u8>,
+        ) {
+            let account = ensure_signed(origin)?;
+            Self::fulfill_nda_content_access_request_impl(account, external_id, encrypted_payload_encryption_key, proof_of_encrypted_payload_encryption_key)?;
+        }
+
+        /// Reject NDA access request
+        ///
+        /// The origin for this call must be _Signed_ by one of the NDA parties.
+        ///
+        /// - `external_id`: Reference for external world and uniques control
+        #[weight = {
+            T::DeipWeightInfo::reject_nda_content_access_request()
+        }]
+        fn reject_nda_content_access_request(
+            origin,
+            external_id: NdaAccessRequestId,
+        ) {
+            let account = ensure_signed(origin)?;
+            Self::reject_nda_content_access_request_impl(account, external_id)?;
+        }
+
+        /// Add a new [Domain](./struct.Domain.html) to the registry.
+        ///
+        /// The origin for this call must be `DomainAdminOrigin`.
+        ///
+        /// - `external_id`: Domain identifier
+        /// - `name`: Human readable name
+        /// - `parent`: Optional. Parent Domain in the hierarchy
+        #[weight = {
+            T::DeipWeightInfo::add_domain()
+        }]
+        fn add_domain(origin,
+            external_id: DomainId,
+            name: Vec<u8>,
+            parent: Option<DomainId>,
+        ) -> DispatchResult {
+            T::DomainAdminOrigin::ensure_origin(origin)?;
+            Self::add_domain_impl(external_id, name, parent)
+        }
+
+        /// Change the name of the Domain.
+        ///
+        /// The origin for this call must be `DomainAdminOrigin`.
+        #[weight = {
+            T::DeipWeightInfo::rename_domain()
+        }]
+        fn rename_domain(origin, domain_id: DomainId, name: Vec<u8>) -> DispatchResult {
+            T::DomainAdminOrigin::ensure_origin(origin)?;
+            Self::rename_domain_impl(domain_id, name)
+        }
+
+        /// Move the Domain under another parent or to the top of the hierarchy.
+        ///
+        /// The origin for this call must be `DomainAdminOrigin`.
+        #[weight = {
+            T::DeipWeightInfo::set_domain_parent(T::MaxDomains::get())
+        }]
+        fn set_domain_parent(origin, domain_id: DomainId, parent: Option<DomainId>) -> DispatchResultWithPostInfo {
+            T::DomainAdminOrigin::ensure_origin(origin)?;
+            Self::set_domain_parent_impl(domain_id, parent)
+        }
+
+        /// Deprecate the Domain. Existing Projects and Reviews keep referencing it,
+        /// but it can't be assigned to new ones.
+        ///
+        /// The origin for this call must be `DomainAdminOrigin`.
+        #[weight = {
+            T::DeipWeightInfo::deprecate_domain(T::MaxDomains::get())
+        }]
+        fn deprecate_domain(origin, domain_id: DomainId) -> DispatchResultWithPostInfo {
+            T::DomainAdminOrigin::ensure_origin(origin)?;
+            Self::deprecate_domain_impl(domain_id)
+        }
+
+        /// Archive the project. Archived project stays queryable, but can't be updated
+        /// and doesn't accept new content, NDAs and licenses.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        #[weight = {
+            T::DeipWeightInfo::archive_project()
+        }]
+        fn archive_project(origin, project_id: ProjectId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::archive_project_impl(account, project_id)
+        }
+
+        /// Remove the project with its content and reviews, together with their
+        /// votes, indexes and storage deposits.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        ///
+        /// - `contents`: Witness. Upper bound of the number of project contents
+        /// - `reviews`: Witness. Upper bound of the number of project reviews
+        /// - `versions`: Witness. Upper bound of the number of versions of all project contents
+        ///
+        /// Fails while contract agreements, NDAs, open review requests or other pallets
+        /// (e.g. crowdfundings) reference the project.
+        #[weight = {
+            T::DeipWeightInfo::remove_project(*contents, *reviews, *versions)
+        }]
+        fn remove_project(origin, project_id: ProjectId, contents: u32, reviews: u32, versions: u32) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            Self::remove_project_impl(account, project_id, contents, reviews, versions)
+        }
+
+        /// Add a new version of the project content. Previous versions stay
+        /// queryable by their numbers.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        ///
+        /// - `external_id`: Project Content identifier
+        /// - `description`: Hash of the new version description
+        /// - `content`: Hash of the new version of digital asset
+        /// - `authors`: Authors of the new version
+        /// - `author_shares`: Optional. Contribution shares of the `authors`, equal if not set
+        #[weight = {
+            let a = authors.len() as u32;
+            T::DeipWeightInfo::update_project_content(a)
+        }]
+        fn update_project_content(origin,
+            external_id: ProjectContentId,
+            description: T::Hash,
+            content: T::Hash,
+            authors: Vec<T::DeipAccountId>,
+            author_shares: Option<Vec<Perbill>>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::update_project_content_impl(account, external_id, description, content, authors, author_shares)
+        }
+
+        /// Add a new [Assessment Model](./struct.AssessmentModel.html) to the registry.
+        ///
+        /// The origin for this call must be `AssessmentModelAdminOrigin`.
+        ///
+        /// - `external_id`: Assessment Model identifier
+        /// - `name`: Human readable name
+        /// - `criteria`: Scored criteria with the allowed score ranges
+        #[weight = {
+            let c = criteria.len() as u32;
+            T::DeipWeightInfo::add_assessment_model(c)
+        }]
+        fn add_assessment_model(origin,
+            external_id: AssessmentModelId,
+            name: Vec<u8>,
+            criteria: Vec<AssessmentCriterion>,
+        ) -> DispatchResult {
+            T::AssessmentModelAdminOrigin::ensure_origin(origin)?;
+            Self::add_assessment_model_impl(external_id, name, criteria)
+        }
+
+        /// Offer the project to another team. The transfer completes when the team
+        /// accepts the offer. A new offer replaces the previous one.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        ///
+        /// - `project_id`: [Project]((./struct.Project.html)) identifier
+        /// - `team_id`: Team the project is offered to
+        #[weight = {
+            T::DeipWeightInfo::offer_project_transfer()
+        }]
+        fn offer_project_transfer(origin, project_id: ProjectId, team_id: T::DeipAccountId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::offer_project_transfer_impl(account, project_id, team_id.into())
+        }
+
+        /// Cancel the project transfer offer.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        #[weight = {
+            T::DeipWeightInfo::cancel_project_transfer()
+        }]
+        fn cancel_project_transfer(origin, project_id: ProjectId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::cancel_project_transfer_impl(account, project_id)
+        }
+
+        /// Accept the project transfer offer and become the project team.
+        ///
+        /// The origin for this call must be _Signed_ by the team the project is offered to.
+        #[weight = {
+            T::DeipWeightInfo::accept_project_transfer()
+        }]
+        fn accept_project_transfer(origin, project_id: ProjectId) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::accept_project_transfer_impl(account, project_id)
+        }
+
+        /// Moves the pending contract agreements, which expiration time has come,
+        /// to the `Expired` state.
+        ///
+        /// The call is _Unsigned_ and submitted by the offchain worker.
+        /// - `ids` - at most `MaxExpiredContractAgreements` agreements
+        #[weight = (
+            T::DeipWeightInfo::expire_contract_agreement().saturating_mul(ids.len() as Weight),
+            DispatchClass::Normal,
+            Pays::No
+        )]
+        #[transactional]
+        fn expire_contract_agreements(origin, ids: Vec<ContractAgreementId>) -> DispatchResult {
+            ensure_none(origin)?;
+            Self::expire_contract_agreements_impl(ids)
+        }
+
+        /// Allows an approver to approve the milestone of the accepted escrow contract agreement.
+        /// The milestone amount is paid to the payee once all approvers approve the milestone.
+        ///
+        /// The origin for this call must be _Signed_.
+        /// - `id` - identifies the escrow contract agreement
+        /// - `milestone` - index of the milestone in the contract terms
+        #[weight = {
+            T::DeipWeightInfo::approve_contract_milestone()
+        }]
+        fn approve_contract_milestone(origin,
+            id: ContractAgreementId,
+            milestone: u32,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::approve_contract_milestone_impl(account, id, milestone)
+        }
+
+        /// Mint the F-NFT item of the project into the `collection` owned by the project team.
+        /// The item fingerprint is derived from the project id, and the item holder
+        /// has the ownership rights of the project from now on.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        ///
+        /// - `project_id`: [Project]((./struct.Project.html)) identifier
+        /// - `collection`: F-NFT collection of the team
+        #[weight = {
+            T::DeipWeightInfo::mint_project_nft()
+        }]
+        fn mint_project_nft(origin, project_id: ProjectId, collection: H160) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::mint_project_nft_impl(account, project_id, collection)
+        }
+
+        /// Add a new [Content Type](./struct.ContentType.html) to the registry.
+        ///
+        /// The origin for this call must be `ContentTypeAdminOrigin`.
+        ///
+        /// - `external_id`: Content Type identifier
+        /// - `name`: Human readable name
+        /// - `finalizes_project`: Content of this type finishes the Project
+        #[weight = {
+            T::DeipWeightInfo::add_content_type()
+        }]
+        fn add_content_type(origin,
+            external_id: ContentTypeId,
+            name: Vec<u8>,
+            finalizes_project: bool,
+        ) -> DispatchResult {
+            T::ContentTypeAdminOrigin::ensure_origin(origin)?;
+            Self::add_content_type_impl(external_id, name, finalizes_project)
+        }
+
+        /// Deprecate the Content Type. Existing content keeps referencing it,
+        /// but it can't be assigned to new content.
+        ///
+        /// The origin for this call must be `ContentTypeAdminOrigin`.
+        #[weight = {
+            T::DeipWeightInfo::deprecate_content_type()
+        }]
+        fn deprecate_content_type(origin, external_id: ContentTypeId) -> DispatchResult {
+            T::ContentTypeAdminOrigin::ensure_origin(origin)?;
+            Self::deprecate_content_type_impl(external_id)
+        }
+
+        /// Set the license of the project content. Once set, the license can only be
+        /// replaced with a license that grants at least the same rights.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        #[weight = {
+            T::DeipWeightInfo::set_project_content_license()
+        }]
+        fn set_project_content_license(origin,
+            external_id: ProjectContentId,
+            license: ContentLicenseOf<T>,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::set_project_content_license_impl(account, external_id, license)
+        }
+
+        /// Escrow a reward for a review of the project content. The reward is reserved
+        /// from the project team.
+        ///
+        /// The origin for this call must be _Signed_ by the project team.
+        ///
+        /// - `project_content_id`: Project Content to review
+        /// - `domain_id`: Domain the review must be upvoted in
+        /// - `reward`: Reward of the review author
+        /// - `required_upvotes`: Number of upvotes in the domain required to claim the reward
+        /// - `deadline`: Unix Timestamp. The reward is refunded if not claimed by this moment
+        #[weight = {
+            T::DeipWeightInfo::create_review_request()
+        }]
+        fn create_review_request(origin,
+            external_id: ReviewRequestId,
+            project_content_id: ProjectContentId,
+            domain_id: DomainId,
+            reward: BalanceOf<T>,
+            required_upvotes: u32,
+            deadline: T::Moment,
+        ) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            Self::create_review_request_impl(account, external_id, project_content_id, domain_id, reward, required_upvotes, deadline)
+        }
+
+        /// Pay the reward of the review request to the author of the review
+        /// upvoted enough times in the requested domain.
+        ///
+        /// The origin for this call must be _Signed_ by any account.
+        #[weight = {
+            T::DeipWeightInfo::claim_review_reward()
+        }]
+        fn claim_review_reward(origin,
+            request_id: ReviewRequestId,
+            review_id: ReviewId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::claim_review_reward_impl(request_id, review_id)
+        }
+
+        /// Return the unclaimed reward to the requester after the deadline.
+        ///
+        /// The origin for this call must be _Signed_ by any account.
+        #[weight = {
+            T::DeipWeightInfo::refund_review_request()
+        }]
+        fn refund_review_request(origin, request_id: ReviewRequestId) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::refund_review_request_impl(request_id)
+        }
+
+        fn offchain_worker(n: T::BlockNumber) {
             if !sp_io::offchain::is_validator() {
                 return;
             }
+            let period = T::ContractAgreementExpirePeriod::get();
+            if period.is_zero() || !(n % period).is_zero() {
+                return;
+            }
+            Self::expire_contract_agreements_offchain();
+        }
+    }
+}
+
+const NON_LOCAL: u8 = 100;
+
+impl<T: Config> ValidateUnsigned for Module<T> {
+    type Call = Call<T>;
+
+    /// Validate unsigned call to this module.
+    ///
+    /// By default unsigned transactions are disallowed, but implementing the validator
+    /// here we make sure that some particular calls (the ones produced by offchain worker)
+    /// are being whitelisted and marked as valid.
+    fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+        // Firstly let's check that we get the local transaction.
+        if !matches!(source, TransactionSource::Local | TransactionSource::InBlock) {
+            return InvalidTransaction::Custom(NON_LOCAL).into()
+        }
+
+        if let Call::expire_contract_agreements(ids) = call {
+            if ids.is_empty() || ids.len() > T::MaxExpiredContractAgreements::get() as usize {
+                return InvalidTransaction::Call.into()
+            }
+            let now = pallet_timestamp::Pallet::<T>::get();
+            for id in ids {
+                let agreement = ContractAgreementMap::<T>::try_get(id)
+                    .map_err(|_| InvalidTransaction::Stale)?;
+                if !agreement.is_expired(&now) {
+                    return InvalidTransaction::Stale.into()
+                }
+            }
+
+            ids.iter()
+                .fold(
+                    ValidTransaction::with_tag_prefix("DeipOffchainWorker")
+                        .propagate(false)
+                        .longevity(5),
+                    |tx, id| tx.and_provides((b"expire_contract_agreement", *id)),
+                )
+                .build()
+        } else {
+            InvalidTransaction::Call.into()
         }
     }
 }
 
 impl<T: Config> Module<T> {
-    fn is_project_finished(project_id: &ProjectId) -> bool {
-        ContentIdByProjectIdV1::iter_prefix(project_id)
-            .map(|(k, _)| ProjectContentMapV1::<T>::get(k))
-            .any(|c| c.content_type == ProjectContentType::FinalResult)
+    /// Project with the final result content doesn't accept new content
+    pub fn is_project_finished(project_id: &ProjectId) -> bool {
+        FinishedProjectV1::contains_key(project_id)
     }
 
     pub fn get_project(project_id: &ProjectId) -> Option<ProjectOf<T>> {
@@ -958,10 +2278,15 @@ impl<T: Config> Module<T> {
     pub fn try_get_project_team(id: &ProjectId) -> Option<AccountIdOf<T>> {
         match ProjectMapV1::<T>::try_get(*id) {
             Err(_) => None,
-            Ok(project) => Some(project.team_id),
+            Ok(project) => Some(Self::project_owner(&project)),
         }
     }
 
+    /// Holder of the project item if the project is tokenized, the project team otherwise
+    pub fn project_owner(project: &ProjectOf<T>) -> AccountIdOf<T> {
+        T::ProjectNft::holder(project.external_id).unwrap_or_else(|| project.team_id.clone())
+    }
+
     pub fn get_domain(domain_id: &DomainId) -> Option<Domain> {
         Domains::try_get(domain_id).ok()
     }
@@ -970,45 +2295,177 @@ impl<T: Config> Module<T> {
         ProjectContentMapV1::<T>::try_get(id).ok()
     }
 
-    pub fn get_nda(nda_id: &NdaId) -> Option<NdaOf<T>> {
-        NdaMapV1::<T>::try_get(nda_id).ok()
+    pub fn get_project_content_version(id: &ProjectContentId, version: u32) -> Option<ProjectContentVersionOf<T>> {
+        ProjectContentVersionMapV1::<T>::try_get(id, version).ok()
     }
 
-    pub fn get_review(id: &ReviewId) -> Option<ReviewOf<T>> {
-        ReviewMapV1::<T>::try_get(id).ok()
+    /// Project Contents citing the `id`, at most `count` items after `start_id`
+    pub fn get_content_citations(
+        id: &ProjectContentId,
+        start_id: Option<ProjectContentId>,
+        count: u32,
+    ) -> Option<Vec<ProjectContentId>> {
+        Self::paged_index::<CitedByV1, _, _>(id, start_id, count)
     }
 
-    pub fn get_contract_agreement(id: &ContractAgreementId) -> Option<ContractAgreementOf<T>> {
-        ContractAgreementMap::<T>::try_get(id).ok()
+    /// Projects of the team, at most `count` items after `start_id`
+    pub fn get_project_list_by_team(
+        team_id: &AccountIdOf<T>,
+        start_id: Option<ProjectId>,
+        count: u32,
+    ) -> Option<Vec<(ProjectId, ProjectOf<T>)>> {
+        Self::paged_index::<ProjectIdByTeamIdV1<T>, _, _>(team_id, start_id, count).map(|ids| {
+            ids.into_iter()
+                .filter_map(|id| Self::get_project(&id).map(|project| (id, project)))
+                .collect()
+        })
+    }
+
+    /// Contents of the project, at most `count` items after `start_id`
+    pub fn get_project_content_list_by_project(
+        project_id: &ProjectId,
+        start_id: Option<ProjectContentId>,
+        count: u32,
+    ) -> Option<Vec<(ProjectContentId, ProjectContentOf<T>)>> {
+        Self::paged_index::<ContentIdByProjectIdV1, _, _>(project_id, start_id, count).map(|ids| {
+            ids.into_iter()
+                .filter_map(|id| Self::get_project_content(&id).map(|content| (id, content)))
+                .collect()
+        })
+    }
+
+    /// Reviews of the project content, at most `count` items after `start_id`
+    pub fn get_review_list_by_project_content(
+        id: &ProjectContentId,
+        start_id: Option<ReviewId>,
+        count: u32,
+    ) -> Option<Vec<(ReviewId, ReviewOf<T>)>> {
+        Self::paged_index::<ReviewIdByContentIdV1, _, _>(id, start_id, count).map(|ids| {
+            ids.into_iter()
+                .filter_map(|id| Self::get_review(&id).map(|review| (id, review)))
+                .collect()
+        })
+    }
+
+    /// Projects tagged with the domain, at most `count` items after `start_id`
+    pub fn get_project_list_by_domain(
+        domain_id: &DomainId,
+        start_id: Option<ProjectId>,
+        count: u32,
+    ) -> Option<Vec<(ProjectId, ProjectOf<T>)>> {
+        Self::paged_index::<ProjectIdByDomainIdV1, _, _>(domain_id, start_id, count).map(|ids| {
+            ids.into_iter()
+                .filter_map(|id| Self::get_project(&id).map(|project| (id, project)))
+                .collect()
+        })
+    }
+
+    /// Contents of the projects tagged with the domain, at most `count` items after `start_id`
+    pub fn get_project_content_list_by_domain(
+        domain_id: &DomainId,
+        start_id: Option<ProjectContentId>,
+        count: u32,
+    ) -> Option<Vec<(ProjectContentId, ProjectContentOf<T>)>> {
+        Self::paged_index::<ContentIdByDomainIdV1, _, _>(domain_id, start_id, count).map(|ids| {
+            ids.into_iter()
+                .filter_map(|id| Self::get_project_content(&id).map(|content| (id, content)))
+                .collect()
+        })
+    }
+
+    /// Reviews tagged with the domain, at most `count` items after `start_id`
+    pub fn get_review_list_by_domain(
+        domain_id: &DomainId,
+        start_id: Option<ReviewId>,
+        count: u32,
+    ) -> Option<Vec<(ReviewId, ReviewOf<T>)>> {
+        Self::paged_index::<ReviewIdByDomainIdV1, _, _>(domain_id, start_id, count).map(|ids| {
+            ids.into_iter()
+                .filter_map(|id| Self::get_review(&id).map(|review| (id, review)))
+                .collect()
+        })
     }
 
-    // /// Allow a user to create domains.
-    // ///
-    // /// The origin for this call must be _Signed_.
-    // ///
-    // /// - `project`: [Domain](./struct.Domain.html) to be created.
-    // #[weight = {
-    //     T::DeipWeightInfo::add_domain()
-    // }]
-    #[allow(dead_code)]
-    fn add_domain(origin: OriginFor<T>, domain: Domain) -> DispatchResult {
-        let account = ensure_signed(origin)?;
+    /// Contract agreements of the type, at most `count` items after `start_id`
+    pub fn get_contract_agreement_list_by_type(
+        terms: &ContractAgreementIndexTerms,
+        start_id: Option<ContractAgreementId>,
+        count: u32,
+    ) -> Option<Vec<(ContractAgreementId, ContractAgreementOf<T>)>> {
+        Self::paged_index::<ContractAgreementIdByType, _, _>(terms, start_id, count).map(|ids| {
+            ids.into_iter()
+                .filter_map(|id| Self::get_contract_agreement(&id).map(|agreement| (id, agreement)))
+                .collect()
+        })
+    }
 
-        let domain_count = DomainCount::get();
-        ensure!(domain_count < MAX_DOMAINS, Error::<T>::DomainLimitReached);
+    pub fn get_content_citation_count(id: &ProjectContentId) -> u32 {
+        CitationCountV1::get(id)
+    }
 
-        let external_id = domain.external_id;
+    /// Returns `true` if the citation is new
+    fn add_citation(cited: ProjectContentId, citing: ProjectContentId) -> bool {
+        if CitedByV1::contains_key(cited, citing) {
+            return false
+        }
+        CitedByV1::insert(cited, citing, ());
+        CitationCountV1::mutate(cited, |count| *count = count.saturating_add(1));
+        true
+    }
+
+    fn remove_citation(cited: ProjectContentId, citing: ProjectContentId) {
+        if CitedByV1::contains_key(cited, citing) {
+            CitedByV1::remove(cited, citing);
+            CitationCountV1::mutate_exists(cited, |count| {
+                *count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0)
+            });
+        }
+    }
 
-        // We don't want to add duplicate domains, so we check whether the potential new
-        // domain is already present in the list. Because the domains is stored as a hash
-        // map this check is constant time O(1)
-        ensure!(!Domains::contains_key(&external_id), Error::<T>::DomainAlreadyExists);
+    /// Keys of the index page: at most `count` items after `start_id`,
+    /// `None` if `start_id` is not in the index
+    fn paged_index<S, K1, K2>(k1: &K1, start_id: Option<K2>, count: u32) -> Option<Vec<K2>>
+    where
+        S: IterableStorageDoubleMap<K1, K2, ()>,
+        K1: FullCodec,
+        K2: FullCodec,
+    {
+        let keys = match start_id {
+            Some(start_id) => {
+                if !S::contains_key(k1, &start_id) {
+                    return None
+                }
+                S::iter_prefix_from(k1, S::hashed_key_for(k1, &start_id))
+            },
+            None => S::iter_prefix(k1),
+        };
+        Some(keys.map(|(k, _)| k).take(count as usize).collect())
+    }
 
-        // Insert the new domin and emit the event
-        Domains::insert(&external_id, domain);
-        DomainCount::put(domain_count + 1); // overflow check not necessary because of maximum
+    pub fn get_assessment_model(id: &AssessmentModelId) -> Option<AssessmentModel> {
+        AssessmentModelMapV1::try_get(id).ok()
+    }
 
-        Self::deposit_event(RawEvent::DomainAdded(account, external_id));
-        Ok(())
+    pub fn get_nda(nda_id: &NdaId) -> Option<NdaOf<T>> {
+        NdaMapV1::<T>::try_get(nda_id).ok()
+    }
+
+    pub fn get_review(id: &ReviewId) -> Option<ReviewOf<T>> {
+        ReviewMapV1::<T>::try_get(id).ok()
+    }
+
+    pub fn get_expertise(account: &AccountIdOf<T>, domain_id: &DomainId) -> u64 {
+        Self::expertise_at(account, domain_id, pallet_timestamp::Pallet::<T>::get())
+    }
+
+    pub fn get_account_expertise(account: &AccountIdOf<T>) -> Vec<(DomainId, u64)> {
+        let now = pallet_timestamp::Pallet::<T>::get();
+        ExpertiseV1::<T>::iter_prefix(account)
+            .map(|(domain_id, expertise)| (domain_id, Self::decay_expertise(expertise, now).0))
+            .collect()
+    }
+
+    pub fn get_contract_agreement(id: &ContractAgreementId) -> Option<ContractAgreementOf<T>> {
+        ContractAgreementMap::<T>::try_get(id).ok()
     }
 }
@@ -1,16 +1,18 @@
 use crate as pallet_deip;
-use frame_support::{parameter_types, traits::Get};
+use frame_support::{dispatch::DispatchResult, parameter_types, traits::Get};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
     testing::{Header, TestXt},
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{BlakeTwo256, Dispatchable, IdentityLookup},
+    DispatchResultWithInfo, Perbill,
 };
 
-use codec::{Decode, Encode};
-use serde::{Deserialize, Serialize};
-
-use deip_assets_error::{ReserveError, UnreserveError};
+use deip_transaction_ctx::{ctx_t, PortalCtxT, TransactionCtx, TransactionCtxId, TransactionCtxT};
 
 pub const DEFAULT_ACCOUNT_ID: <Test as system::Config>::AccountId = 123;
 pub const ALICE_ACCOUNT_ID: <Test as system::Config>::AccountId = 124;
@@ -25,10 +27,6 @@ type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 type Balance = u128;
 type AccountId = u64;
-type AssetId = u32;
-
-#[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
-pub struct DeipAssetId(pub AssetId);
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -40,9 +38,7 @@ frame_support::construct_runtime!(
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
         Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
-        Deip: pallet_deip::{Pallet, Call, Storage, Event<T>, Config},
-        Assets: pallet_assets::{Pallet, Storage, Event<T>},
-        DeipAssets: pallet_deip_assets::{Pallet, Storage},
+        Deip: pallet_deip::{Pallet, Call, Storage, Event<T>, Config, ValidateUnsigned},
     }
 );
 
@@ -95,66 +91,145 @@ impl pallet_balances::Config for Test {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const MaxNdaAccessRequests: u32 = 3;
+    pub const MaxDomains: u32 = 100;
+    pub const MaxDomainNameLength: u32 = 32;
+    pub const MaxContentTypeNameLength: u32 = 32;
+    pub const MaxAssessmentCriteria: u32 = 3;
+    pub const MaxAssessmentNameLength: u32 = 32;
+    pub const ExpertiseUpvoteReward: u64 = 100;
+    pub const VoterExpertiseShare: Perbill = Perbill::from_percent(10);
+    pub const ExpertiseDecay: Perbill = Perbill::from_percent(50);
+    pub const ExpertiseDecayPeriod: u64 = 86_400_000;
+    pub const ContractAgreementExpirePeriod: u64 = 1;
+    pub const MaxContractMilestones: u32 = 3;
+    pub const MaxExpiredContractAgreements: u32 = 2;
+    pub static DepositBase: Balance = 0;
+    pub static DepositPerByte: Balance = 0;
+    pub const MaxProjectDomains: u32 = 3;
+    pub const MaxReviewDomains: u32 = 3;
+    pub const MaxContentAuthors: u32 = 3;
+    pub const MaxContentReferences: u32 = 3;
+    pub const MaxNdaParties: u16 = 3;
+}
+
 impl pallet_deip::Config for Test {
     type Event = Event;
     type DeipAccountId = Self::AccountId;
     type Currency = Balances;
+    type TransactionCtx = TransactionCtxMock<TransactionCtx<Self>>;
+    type DeipWeightInfo = pallet_deip::Weights<Self>;
+    type MaxNdaParties = MaxNdaParties;
+    type MaxNdaAccessRequests = MaxNdaAccessRequests;
+    type DomainAdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type MaxDomains = MaxDomains;
+    type MaxDomainNameLength = MaxDomainNameLength;
+    type ContentTypeAdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type MaxContentTypeNameLength = MaxContentTypeNameLength;
+    type ProjectReferences = ();
+    type ProjectNft = ProjectNftMock;
+    type DaoLookup = DaoLookupMock;
+    type AssessmentModelAdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type MaxAssessmentCriteria = MaxAssessmentCriteria;
+    type MaxAssessmentNameLength = MaxAssessmentNameLength;
+    type ExpertiseUpvoteReward = ExpertiseUpvoteReward;
+    type VoterExpertiseShare = VoterExpertiseShare;
+    type ExpertiseDecay = ExpertiseDecay;
+    type ExpertiseDecayPeriod = ExpertiseDecayPeriod;
+    type ContractAgreementExpirePeriod = ContractAgreementExpirePeriod;
+    type MaxContractMilestones = MaxContractMilestones;
+    type MaxExpiredContractAgreements = MaxExpiredContractAgreements;
+    type DepositBase = DepositBase;
+    type DepositPerByte = DepositPerByte;
+    type MaxProjectDomains = MaxProjectDomains;
+    type MaxReviewDomains = MaxReviewDomains;
+    type MaxContentAuthors = MaxContentAuthors;
+    type MaxContentReferences = MaxContentReferences;
 }
 
-parameter_types! {
-    pub const MinimumPeriod: u64 = 5;
+ctx_t!(TransactionCtxMock);
+
+/// Dispatches calls right away as there is no portal to tag them with
+impl PortalCtxT<pallet_deip::Call<Test>> for TransactionCtxMock<TransactionCtx<Test>> {
+    type PortalId = ();
+
+    fn portal_id(_ctx: &TransactionCtxId<Self>) -> Self::PortalId {}
+
+    fn dispatch<D: Dispatchable>(
+        &self,
+        _portal_id: Self::PortalId,
+        call: D,
+        origin: D::Origin,
+    ) -> DispatchResultWithInfo<D::PostInfo> {
+        call.dispatch(origin)
+    }
+
+    fn submit_postponed(
+        _call: pallet_deip::Call<Test>,
+        _ctx: TransactionCtxId<Self>,
+    ) -> Result<(), ()> {
+        Err(())
+    }
 }
 
-impl pallet_timestamp::Config for Test {
-    type Moment = u64;
-    type OnTimestampSet = ();
-    type MinimumPeriod = MinimumPeriod;
-    type WeightInfo = ();
+thread_local! {
+    static PROJECT_NFT_HOLDERS: RefCell<BTreeMap<pallet_deip::ProjectId, AccountId>> =
+        RefCell::new(BTreeMap::new());
 }
 
-parameter_types! {
-    pub const AssetDeposit: Balance = 0;
-    pub const ApprovalDeposit: Balance = 0;
-    pub const StringLimit: u32 = 50;
-    pub const MetadataDepositBase: Balance = 0;
-    pub const MetadataDepositPerByte: Balance = 0;
+/// Project items kept in memory instead of the F-NFT pallet
+pub struct ProjectNftMock;
+
+impl ProjectNftMock {
+    pub fn transfer(project_id: pallet_deip::ProjectId, to: AccountId) {
+        PROJECT_NFT_HOLDERS.with(|holders| holders.borrow_mut().insert(project_id, to));
+    }
 }
 
-impl pallet_assets::Config for Test {
-    type Event = Event;
-    type Balance = u64;
-    type AssetId = AssetId;
-    type Currency = Balances;
-    type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
-    type AssetDeposit = AssetDeposit;
-    type StringLimit = StringLimit;
-    type MetadataDepositBase = MetadataDepositBase;
-    type MetadataDepositPerByte = MetadataDepositPerByte;
-    type ApprovalDeposit = ApprovalDeposit;
-    type Freezer = ();
-    type Extra = ();
-    type WeightInfo = pallet_assets::weights::SubstrateWeight<Test>;
+impl pallet_deip::ProjectNftT<AccountId> for ProjectNftMock {
+    fn mint(
+        project_id: pallet_deip::ProjectId,
+        _collection: sp_core::H160,
+        owner: &AccountId,
+    ) -> DispatchResult {
+        Self::transfer(project_id, *owner);
+        Ok(())
+    }
+
+    fn holder(project_id: pallet_deip::ProjectId) -> Option<AccountId> {
+        PROJECT_NFT_HOLDERS.with(|holders| holders.borrow().get(&project_id).copied())
+    }
 }
 
-parameter_types! {
-    pub const WipePeriod: u64 = 10;
+thread_local! {
+    static DAO_ACCOUNTS: RefCell<BTreeSet<AccountId>> = RefCell::new(BTreeSet::new());
 }
 
-impl DeipProjectsInfo<AccountId> for Test {
-    type ProjectId = pallet_deip::ProjectId;
-    type InvestmentId = pallet_deip::InvestmentId;
+/// DAO accounts kept in memory instead of the DAO pallet
+pub struct DaoLookupMock;
 
-    fn try_get_project_team(id: &Self::ProjectId) -> Option<AccountId> {
-        Deip::try_get_project_team(id)
+impl DaoLookupMock {
+    pub fn register(account: AccountId) {
+        DAO_ACCOUNTS.with(|accounts| accounts.borrow_mut().insert(account));
     }
 }
 
-impl pallet_deip_assets::Config for Test {
-    type ProjectsInfo = Self;
-    type DeipAccountId = Self::AccountId;
-    type AssetsAssetId = AssetId;
-    type AssetId = DeipAssetId;
-    type WipePeriod = WipePeriod;
+impl pallet_deip::DaoLookupT<AccountId> for DaoLookupMock {
+    fn is_dao(account: &AccountId) -> bool {
+        DAO_ACCOUNTS.with(|accounts| accounts.borrow().contains(account))
+    }
+}
+
+parameter_types! {
+    pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
 }
 
 impl<LocalCall> system::offchain::SendTransactionTypes<LocalCall> for Test
@@ -167,7 +242,9 @@ where
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+    let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+    pallet_deip::GenesisConfig::default().assimilate_storage::<Test>(&mut t).unwrap();
+    t.into()
 }
 
 pub fn new_test_ext2() -> sp_io::TestExternalities {
@@ -181,6 +258,7 @@ pub fn new_test_ext2() -> sp_io::TestExternalities {
     }
     .assimilate_storage(&mut t)
     .unwrap();
+    pallet_deip::GenesisConfig::default().assimilate_storage::<Test>(&mut t).unwrap();
 
     let mut ext = sp_io::TestExternalities::new(t);
     ext.execute_with(|| {
@@ -0,0 +1,183 @@
+use super::*;
+
+impl<T: Config> Module<T> {
+    pub(super) fn create_project_nda_impl(
+        contract_creator: T::AccountId,
+        external_id: NdaId,
+        end_date: T::Moment,
+        contract_hash: T::Hash,
+        maybe_start_date: Option<T::Moment>,
+        parties: Vec<T::DeipAccountId>,
+        projects: Vec<ProjectId>,
+    ) -> DispatchResult {
+        let mut projects = projects;
+        projects.sort();
+        projects.dedup();
+        let timestamp = pallet_timestamp::Pallet::<T>::get();
+
+        ensure!(end_date > timestamp, Error::<T>::NdaEndDateMustBeLaterCurrentMoment);
+
+        ensure!(projects.len() <= T::MaxNdaParties::get() as usize, Error::<T>::TooMuchNdaParties);
+        ensure!(parties.len() <= T::MaxNdaParties::get() as usize, Error::<T>::TooMuchNdaParties);
+
+        if let Some(start_date) = maybe_start_date {
+            ensure!(
+                start_date >= timestamp,
+                Error::<T>::NdaStartDateMustBeLaterOrEqualCurrentMoment
+            );
+            ensure!(end_date > start_date, Error::<T>::NdaStartDateMustBeLessThanEndDate);
+        }
+
+        let parties: Vec<T::AccountId> = parties.into_iter().map(Into::into).collect();
+
+        projects.iter().try_for_each(|id| -> DispatchResult {
+            let project =
+                ProjectMapV1::<T>::try_get(id).map_err(|_| Error::<T>::NoSuchProject)?;
+            ensure!(
+                parties.contains(&Self::project_owner(&project)),
+                Error::<T>::TeamOfAllProjectsMustSpecifiedAsParty
+            );
+            ensure!(!project.is_archived, Error::<T>::ProjectIsArchived);
+
+            Ok(())
+        })?;
+
+        ensure!(!NdaMapV1::<T>::contains_key(external_id), Error::<T>::NdaAlreadyExists);
+
+        let nda = Nda {
+            contract_creator: contract_creator.clone(),
+            external_id,
+            end_date,
+            start_date: maybe_start_date,
+            contract_hash,
+            parties,
+            projects,
+        };
+
+        for project_id in &nda.projects {
+            NdaIdByProjectIdV1::insert(project_id, nda.external_id, ());
+        }
+        NdaMapV1::<T>::insert(nda.external_id, nda);
+
+        // Emit an event that the NDA was created.
+        Self::deposit_event(RawEvent::NdaCreated(contract_creator, external_id));
+
+        Ok(())
+    }
+
+    pub(super) fn create_nda_content_access_request_impl(
+        account: T::AccountId,
+        external_id: NdaAccessRequestId,
+        nda_external_id: NdaId,
+        encrypted_payload_hash: T::Hash,
+        encrypted_payload_iv: Vec<u8>,
+    ) -> DispatchResult {
+        let timestamp = pallet_timestamp::Pallet::<T>::get();
+
+        let nda = NdaMapV1::<T>::try_get(nda_external_id).map_err(|_| Error::<T>::NoSuchNda)?;
+
+        ensure!(nda.start_date <= Some(timestamp), Error::<T>::NdaContractIsNotActiveYet);
+        ensure!(nda.end_date > timestamp, Error::<T>::NdaContractIsExpired);
+
+        ensure!(
+            !NdaAccessRequestMapV1::<T>::contains_key(external_id),
+            Error::<T>::NdaAccessRequestAlreadyExists
+        );
+
+        NdaAccessRequestIdsByNdaIdV1::<T>::try_mutate(nda_external_id, |ids| {
+            ids.try_push(external_id).map_err(|_| Error::<T>::TooMuchNdaAccessRequests)
+        })?;
+
+        let nda_request = NdaAccessRequest {
+            external_id,
+            nda_external_id,
+            requester: account.clone(),
+            encrypted_payload_hash,
+            encrypted_payload_iv,
+            status: NdaAccessRequestStatus::Pending,
+            grantor: None,
+            encrypted_payload_encryption_key: None,
+            proof_of_encrypted_payload_encryption_key: None,
+        };
+
+        NdaAccessRequestMapV1::<T>::insert(nda_request.external_id, nda_request);
+
+        // Emit an event that the NDA access request was created.
+        Self::deposit_event(RawEvent::NdaAccessRequestCreated(account, external_id));
+
+        Ok(())
+    }
+
+    pub(super) fn fulfill_nda_content_access_request_impl(
+        account: T::AccountId,
+        external_id: NdaAccessRequestId,
+        encrypted_payload_encryption_key: Vec<u8>,
+        proof_of_encrypted_payload_encryption_key: Vec<u8>,
+    ) -> DispatchResult {
+        NdaAccessRequestMapV1::<T>::try_mutate_exists(
+            external_id,
+            |maybe_nda_access_request| -> DispatchResult {
+                let nda_access_request = maybe_nda_access_request
+                    .as_mut()
+                    .ok_or(Error::<T>::NoSuchNdaAccessRequest)?;
+
+                ensure!(
+                    nda_access_request.status == NdaAccessRequestStatus::Pending,
+                    Error::<T>::NdaAccessRequestAlreadyFinalized
+                );
+                Self::ensure_nda_grantor(&account, &nda_access_request.nda_external_id)?;
+
+                nda_access_request.status = NdaAccessRequestStatus::Fulfilled;
+                nda_access_request.grantor = Some(account.clone());
+                nda_access_request.encrypted_payload_encryption_key =
+                    Some(encrypted_payload_encryption_key);
+                nda_access_request.proof_of_encrypted_payload_encryption_key =
+                    Some(proof_of_encrypted_payload_encryption_key);
+
+                Ok(())
+            },
+        )?;
+
+        // Emit an event that the NDA access request was fulfilled.
+        Self::deposit_event(RawEvent::NdaAccessRequestFulfilled(account, external_id));
+
+        Ok(())
+    }
+
+    pub(super) fn reject_nda_content_access_request_impl(
+        account: T::AccountId,
+        external_id: NdaAccessRequestId,
+    ) -> DispatchResult {
+        NdaAccessRequestMapV1::<T>::try_mutate_exists(
+            external_id,
+            |maybe_nda_access_request| -> DispatchResult {
+                let nda_access_request = maybe_nda_access_request
+                    .as_mut()
+                    .ok_or(Error::<T>::NoSuchNdaAccessRequest)?;
+
+                ensure!(
+                    nda_access_request.status == NdaAccessRequestStatus::Pending,
+                    Error::<T>::NdaAccessRequestAlreadyFinalized
+                );
+                Self::ensure_nda_grantor(&account, &nda_access_request.nda_external_id)?;
+
+                nda_access_request.status = NdaAccessRequestStatus::Rejected;
+
+                Ok(())
+            },
+        )?;
+
+        // Emit an event that the NDA access request was rejected.
+        Self::deposit_event(RawEvent::NdaAccessRequestRejected(account, external_id));
+
+        Ok(())
+    }
+
+    fn ensure_nda_grantor(account: &T::AccountId, nda_id: &NdaId) -> DispatchResult {
+        let nda = NdaMapV1::<T>::try_get(nda_id).map_err(|_| Error::<T>::NoSuchNda)?;
+
+        ensure!(nda.parties.contains(account), Error::<T>::NdaAccessRequestGrantorIsNotParty);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use super::*;
+use deip_asset_system::{
+    mint_item, pick_item, NFTImplT, NFTokenItemT, OpaqueUnique, Unique, UniqueProject,
+};
+use sp_std::marker::PhantomData;
+
+impl<T: Config> Module<T> {
+    pub(super) fn archive_project_impl(account: T::AccountId, project_id: ProjectId) -> DispatchResult {
+        ProjectMapV1::<T>::mutate_exists(project_id, |maybe_project| -> DispatchResult {
+            let project = maybe_project.as_mut().ok_or(Error::<T>::NoSuchProject)?;
+
+            ensure!(Self::project_owner(project) == account, Error::<T>::NoPermission);
+            ensure!(!project.is_archived, Error::<T>::ProjectIsArchived);
+
+            project.is_archived = true;
+
+            Ok(())
+        })?;
+
+        Self::deposit_event(RawEvent::ProjectArchived(account, project_id));
+
+        Ok(())
+    }
+
+    pub(super) fn offer_project_transfer_impl(
+        account: T::AccountId,
+        project_id: ProjectId,
+        team_id: T::AccountId,
+    ) -> DispatchResult {
+        let project =
+            ProjectMapV1::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+
+        ensure!(project.team_id == account, Error::<T>::NoPermission);
+        ensure!(!project.is_archived, Error::<T>::ProjectIsArchived);
+        ensure!(T::ProjectNft::holder(project_id).is_none(), Error::<T>::ProjectIsTokenized);
+        ensure!(team_id != account, Error::<T>::ProjectTransferToSameTeam);
+
+        ProjectTransferOfferV1::<T>::insert(project_id, team_id.clone());
+
+        Self::deposit_event(RawEvent::ProjectTransferOffered(project_id, account, team_id));
+
+        Ok(())
+    }
+
+    pub(super) fn cancel_project_transfer_impl(
+        account: T::AccountId,
+        project_id: ProjectId,
+    ) -> DispatchResult {
+        let project =
+            ProjectMapV1::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+
+        ensure!(project.team_id == account, Error::<T>::NoPermission);
+        ensure!(
+            ProjectTransferOfferV1::<T>::contains_key(project_id),
+            Error::<T>::NoSuchProjectTransferOffer
+        );
+
+        ProjectTransferOfferV1::<T>::remove(project_id);
+
+        Self::deposit_event(RawEvent::ProjectTransferCancelled(project_id));
+
+        Ok(())
+    }
+
+    pub(super) fn accept_project_transfer_impl(
+        account: T::AccountId,
+        project_id: ProjectId,
+    ) -> DispatchResult {
+        let offered_to = ProjectTransferOfferV1::<T>::get(project_id)
+            .ok_or(Error::<T>::NoSuchProjectTransferOffer)?;
+        ensure!(offered_to == account, Error::<T>::ProjectTransferOfferedToAnotherTeam);
+
+        let previous_team = ProjectMapV1::<T>::try_mutate_exists(
+            project_id,
+            |maybe_project| -> Result<T::AccountId, DispatchError> {
+                let project = maybe_project.as_mut().ok_or(Error::<T>::NoSuchProject)?;
+                ensure!(!project.is_archived, Error::<T>::ProjectIsArchived);
+                Ok(sp_std::mem::replace(&mut project.team_id, account.clone()))
+            },
+        )?;
+
+        ProjectTransferOfferV1::<T>::remove(project_id);
+        ProjectIdByTeamIdV1::<T>::remove(&previous_team, project_id);
+        ProjectIdByTeamIdV1::<T>::insert(&account, project_id, ());
+
+        Self::deposit_event(RawEvent::ProjectTransferred(project_id, previous_team, account));
+
+        Ok(())
+    }
+
+    pub(super) fn mint_project_nft_impl(
+        account: T::AccountId,
+        project_id: ProjectId,
+        collection: H160,
+    ) -> DispatchResult {
+        let project =
+            ProjectMapV1::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+
+        ensure!(project.team_id == account, Error::<T>::NoPermission);
+        ensure!(!project.is_archived, Error::<T>::ProjectIsArchived);
+        ensure!(T::ProjectNft::holder(project_id).is_none(), Error::<T>::ProjectAlreadyTokenized);
+
+        T::ProjectNft::mint(project_id, collection, &account)?;
+
+        // Team transfers aren't possible anymore, the item is transferred instead
+        if ProjectTransferOfferV1::<T>::take(project_id).is_some() {
+            Self::deposit_event(RawEvent::ProjectTransferCancelled(project_id));
+        }
+
+        Self::deposit_event(RawEvent::ProjectNftMinted(project_id, collection, account));
+
+        Ok(())
+    }
+
+    pub(super) fn remove_project_impl(
+        account: T::AccountId,
+        project_id: ProjectId,
+        contents: u32,
+        reviews: u32,
+        versions: u32,
+    ) -> DispatchResultWithPostInfo {
+        let project =
+            ProjectMapV1::<T>::try_get(project_id).map_err(|_| Error::<T>::NoSuchProject)?;
+
+        ensure!(project.team_id == account, Error::<T>::NoPermission);
+        ensure!(T::ProjectNft::holder(project_id).is_none(), Error::<T>::ProjectIsTokenized);
+        ensure!(
+            ContractAgreementIdByProjectIdV1::iter_prefix(project_id).next().is_none(),
+            Error::<T>::ProjectHasContractAgreements
+        );
+        ensure!(
+            NdaIdByProjectIdV1::iter_prefix(project_id).next().is_none(),
+            Error::<T>::ProjectHasNdas
+        );
+        ensure!(
+            !T::ProjectReferences::is_referenced(&project_id),
+            Error::<T>::ProjectIsReferenced
+        );
+
+        let content_ids: Vec<ProjectContentId> =
+            ContentIdByProjectIdV1::iter_prefix(project_id).map(|(id, _)| id).collect();
+        let review_ids: Vec<ReviewId> =
+            ReviewIdByProjectIdV1::iter_prefix(project_id).map(|(id, _)| id).collect();
+        let review_count = review_ids.len() as u32;
+        let content_count = content_ids.len() as u32;
+
+        ensure!(
+            content_count <= contents && review_count <= reviews,
+            Error::<T>::ProjectRemoveWitnessTooLow
+        );
+
+        let mut version_count = 0u32;
+        for content_id in &content_ids {
+            ensure!(
+                ReviewRequestIdByContentIdV1::iter_prefix(content_id).next().is_none(),
+                Error::<T>::ProjectHasReviewRequests
+            );
+            let content = ProjectContentMapV1::<T>::get(content_id);
+            version_count = version_count.saturating_add(content.version.saturating_add(1));
+        }
+        ensure!(version_count <= versions, Error::<T>::ProjectRemoveWitnessTooLow);
+
+        for content_id in content_ids {
+            let content = ProjectContentMapV1::<T>::take(content_id);
+            for version in 0..=content.version {
+                Self::release_deposit(Deposited::ProjectContent(content_id, version));
+            }
+            for reference in content.references.unwrap_or_default() {
+                Self::remove_citation(reference, content_id);
+            }
+            CitedByV1::remove_prefix(content_id, None);
+            CitationCountV1::remove(content_id);
+            ProjectContentVersionMapV1::<T>::remove_prefix(content_id, None);
+            ReviewIdByContentIdV1::remove_prefix(content_id, None);
+            for domain in &project.domains {
+                ContentIdByDomainIdV1::remove(domain, content_id);
+            }
+        }
+        for review_id in review_ids {
+            Self::remove_review(review_id);
+        }
+        for domain in &project.domains {
+            ProjectIdByDomainIdV1::remove(domain, project_id);
+        }
+        ContentIdByProjectIdV1::remove_prefix(project_id, None);
+        ReviewIdByProjectIdV1::remove_prefix(project_id, None);
+        FinishedProjectV1::remove(project_id);
+        ProjectIdByTeamIdV1::<T>::remove(&project.team_id, project_id);
+        ProjectTransferOfferV1::<T>::remove(project_id);
+        ProjectMapV1::<T>::remove(project_id);
+        Self::release_deposit(Deposited::Project(project_id));
+
+        Self::deposit_event(RawEvent::ProjectRemoved(account, project));
+
+        Ok(Some(T::DeipWeightInfo::remove_project(content_count, review_count, version_count))
+            .into())
+    }
+}
+
+/// [`ProjectNftT`] backed by an F-NFT implementation (e.g. `pallet_deip_f_nft`).
+/// The Project item is bound to the Project with the [`UniqueProject`] fingerprint.
+pub struct ProjectNft<Impl>(PhantomData<Impl>);
+
+impl<Impl: NFTImplT> ProjectNft<Impl> {
+    pub fn fingerprint(project_id: ProjectId) -> Impl::Fingerprint {
+        Unique::<Impl>::fingerprint(UniqueProject(project_id))
+    }
+}
+
+impl<Impl: NFTImplT> ProjectNftT<Impl::Account> for ProjectNft<Impl> {
+    fn mint(project_id: ProjectId, collection: H160, owner: &Impl::Account) -> DispatchResult {
+        let unique = OpaqueUnique::<Impl>(Self::fingerprint(project_id));
+        mint_item(collection.into(), owner, unique)
+    }
+
+    fn holder(project_id: ProjectId) -> Option<Impl::Account> {
+        pick_item::<Impl>(Self::fingerprint(project_id)).ok().map(|item| item.account().clone())
+    }
+}
@@ -26,11 +26,15 @@ pub struct Review<Hash, AccountId> {
     /// List of Domains aka tags Project matches
     pub(crate) domains: Vec<DomainId>,
     /// Model number by which the evaluation is carried out
-    pub(crate) assessment_model: u32,
-    /// percent in "50.00 %" format
+    pub(crate) assessment_model: AssessmentModelId,
+    /// Legacy free-form score in "50.00 %" format. Empty for reviews with `scores`
     pub(crate) weight: Vec<u8>,
     /// Reference to Project Content
     pub(crate) project_content_external_id: ProjectContentId,
+    /// Reviewed version of the Project Content
+    pub(crate) project_content_version: u32,
+    /// Score for each criterion of the assessment model
+    pub(crate) scores: Vec<Permill>,
 }
 
 impl<T: Config> Module<T> {
@@ -40,35 +44,52 @@ impl<T: Config> Module<T> {
         author: T::DeipAccountId,
         content: T::Hash,
         domains: Vec<DomainId>,
-        assessment_model: u32,
-        weight: Vec<u8>,
+        assessment_model: AssessmentModelId,
+        scores: Vec<Permill>,
         project_content_external_id: ProjectContentId,
+        project_content_version: Option<u32>,
     ) -> DispatchResult {
         ensure!(!domains.is_empty(), Error::<T>::ReviewNoDomainSpecified);
+        ensure!(domains.len() <= T::MaxReviewDomains::get() as usize, Error::<T>::TooManyDomains);
 
         for domain in &domains {
-            ensure!(Domains::contains_key(&domain), Error::<T>::DomainNotExists);
+            Self::ensure_domain_is_active(domain)?;
         }
 
+        Self::ensure_review_scores(assessment_model, &scores)?;
+
+        ensure!(!ReviewMapV1::<T>::contains_key(external_id), Error::<T>::ReviewAlreadyExists);
+
+        let project_content = ProjectContentMapV1::<T>::try_get(project_content_external_id)
+            .map_err(|_| Error::<T>::NoSuchProjectContent)?;
+
+        let project_content_version = project_content_version.unwrap_or(project_content.version);
+        ensure!(
+            project_content_version <= project_content.version,
+            Error::<T>::NoSuchProjectContentVersion
+        );
+
         let review = Review {
             external_id,
             author: author.into(),
             content,
             domains,
             assessment_model,
-            weight,
+            weight: Vec::new(),
             project_content_external_id,
+            project_content_version,
+            scores,
         };
 
-        ensure!(!ReviewMapV1::<T>::contains_key(review.external_id), Error::<T>::ReviewAlreadyExists);
-
-        let content = ProjectContentMapV1::<T>::try_get(review.project_content_external_id)
-            .map_err(|_| Error::<T>::NoSuchProjectContent)?;
+        Self::reserve_deposit(&account, Deposited::Review(external_id), review.encoded_size())?;
 
         ReviewMapV1::<T>::insert(review.external_id, review.clone());
-        ReviewIdByProjectIdV1::insert(content.project_external_id, review.external_id, ());
-        ReviewIdByContentIdV1::insert(content.external_id, review.external_id, ());
+        ReviewIdByProjectIdV1::insert(project_content.project_external_id, review.external_id, ());
+        ReviewIdByContentIdV1::insert(project_content.external_id, review.external_id, ());
         ReviewIdByAccountIdV1::<T>::insert(review.author.clone(), review.external_id, ());
+        for domain in &review.domains {
+            ReviewIdByDomainIdV1::insert(domain, review.external_id, ());
+        }
 
         Self::deposit_event(RawEvent::ReviewCreated(account, review));
 
@@ -80,7 +101,8 @@ impl<T: Config> Module<T> {
         review_id: ReviewId,
         domain_id: DomainId,
     ) -> DispatchResult {
-        ensure!(Domains::contains_key(domain_id), Error::<T>::ReviewVoteNoSuchDomain);
+        let domain = Domains::try_get(domain_id).map_err(|_| Error::<T>::ReviewVoteNoSuchDomain)?;
+        ensure!(!domain.is_deprecated, Error::<T>::DomainIsDeprecated);
 
         let review =
             ReviewMapV1::<T>::try_get(review_id).map_err(|_| Error::<T>::ReviewVoteNoSuchReview)?;
@@ -91,12 +113,8 @@ impl<T: Config> Module<T> {
             Error::<T>::ReviewAlreadyVotedWithDomain
         );
 
-        let vote = Vote {
-            dao: account.clone(),
-            review_id,
-            domain_id,
-            voting_time: pallet_timestamp::Pallet::<T>::get(),
-        };
+        let now = pallet_timestamp::Pallet::<T>::get();
+        let vote = Vote { dao: account.clone(), review_id, domain_id, voting_time: now };
 
         ReviewVoteMap::<T>::insert((review_id, account.clone(), domain_id), vote);
         VoteIdByReviewIdV1::<T>::insert(review_id, (review_id, account.clone(), domain_id), ());
@@ -106,8 +124,28 @@ impl<T: Config> Module<T> {
             (),
         );
 
-        Self::deposit_event(RawEvent::ReviewUpvoted(review_id, account, domain_id));
+        Self::deposit_event(RawEvent::ReviewUpvoted(review_id, account.clone(), domain_id));
+
+        if review.author != account && T::DaoLookup::is_dao(&account) {
+            ReviewUpvoteCountV1::mutate(review_id, domain_id, |count| *count += 1);
+            Self::accrue_expertise(&review.author, &account, domain_id, now);
+        }
 
         Ok(())
     }
+
+    /// Removes the Review with its votes and indexes and returns its deposit
+    pub(super) fn remove_review(review_id: ReviewId) {
+        let review = ReviewMapV1::<T>::take(review_id);
+        ReviewIdByAccountIdV1::<T>::remove(&review.author, review_id);
+        for domain in &review.domains {
+            ReviewIdByDomainIdV1::remove(domain, review_id);
+        }
+        for (vote_id, _) in VoteIdByReviewIdV1::<T>::drain_prefix(review_id) {
+            VoteIdByAccountId::<T>::remove(&vote_id.1, &vote_id);
+            ReviewVoteMap::<T>::remove(vote_id);
+        }
+        ReviewUpvoteCountV1::remove_prefix(review_id, None);
+        Self::release_deposit(Deposited::Review(review_id));
+    }
 }
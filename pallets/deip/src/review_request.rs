@@ -0,0 +1,143 @@
+use super::*;
+use frame_support::traits::BalanceStatus;
+
+/// Unique Review Request reference
+pub type Id = H160;
+
+/// Reward escrowed by the Project team for a review of the Project Content.
+/// The author of a review upvoted `required_upvotes` times in the domain
+/// claims the reward before the deadline, otherwise it's refunded to the team.
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Request<AccountId, Balance, Moment> {
+    /// Reference for external world and uniques control
+    pub external_id: Id,
+    /// Account the reward is reserved from
+    pub requester: AccountId,
+    /// Reference to the Project Content to review
+    pub project_content_id: ProjectContentId,
+    /// Domain the review must be upvoted in
+    pub domain_id: DomainId,
+    pub reward: Balance,
+    /// Number of upvotes in the domain the review needs to claim the reward
+    pub required_upvotes: u32,
+    /// The reward can be claimed until this moment and refunded after it
+    pub deadline: Moment,
+}
+
+pub type RequestOf<T> = Request<AccountIdOf<T>, BalanceOf<T>, MomentOf<T>>;
+
+impl<T: Config> Module<T> {
+    pub(super) fn create_review_request_impl(
+        account: T::AccountId,
+        external_id: Id,
+        project_content_id: ProjectContentId,
+        domain_id: DomainId,
+        reward: BalanceOf<T>,
+        required_upvotes: u32,
+        deadline: T::Moment,
+    ) -> DispatchResult {
+        ensure!(
+            !ReviewRequestMapV1::<T>::contains_key(external_id),
+            Error::<T>::ReviewRequestAlreadyExists
+        );
+        ensure!(
+            !reward.is_zero() && required_upvotes > 0,
+            Error::<T>::ReviewRequestWrongTerms
+        );
+        ensure!(
+            deadline > pallet_timestamp::Pallet::<T>::get(),
+            Error::<T>::ReviewRequestDeadlineMustBeLaterCurrentMoment
+        );
+
+        let content = ProjectContentMapV1::<T>::try_get(project_content_id)
+            .map_err(|_| Error::<T>::NoSuchProjectContent)?;
+        let project = ProjectMapV1::<T>::try_get(content.project_external_id)
+            .map_err(|_| Error::<T>::NoSuchProject)?;
+        ensure!(Self::project_owner(&project) == account, Error::<T>::NoPermission);
+        ensure!(!project.is_archived, Error::<T>::ProjectIsArchived);
+
+        Self::ensure_domain_is_active(&domain_id)?;
+
+        T::Currency::reserve(&account, reward)
+            .map_err(|_| Error::<T>::ReviewRequestNotEnoughBalance)?;
+
+        ReviewRequestMapV1::<T>::insert(
+            external_id,
+            Request {
+                external_id,
+                requester: account.clone(),
+                project_content_id,
+                domain_id,
+                reward,
+                required_upvotes,
+                deadline,
+            },
+        );
+        ReviewRequestIdByContentIdV1::insert(project_content_id, external_id, ());
+
+        Self::deposit_event(RawEvent::ReviewRequestCreated(external_id, account));
+
+        Ok(())
+    }
+
+    pub(super) fn claim_review_reward_impl(request_id: Id, review_id: ReviewId) -> DispatchResult {
+        let request = ReviewRequestMapV1::<T>::try_get(request_id)
+            .map_err(|_| Error::<T>::NoSuchReviewRequest)?;
+        ensure!(
+            pallet_timestamp::Pallet::<T>::get() <= request.deadline,
+            Error::<T>::ReviewRequestExpired
+        );
+
+        let review =
+            ReviewMapV1::<T>::try_get(review_id).map_err(|_| Error::<T>::NoSuchReview)?;
+        ensure!(
+            review.project_content_external_id == request.project_content_id &&
+                review.domains.contains(&request.domain_id) &&
+                review.author != request.requester,
+            Error::<T>::ReviewMismatchReviewRequest
+        );
+        ensure!(
+            ReviewUpvoteCountV1::get(review_id, request.domain_id) >= request.required_upvotes,
+            Error::<T>::ReviewNotEnoughUpvotes
+        );
+
+        T::Currency::repatriate_reserved(
+            &request.requester,
+            &review.author,
+            request.reward,
+            BalanceStatus::Free,
+        )?;
+
+        ReviewRequestMapV1::<T>::remove(request_id);
+        ReviewRequestIdByContentIdV1::remove(request.project_content_id, request_id);
+
+        Self::deposit_event(RawEvent::ReviewRewardPaid(
+            request_id,
+            review_id,
+            review.author,
+            request.reward,
+        ));
+
+        Ok(())
+    }
+
+    pub(super) fn refund_review_request_impl(request_id: Id) -> DispatchResult {
+        let request = ReviewRequestMapV1::<T>::try_get(request_id)
+            .map_err(|_| Error::<T>::NoSuchReviewRequest)?;
+        ensure!(
+            pallet_timestamp::Pallet::<T>::get() > request.deadline,
+            Error::<T>::ReviewRequestNotExpiredYet
+        );
+
+        T::Currency::unreserve(&request.requester, request.reward);
+
+        ReviewRequestMapV1::<T>::remove(request_id);
+        ReviewRequestIdByContentIdV1::remove(request.project_content_id, request_id);
+
+        Self::deposit_event(RawEvent::ReviewRequestRefunded(request_id));
+
+        Ok(())
+    }
+}
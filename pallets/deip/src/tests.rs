@@ -1,17 +1,12 @@
 use crate::{mock::*, *};
-use frame_support::{
-    assert_noop, assert_ok,
-    traits::{OffchainWorker, OnFinalize, OnInitialize, UnfilteredDispatchable},
-};
+use frame_support::{assert_noop, assert_ok};
 use sp_core::{
     offchain::{testing::*, TransactionPoolExt},
     H256,
 };
 use sp_io::TestExternalities;
-use sp_runtime::{
-    offchain::OffchainWorkerExt,
-    traits::{One, Zero},
-};
+use sp_runtime::{offchain::OffchainWorkerExt, DispatchError};
+use deip_serializable_u128::SerializableAtLeast32BitUnsigned;
 use sp_std::sync::Arc;
 use std::{
     sync::RwLock,
@@ -20,8 +15,6 @@ use std::{
 
 const DAY_IN_MILLIS: u64 = 86400000;
 
-type BlockNumber = <Test as system::Config>::BlockNumber;
-
 fn create_ok_project(
     maybe_account_id: Option<<Test as system::Config>::AccountId>,
 ) -> (ProjectId, ProjectOf<Test>, DomainId, <Test as system::Config>::AccountId) {
@@ -30,10 +23,7 @@ fn create_ok_project(
         maybe_account_id.unwrap_or(DEFAULT_ACCOUNT_ID);
     let project_id = ProjectId::random();
 
-    assert_ok!(Deip::add_domain(
-        Origin::signed(account_id),
-        Domain { external_id: domain_id.clone() }
-    ));
+    assert_ok!(Deip::add_domain(Origin::root(), domain_id, b"domain".to_vec(), None));
 
     let project = ProjectOf::<Test> {
         is_private: false,
@@ -41,6 +31,7 @@ fn create_ok_project(
         team_id: account_id,
         description: H256::random(),
         domains: vec![domain_id],
+        is_archived: false,
     };
 
     assert_ok!(Deip::create_project(
@@ -122,20 +113,26 @@ fn create_ok_nda_content_access_request(
     (access_request_id, expected_nda_request)
 }
 
-fn create_mint_asset(
-    account_id: AccountIdOf<Test>,
-    id: DeipAssetIdOf<Test>,
-    amount: DeipAssetBalanceOf<Test>,
-    project_id: Option<ProjectId>,
-) {
-    let call =
-        pallet_deip_assets::Call::<Test>::create_asset(id, account_id, 1u32.into(), project_id);
-    let result = call.dispatch_bypass_filter(Origin::signed(account_id));
-    assert_ok!(result);
-
-    let call = pallet_deip_assets::Call::<Test>::issue_asset(id, account_id, amount);
-    let result = call.dispatch_bypass_filter(Origin::signed(account_id));
-    assert_ok!(result);
+fn create_ok_assessment_model() -> AssessmentModelId {
+    let id: AssessmentModelId = 1;
+    assert_ok!(Deip::add_assessment_model(
+        Origin::root(),
+        id,
+        b"model".to_vec(),
+        vec![
+            AssessmentCriterion {
+                name: b"novelty".to_vec(),
+                min: Permill::zero(),
+                max: Permill::one(),
+            },
+            AssessmentCriterion {
+                name: b"rigor".to_vec(),
+                min: Permill::from_percent(10),
+                max: Permill::from_percent(90),
+            },
+        ],
+    ));
+    id
 }
 
 /// convert an externalities to one that can handle offchain worker tests.
@@ -181,10 +178,7 @@ fn add_domain() {
     new_test_ext().execute_with(|| {
         let domain_id = DomainId::random();
         // Dispatch a signed add domian extrinsic.
-        assert_ok!(Deip::add_domain(
-            Origin::signed(DEFAULT_ACCOUNT_ID),
-            Domain { external_id: domain_id.clone() }
-        ));
+        assert_ok!(Deip::add_domain(Origin::root(), domain_id, b"domain".to_vec(), None));
 
         // Read pallet storage and assert an expected result.
         assert_eq!(Deip::domain_count(), 1);
@@ -201,17 +195,109 @@ fn cant_add_duplicate_domain() {
     new_test_ext().execute_with(|| {
         let domain_id = DomainId::random();
 
-        assert_ok!(Deip::add_domain(
-            Origin::signed(DEFAULT_ACCOUNT_ID),
-            Domain { external_id: domain_id.clone() }
-        ));
+        assert_ok!(Deip::add_domain(Origin::root(), domain_id, b"domain".to_vec(), None));
+
+        assert_noop!(
+            Deip::add_domain(Origin::root(), domain_id, b"domain".to_vec(), None),
+            Error::<Test>::DomainAlreadyExists
+        );
+    })
+}
 
+#[test]
+fn cant_add_domain_without_admin_origin() {
+    new_test_ext().execute_with(|| {
         assert_noop!(
             Deip::add_domain(
                 Origin::signed(DEFAULT_ACCOUNT_ID),
-                Domain { external_id: domain_id.clone() }
+                DomainId::random(),
+                b"domain".to_vec(),
+                None
             ),
-            Error::<Test>::DomainAlreadyExists
+            DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn add_domain_hierarchy() {
+    new_test_ext().execute_with(|| {
+        let parent_id = DomainId::random();
+        let child_id = DomainId::random();
+
+        assert_ok!(Deip::add_domain(Origin::root(), parent_id, b"physics".to_vec(), None));
+        assert_ok!(Deip::add_domain(
+            Origin::root(),
+            child_id,
+            b"optics".to_vec(),
+            Some(parent_id)
+        ));
+
+        assert_eq!(Domains::get(child_id).parent, Some(parent_id));
+        assert!(DomainIdByParentIdV1::contains_key(parent_id, child_id));
+
+        assert_noop!(
+            Deip::add_domain(Origin::root(), DomainId::random(), vec![], Some(DomainId::random())),
+            Error::<Test>::NoSuchParentDomain
+        );
+        assert_noop!(
+            Deip::set_domain_parent(Origin::root(), parent_id, Some(child_id)),
+            Error::<Test>::DomainHierarchyCycle
+        );
+
+        assert_ok!(Deip::set_domain_parent(Origin::root(), child_id, None));
+        assert_eq!(Domains::get(child_id).parent, None);
+        assert!(!DomainIdByParentIdV1::contains_key(parent_id, child_id));
+    })
+}
+
+#[test]
+fn rename_domain() {
+    new_test_ext().execute_with(|| {
+        let domain_id = DomainId::random();
+        assert_ok!(Deip::add_domain(Origin::root(), domain_id, b"old".to_vec(), None));
+
+        assert_ok!(Deip::rename_domain(Origin::root(), domain_id, b"new".to_vec()));
+        assert_eq!(Domains::get(domain_id).name, b"new".to_vec());
+
+        assert_noop!(
+            Deip::rename_domain(Origin::root(), domain_id, vec![0; 33]),
+            Error::<Test>::DomainNameTooLong
+        );
+        assert_noop!(
+            Deip::rename_domain(Origin::root(), DomainId::random(), b"new".to_vec()),
+            Error::<Test>::DomainNotExists
+        );
+    })
+}
+
+#[test]
+fn deprecated_domain_cant_be_used_by_new_projects() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _, domain_id, account_id) = create_ok_project(None);
+        let child_id = DomainId::random();
+        assert_ok!(Deip::add_domain(Origin::root(), child_id, vec![], Some(domain_id)));
+
+        assert_noop!(
+            Deip::deprecate_domain(Origin::root(), domain_id),
+            Error::<Test>::DomainHasActiveChildren
+        );
+        assert_ok!(Deip::deprecate_domain(Origin::root(), child_id));
+        assert_ok!(Deip::deprecate_domain(Origin::root(), domain_id));
+
+        // Existing projects keep the deprecated domain
+        assert_eq!(ProjectMapV1::<Test>::get(project_id).domains, vec![domain_id]);
+
+        assert_noop!(
+            Deip::create_project(
+                Origin::signed(account_id),
+                false,
+                ProjectId::random(),
+                account_id,
+                H256::random(),
+                vec![domain_id]
+            ),
+            Error::<Test>::DomainIsDeprecated
         );
     })
 }
@@ -334,202 +420,811 @@ fn cant_update_not_existed_project() {
 }
 
 #[test]
-fn create_project_content() {
+fn archive_project() {
     new_test_ext().execute_with(|| {
         let (project_id, ..) = create_ok_project(None);
-        let project_content_id = ProjectContentId::random();
 
-        assert_ok!(Deip::create_project_content(
-            Origin::signed(DEFAULT_ACCOUNT_ID),
-            project_content_id,
-            project_id,
-            DEFAULT_ACCOUNT_ID,
-            ProjectContentType::Announcement,
-            H256::random(),
-            H256::random(),
-            vec![DEFAULT_ACCOUNT_ID],
-            None
-        ));
+        assert_ok!(Deip::archive_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id));
 
-        assert!(
-            <ProjectContentMapV1<Test>>::contains_key(project_content_id),
-            "Project Content Map did not contain key, value was `{}`",
-            project_content_id
+        assert!(ProjectMapV1::<Test>::get(project_id).is_archived);
+
+        assert_noop!(
+            Deip::update_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, None, Some(true)),
+            Error::<Test>::ProjectIsArchived
         );
 
-        assert!(
-            ContentIdByProjectIdV1::contains_key(project_id, project_content_id),
-            "ContentIdByProjectIdV1 does not contain the key: `{}`, `{}`",
-            project_id,
-            project_content_id
+        assert_noop!(
+            Deip::create_project_content(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                ProjectContentId::random(),
+                project_id,
+                DEFAULT_ACCOUNT_ID,
+                ProjectContentType::Announcement.into(),
+                H256::random(),
+                H256::random(),
+                vec![DEFAULT_ACCOUNT_ID],
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::ProjectIsArchived
         );
     })
 }
 
 #[test]
-fn create_project_content_with_references() {
+fn transfer_project() {
     new_test_ext().execute_with(|| {
         let (project_id, ..) = create_ok_project(None);
 
-        let project_content_id = ProjectContentId::random();
-        let description = H256::random();
-        let content = H256::random();
+        assert_noop!(
+            Deip::offer_project_transfer(Origin::signed(ALICE_ACCOUNT_ID), project_id, BOB_ACCOUNT_ID),
+            Error::<Test>::NoPermission
+        );
+        assert_noop!(
+            Deip::offer_project_transfer(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                project_id,
+                DEFAULT_ACCOUNT_ID
+            ),
+            Error::<Test>::ProjectTransferToSameTeam
+        );
 
-        assert_ok!(Deip::create_project_content(
+        assert_ok!(Deip::offer_project_transfer(
             Origin::signed(DEFAULT_ACCOUNT_ID),
-            project_content_id,
             project_id,
-            DEFAULT_ACCOUNT_ID,
-            ProjectContentType::Announcement,
-            description,
-            content,
-            vec![DEFAULT_ACCOUNT_ID],
-            None
+            ALICE_ACCOUNT_ID
         ));
+        assert_ok!(Deip::cancel_project_transfer(Origin::signed(DEFAULT_ACCOUNT_ID), project_id));
+        assert_noop!(
+            Deip::accept_project_transfer(Origin::signed(ALICE_ACCOUNT_ID), project_id),
+            Error::<Test>::NoSuchProjectTransferOffer
+        );
 
-        let project_content_with_reference_id = ProjectContentId::random();
-
-        assert_ok!(Deip::create_project_content(
+        assert_ok!(Deip::offer_project_transfer(
             Origin::signed(DEFAULT_ACCOUNT_ID),
-            project_content_with_reference_id,
             project_id,
-            DEFAULT_ACCOUNT_ID,
-            ProjectContentType::Announcement,
-            description,
-            content,
-            vec![DEFAULT_ACCOUNT_ID],
-            Some(vec![project_content_id])
+            ALICE_ACCOUNT_ID
         ));
-
-        assert!(
-            <ProjectContentMapV1<Test>>::contains_key(project_content_with_reference_id),
-            "Project Content Map did not contain key, value was `{}`",
-            project_content_with_reference_id
+        assert_noop!(
+            Deip::accept_project_transfer(Origin::signed(BOB_ACCOUNT_ID), project_id),
+            Error::<Test>::ProjectTransferOfferedToAnotherTeam
         );
+        assert_ok!(Deip::accept_project_transfer(Origin::signed(ALICE_ACCOUNT_ID), project_id));
 
-        assert!(
-            ContentIdByProjectIdV1::contains_key(project_id, project_content_with_reference_id),
-            "ContentIdByProjectIdV1 does not contain the key: `{}`, `{}`",
-            project_id,
-            project_content_with_reference_id
+        assert_eq!(ProjectMapV1::<Test>::get(project_id).team_id, ALICE_ACCOUNT_ID);
+        assert!(Deip::project_transfer_offer(project_id).is_none());
+        assert!(!ProjectIdByTeamIdV1::<Test>::contains_key(DEFAULT_ACCOUNT_ID, project_id));
+        assert!(ProjectIdByTeamIdV1::<Test>::contains_key(ALICE_ACCOUNT_ID, project_id));
+
+        assert_noop!(
+            Deip::update_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, None, Some(true)),
+            Error::<Test>::NoPermission
         );
+        assert_ok!(Deip::update_project(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            project_id,
+            None,
+            Some(true)
+        ));
     })
 }
 
 #[test]
-fn cant_add_duplicated_project_content() {
+fn mint_project_nft() {
     new_test_ext().execute_with(|| {
         let (project_id, ..) = create_ok_project(None);
+        let collection = H160::random();
 
-        let content_id = ProjectContentId::random();
-        let description = H256::random();
-        let content = H256::random();
-
-        assert_ok!(Deip::create_project_content(
+        assert_ok!(Deip::offer_project_transfer(
             Origin::signed(DEFAULT_ACCOUNT_ID),
-            content_id,
             project_id,
-            DEFAULT_ACCOUNT_ID,
-            ProjectContentType::Announcement,
-            description,
-            content,
-            vec![DEFAULT_ACCOUNT_ID],
-            None
+            ALICE_ACCOUNT_ID
         ));
-
         assert_noop!(
-            Deip::create_project_content(
-                Origin::signed(DEFAULT_ACCOUNT_ID),
-                content_id,
-                project_id,
-                DEFAULT_ACCOUNT_ID,
-                ProjectContentType::Announcement,
-                description,
-                content,
-                vec![DEFAULT_ACCOUNT_ID],
-                None
-            ),
-            Error::<Test>::ProjectContentAlreadyExists
+            Deip::mint_project_nft(Origin::signed(ALICE_ACCOUNT_ID), project_id, collection),
+            Error::<Test>::NoPermission
         );
-    })
-}
+        assert_ok!(Deip::mint_project_nft(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, collection));
+        assert!(Deip::project_transfer_offer(project_id).is_none());
 
-#[test]
-fn cant_add_project_content_with_wrong_project_reference() {
-    new_test_ext().execute_with(|| {
         assert_noop!(
-            Deip::create_project_content(
-                Origin::signed(DEFAULT_ACCOUNT_ID),
-                ProjectContentId::random(),
-                ProjectId::random(),
-                DEFAULT_ACCOUNT_ID,
-                ProjectContentType::Announcement,
-                H256::random(),
-                H256::random(),
-                vec![DEFAULT_ACCOUNT_ID],
-                None
-            ),
-            Error::<Test>::NoSuchProject
+            Deip::mint_project_nft(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, collection),
+            Error::<Test>::ProjectAlreadyTokenized
+        );
+        assert_noop!(
+            Deip::offer_project_transfer(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, BOB_ACCOUNT_ID),
+            Error::<Test>::ProjectIsTokenized
         );
-    })
-}
 
-#[test]
-fn cant_add_project_content_to_incorrect_team() {
-    new_test_ext().execute_with(|| {
-        let (project_id, ..) = create_ok_project(None);
-        let wrong_account_id = 234;
+        // Ownership rights follow the item
+        ProjectNftMock::transfer(project_id, ALICE_ACCOUNT_ID);
+        assert_eq!(Deip::try_get_project_team(&project_id), Some(ALICE_ACCOUNT_ID));
 
         assert_noop!(
-            Deip::create_project_content(
-                Origin::signed(DEFAULT_ACCOUNT_ID),
-                ProjectContentId::random(),
-                project_id,
-                wrong_account_id,
-                ProjectContentType::Announcement,
-                H256::random(),
-                H256::random(),
-                vec![DEFAULT_ACCOUNT_ID],
-                None
-            ),
-            Error::<Test>::ProjectNotBelongToTeam
+            Deip::update_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, None, Some(true)),
+            Error::<Test>::NoPermission
         );
+        assert_ok!(Deip::update_project(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            project_id,
+            None,
+            Some(true)
+        ));
     })
 }
 
 #[test]
-fn cant_add_project_content_to_finished_project() {
+fn remove_project() {
     new_test_ext().execute_with(|| {
-        let (project_id, ..) = create_ok_project(None);
-
-        let description = H256::random();
-        let content = H256::random();
+        DaoLookupMock::register(BOB_ACCOUNT_ID);
+        let (project_id, _, domain_id, _) = create_ok_project(None);
+        let project_content_id = ProjectContentId::random();
 
         assert_ok!(Deip::create_project_content(
             Origin::signed(DEFAULT_ACCOUNT_ID),
-            ProjectContentId::random(),
+            project_content_id,
             project_id,
             DEFAULT_ACCOUNT_ID,
-            ProjectContentType::FinalResult,
-            description,
-            content,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
             vec![DEFAULT_ACCOUNT_ID],
             None,
+            None,
+            None
+        ));
+
+        let review_id = ReviewId::random();
+        assert_ok!(Deip::create_review(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            review_id,
+            ALICE_ACCOUNT_ID,
+            H256::random(),
+            vec![domain_id],
+            create_ok_assessment_model(),
+            vec![Permill::one(), Permill::from_percent(50)],
+            project_content_id,
+            None,
         ));
+        assert_ok!(Deip::upvote_review(Origin::signed(BOB_ACCOUNT_ID), review_id, domain_id));
 
         assert_noop!(
-            Deip::create_project_content(
-                Origin::signed(DEFAULT_ACCOUNT_ID),
-                ProjectContentId::random(),
-                project_id,
-                DEFAULT_ACCOUNT_ID,
-                ProjectContentType::MilestoneCode,
-                description,
-                content,
-                vec![DEFAULT_ACCOUNT_ID],
-                None,
-            ),
-            Error::<Test>::ProjectAlreadyFinished
+            Deip::remove_project(Origin::signed(ALICE_ACCOUNT_ID), project_id, 1, 1, 1),
+            Error::<Test>::NoPermission
+        );
+
+        assert_noop!(
+            Deip::remove_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, 1, 0, 1),
+            Error::<Test>::ProjectRemoveWitnessTooLow
+        );
+
+        assert_ok!(Deip::remove_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, 1, 1, 1));
+
+        assert!(!ProjectMapV1::<Test>::contains_key(project_id));
+        assert!(!ProjectIdByTeamIdV1::<Test>::contains_key(DEFAULT_ACCOUNT_ID, project_id));
+        assert!(!ProjectContentMapV1::<Test>::contains_key(project_content_id));
+        assert!(!ContentIdByProjectIdV1::contains_key(project_id, project_content_id));
+        assert!(!ReviewMapV1::<Test>::contains_key(review_id));
+        assert!(!ReviewIdByProjectIdV1::contains_key(project_id, review_id));
+        assert!(!ReviewIdByAccountIdV1::<Test>::contains_key(ALICE_ACCOUNT_ID, review_id));
+        assert!(!ReviewIdByDomainIdV1::contains_key(domain_id, review_id));
+        assert!(!ReviewVoteMap::<Test>::contains_key((review_id, BOB_ACCOUNT_ID, domain_id)));
+        assert_eq!(Deip::review_upvote_count(review_id, domain_id), 0);
+    })
+}
+
+#[test]
+fn cant_remove_project_with_license_agreement() {
+    new_test_ext2().execute_with(|| {
+        let (license_id, project_id) = create_ok_license_agreement(100, None);
+
+        assert_noop!(
+            Deip::remove_project(Origin::signed(ALICE_ACCOUNT_ID), project_id, 0, 0, 0),
+            Error::<Test>::ProjectHasContractAgreements
+        );
+
+        assert_ok!(Deip::reject_contract_agreement(
+            Origin::signed(BOB_ACCOUNT_ID),
+            license_id,
+            BOB_ACCOUNT_ID
+        ));
+
+        assert_ok!(Deip::remove_project(Origin::signed(ALICE_ACCOUNT_ID), project_id, 0, 0, 0));
+    })
+}
+
+#[test]
+fn cant_remove_project_with_nda_or_open_review_request() {
+    new_test_ext2().execute_with(|| {
+        let (nda_id, nda) = create_ok_nda();
+        assert_noop!(
+            Deip::remove_project(Origin::signed(DEFAULT_ACCOUNT_ID), nda.projects[0], 0, 0, 0),
+            Error::<Test>::ProjectHasNdas
+        );
+        assert!(NdaMapV1::<Test>::contains_key(nda_id));
+
+        let (project_id, _, domain_id, _) = create_ok_project(None);
+        let project_content_id = ProjectContentId::random();
+        let now = Timestamp::get();
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+        let request_id = ReviewRequestId::random();
+        assert_ok!(Deip::create_review_request(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            request_id,
+            project_content_id,
+            domain_id,
+            100,
+            1,
+            now + DAY_IN_MILLIS
+        ));
+
+        assert_noop!(
+            Deip::remove_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, 1, 0, 1),
+            Error::<Test>::ProjectHasReviewRequests
+        );
+
+        Timestamp::set_timestamp(now + DAY_IN_MILLIS + 1);
+        assert_ok!(Deip::refund_review_request(Origin::signed(DEFAULT_ACCOUNT_ID), request_id));
+        assert_ok!(Deip::remove_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, 1, 0, 1));
+    })
+}
+
+#[test]
+fn storage_deposits_are_returned_on_project_removal() {
+    new_test_ext2().execute_with(|| {
+        DepositBase::set(10);
+        DepositPerByte::set(1);
+
+        let (project_id, project, domain_id, _) = create_ok_project(None);
+        let project_deposit = Deip::deposit_of(project.encode().len());
+        assert_eq!(Balances::reserved_balance(DEFAULT_ACCOUNT_ID), project_deposit);
+        assert_eq!(
+            Deip::storage_deposit(Deposited::Project(project_id)),
+            Some((DEFAULT_ACCOUNT_ID, project_deposit))
+        );
+
+        assert_noop!(
+            Deip::create_project(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                false,
+                ProjectId::random(),
+                DEFAULT_ACCOUNT_ID,
+                H256::random(),
+                vec![domain_id; 4]
+            ),
+            Error::<Test>::TooManyDomains
+        );
+
+        let project_content_id = ProjectContentId::random();
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+        assert_ok!(Deip::update_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None
+        ));
+        assert!(Deip::storage_deposit(Deposited::ProjectContent(project_content_id, 1)).is_some());
+        assert!(Balances::reserved_balance(DEFAULT_ACCOUNT_ID) > project_deposit);
+
+        let review_id = ReviewId::random();
+        assert_ok!(Deip::create_review(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            review_id,
+            ALICE_ACCOUNT_ID,
+            H256::random(),
+            vec![domain_id],
+            create_ok_assessment_model(),
+            vec![Permill::one(), Permill::from_percent(50)],
+            project_content_id,
+            None,
+        ));
+        assert!(Deip::storage_deposit(Deposited::Review(review_id)).is_some());
+        assert!(Balances::reserved_balance(ALICE_ACCOUNT_ID) > 0);
+
+        assert_noop!(
+            Deip::remove_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, 1, 1, 1),
+            Error::<Test>::ProjectRemoveWitnessTooLow
+        );
+        assert_ok!(Deip::remove_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, 1, 1, 2));
+        assert_eq!(Balances::reserved_balance(DEFAULT_ACCOUNT_ID), 0);
+        assert_eq!(Balances::reserved_balance(ALICE_ACCOUNT_ID), 0);
+        assert!(Deip::storage_deposit(Deposited::ProjectContent(project_content_id, 0)).is_none());
+        assert!(Deip::storage_deposit(Deposited::ProjectContent(project_content_id, 1)).is_none());
+        assert!(Deip::storage_deposit(Deposited::Review(review_id)).is_none());
+    })
+}
+
+#[test]
+fn create_project_content() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let project_content_id = ProjectContentId::random();
+
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+
+        assert!(
+            <ProjectContentMapV1<Test>>::contains_key(project_content_id),
+            "Project Content Map did not contain key, value was `{}`",
+            project_content_id
+        );
+
+        assert!(
+            ContentIdByProjectIdV1::contains_key(project_id, project_content_id),
+            "ContentIdByProjectIdV1 does not contain the key: `{}`, `{}`",
+            project_id,
+            project_content_id
+        );
+    })
+}
+
+#[test]
+fn create_project_content_with_references() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+
+        let project_content_id = ProjectContentId::random();
+        let description = H256::random();
+        let content = H256::random();
+
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            description,
+            content,
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+
+        let project_content_with_reference_id = ProjectContentId::random();
+
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_with_reference_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            description,
+            content,
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            Some(vec![project_content_id]),
+            None
+        ));
+
+        assert!(
+            <ProjectContentMapV1<Test>>::contains_key(project_content_with_reference_id),
+            "Project Content Map did not contain key, value was `{}`",
+            project_content_with_reference_id
+        );
+
+        assert!(
+            ContentIdByProjectIdV1::contains_key(project_id, project_content_with_reference_id),
+            "ContentIdByProjectIdV1 does not contain the key: `{}`, `{}`",
+            project_id,
+            project_content_with_reference_id
+        );
+
+        assert!(CitedByV1::contains_key(project_content_id, project_content_with_reference_id));
+        assert_eq!(Deip::get_content_citation_count(&project_content_id), 1);
+    })
+}
+
+#[test]
+fn get_content_citations_paged() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let cited_id = ProjectContentId::random();
+
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            cited_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+
+        for _ in 0..3 {
+            assert_ok!(Deip::create_project_content(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                ProjectContentId::random(),
+                project_id,
+                DEFAULT_ACCOUNT_ID,
+                ProjectContentType::Announcement.into(),
+                H256::random(),
+                H256::random(),
+                vec![DEFAULT_ACCOUNT_ID],
+                None,
+                Some(vec![cited_id, cited_id]),
+                None
+            ));
+        }
+
+        assert_eq!(Deip::get_content_citation_count(&cited_id), 3);
+
+        let first_page = Deip::get_content_citations(&cited_id, None, 2).unwrap();
+        assert_eq!(first_page.len(), 2);
+
+        let second_page =
+            Deip::get_content_citations(&cited_id, first_page.last().copied(), 2).unwrap();
+        assert_eq!(second_page.len(), 1);
+        assert!(!first_page.contains(&second_page[0]));
+
+        assert_eq!(Deip::get_content_citations(&cited_id, Some(cited_id), 2), None);
+    })
+}
+
+#[test]
+fn get_project_content_list_by_project_paged() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+
+        for _ in 0..3 {
+            assert_ok!(Deip::create_project_content(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                ProjectContentId::random(),
+                project_id,
+                DEFAULT_ACCOUNT_ID,
+                ProjectContentType::Announcement.into(),
+                H256::random(),
+                H256::random(),
+                vec![DEFAULT_ACCOUNT_ID],
+                None,
+                None,
+                None
+            ));
+        }
+
+        let first_page =
+            Deip::get_project_content_list_by_project(&project_id, None, 2).unwrap();
+        assert_eq!(first_page.len(), 2);
+        assert!(first_page.iter().all(|(id, content)| content.external_id == *id));
+
+        let start_id = first_page.last().map(|(id, _)| *id);
+        let second_page =
+            Deip::get_project_content_list_by_project(&project_id, start_id, 2).unwrap();
+        assert_eq!(second_page.len(), 1);
+        assert!(first_page.iter().all(|(id, _)| *id != second_page[0].0));
+
+        let unknown_id = Some(ProjectContentId::random());
+        assert!(Deip::get_project_content_list_by_project(&project_id, unknown_id, 2).is_none());
+
+        let projects = Deip::get_project_list_by_team(&DEFAULT_ACCOUNT_ID, None, 10).unwrap();
+        assert!(projects.iter().any(|(id, _)| *id == project_id));
+    })
+}
+
+#[test]
+fn domain_indexes_follow_projects_contents_and_reviews() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _, domain_id, ..) = create_ok_project(None);
+        let assessment_model = create_ok_assessment_model();
+
+        let content_id = ProjectContentId::random();
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+
+        let review_id = ReviewId::random();
+        assert_ok!(Deip::create_review(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            review_id,
+            ALICE_ACCOUNT_ID,
+            H256::random(),
+            vec![domain_id],
+            assessment_model,
+            vec![Permill::from_percent(50), Permill::from_percent(50)],
+            content_id,
+            None
+        ));
+
+        let projects = Deip::get_project_list_by_domain(&domain_id, None, 10).unwrap();
+        assert_eq!(projects.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![project_id]);
+        let contents = Deip::get_project_content_list_by_domain(&domain_id, None, 10).unwrap();
+        assert_eq!(contents.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![content_id]);
+        let reviews = Deip::get_review_list_by_domain(&domain_id, None, 10).unwrap();
+        assert_eq!(reviews.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![review_id]);
+        assert_eq!(Deip::get_project_list_by_domain(&DomainId::random(), None, 10), Some(vec![]));
+
+        assert_ok!(Deip::remove_project(Origin::signed(DEFAULT_ACCOUNT_ID), project_id, 1, 1, 1));
+        assert_eq!(Deip::get_project_list_by_domain(&domain_id, None, 10), Some(vec![]));
+        assert_eq!(Deip::get_project_content_list_by_domain(&domain_id, None, 10), Some(vec![]));
+        assert_eq!(Deip::get_review_list_by_domain(&domain_id, None, 10), Some(vec![]));
+    })
+}
+
+#[test]
+fn cant_add_duplicated_project_content() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+
+        let content_id = ProjectContentId::random();
+        let description = H256::random();
+        let content = H256::random();
+
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            description,
+            content,
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+
+        assert_noop!(
+            Deip::create_project_content(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                content_id,
+                project_id,
+                DEFAULT_ACCOUNT_ID,
+                ProjectContentType::Announcement.into(),
+                description,
+                content,
+                vec![DEFAULT_ACCOUNT_ID],
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::ProjectContentAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn cant_add_project_content_with_wrong_project_reference() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Deip::create_project_content(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                ProjectContentId::random(),
+                ProjectId::random(),
+                DEFAULT_ACCOUNT_ID,
+                ProjectContentType::Announcement.into(),
+                H256::random(),
+                H256::random(),
+                vec![DEFAULT_ACCOUNT_ID],
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::NoSuchProject
+        );
+    })
+}
+
+#[test]
+fn cant_add_project_content_to_incorrect_team() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let wrong_account_id = 234;
+
+        assert_noop!(
+            Deip::create_project_content(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                ProjectContentId::random(),
+                project_id,
+                wrong_account_id,
+                ProjectContentType::Announcement.into(),
+                H256::random(),
+                H256::random(),
+                vec![DEFAULT_ACCOUNT_ID],
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::ProjectNotBelongToTeam
+        );
+    })
+}
+
+#[test]
+fn cant_add_project_content_to_finished_project() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+
+        let description = H256::random();
+        let content = H256::random();
+
+        assert!(!Deip::is_project_finished(&project_id));
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            ProjectContentId::random(),
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::FinalResult.into(),
+            description,
+            content,
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None,
+        ));
+        assert!(Deip::is_project_finished(&project_id));
+
+        assert_noop!(
+            Deip::create_project_content(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                ProjectContentId::random(),
+                project_id,
+                DEFAULT_ACCOUNT_ID,
+                ProjectContentType::MilestoneCode.into(),
+                description,
+                content,
+                vec![DEFAULT_ACCOUNT_ID],
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::ProjectAlreadyFinished
+        );
+    })
+}
+
+#[test]
+fn custom_content_type_finalizes_project() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let album: ContentTypeId = 100;
+
+        assert_noop!(
+            Deip::add_content_type(Origin::signed(DEFAULT_ACCOUNT_ID), album, b"Album".to_vec(), true),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Deip::add_content_type(Origin::root(), album, b"Album".to_vec(), true));
+        assert_noop!(
+            Deip::add_content_type(Origin::root(), album, b"Album".to_vec(), false),
+            Error::<Test>::ContentTypeAlreadyExists
+        );
+        assert!(Deip::content_types(ContentTypeId::from(ProjectContentType::FinalResult)).finalizes_project);
+
+        let create_content = |content_type: ContentTypeId| {
+            Deip::create_project_content(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                ProjectContentId::random(),
+                project_id,
+                DEFAULT_ACCOUNT_ID,
+                content_type,
+                H256::random(),
+                H256::random(),
+                vec![DEFAULT_ACCOUNT_ID],
+                None,
+                None,
+                None,
+            )
+        };
+
+        assert_noop!(create_content(album + 1), Error::<Test>::NoSuchContentType);
+
+        assert_ok!(Deip::deprecate_content_type(Origin::root(), ProjectContentType::MilestoneThesis.into()));
+        assert_noop!(
+            create_content(ProjectContentType::MilestoneThesis.into()),
+            Error::<Test>::ContentTypeIsDeprecated
+        );
+
+        assert_ok!(create_content(ProjectContentType::MilestoneCode.into()));
+        assert!(!Deip::is_project_finished(&project_id));
+        assert_ok!(create_content(album));
+        assert!(Deip::is_project_finished(&project_id));
+    })
+}
+
+#[test]
+fn content_license_changes_only_in_permissive_direction() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let content_id = ProjectContentId::random();
+
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            Some(ContentLicense::Known(KnownLicense::CcByNc)),
+        ));
+        let rights = Deip::get_project_content(&content_id).unwrap().license.unwrap().rights();
+        assert!(rights.remix && !rights.commercial);
+
+        let set_license = |account, license: KnownLicense| {
+            Deip::set_project_content_license(
+                Origin::signed(account),
+                content_id,
+                ContentLicense::Known(license),
+            )
+        };
+
+        assert_noop!(set_license(ALICE_ACCOUNT_ID, KnownLicense::CcBy), Error::<Test>::NoPermission);
+        // No derivatives revokes the remix right
+        assert_noop!(
+            set_license(DEFAULT_ACCOUNT_ID, KnownLicense::CcByNd),
+            Error::<Test>::ContentLicenseLessPermissive
+        );
+        assert_ok!(set_license(DEFAULT_ACCOUNT_ID, KnownLicense::CcBy));
+        // Share-alike adds a requirement
+        assert_noop!(
+            set_license(DEFAULT_ACCOUNT_ID, KnownLicense::Gpl3),
+            Error::<Test>::ContentLicenseLessPermissive
+        );
+        assert_ok!(set_license(DEFAULT_ACCOUNT_ID, KnownLicense::Cc0));
+        assert_noop!(
+            Deip::set_project_content_license(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                content_id,
+                ContentLicense::Custom {
+                    terms: H256::random(),
+                    rights: LicenseRights { reuse: true, remix: true, commercial: true, attribution: true, share_alike: false },
+                },
+            ),
+            Error::<Test>::ContentLicenseLessPermissive
         );
     })
 }
@@ -540,18 +1235,537 @@ fn cant_add_project_content_with_wrong_references() {
         let (project_id, ..) = create_ok_project(None);
 
         assert_noop!(
-            Deip::create_project_content(
-                Origin::signed(DEFAULT_ACCOUNT_ID),
-                ProjectContentId::random(),
-                project_id,
-                DEFAULT_ACCOUNT_ID,
-                ProjectContentType::Announcement,
-                H256::random(),
-                H256::random(),
-                vec![DEFAULT_ACCOUNT_ID],
-                Some(vec![ProjectContentId::random()]),
+            Deip::create_project_content(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                ProjectContentId::random(),
+                project_id,
+                DEFAULT_ACCOUNT_ID,
+                ProjectContentType::Announcement.into(),
+                H256::random(),
+                H256::random(),
+                vec![DEFAULT_ACCOUNT_ID],
+                None,
+                Some(vec![ProjectContentId::random()]),
+                None,
+            ),
+            Error::<Test>::NoSuchReference
+        );
+    })
+}
+
+#[test]
+fn update_project_content_keeps_versions() {
+    new_test_ext().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let project_content_id = ProjectContentId::random();
+        let first_content = H256::random();
+        let second_content = H256::random();
+
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            first_content,
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+
+        assert_noop!(
+            Deip::update_project_content(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                project_content_id,
+                H256::random(),
+                second_content,
+                vec![ALICE_ACCOUNT_ID],
+                None
+            ),
+            Error::<Test>::NoPermission
+        );
+
+        assert_ok!(Deip::update_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            H256::random(),
+            second_content,
+            vec![DEFAULT_ACCOUNT_ID, ALICE_ACCOUNT_ID],
+            None
+        ));
+
+        let project_content = ProjectContentMapV1::<Test>::get(project_content_id);
+        assert_eq!(project_content.version, 1);
+        assert_eq!(project_content.content, second_content);
+        assert_eq!(project_content.authors, vec![DEFAULT_ACCOUNT_ID, ALICE_ACCOUNT_ID]);
+
+        assert_eq!(
+            Deip::get_project_content_version(&project_content_id, 0).unwrap().content,
+            first_content
+        );
+        assert_eq!(
+            Deip::get_project_content_version(&project_content_id, 1).unwrap().content,
+            second_content
+        );
+        assert!(Deip::get_project_content_version(&project_content_id, 2).is_none());
+    })
+}
+
+#[test]
+fn pay_content_royalty_by_author_shares() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, ..) = create_ok_project(None);
+        let project_content_id = ProjectContentId::random();
+        let authors = vec![DEFAULT_ACCOUNT_ID, ALICE_ACCOUNT_ID];
+
+        assert_noop!(
+            Deip::create_project_content(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                project_content_id,
+                project_id,
+                DEFAULT_ACCOUNT_ID,
+                ProjectContentType::Announcement.into(),
+                H256::random(),
+                H256::random(),
+                authors.clone(),
+                Some(vec![Perbill::from_percent(70), Perbill::from_percent(20)]),
+                None,
+                None
+            ),
+            Error::<Test>::AuthorSharesNotComplete
+        );
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
+            authors.clone(),
+            Some(vec![Perbill::from_percent(70), Perbill::from_percent(30)]),
+            None,
+            None
+        ));
+
+        let default_balance_before = Balances::free_balance(DEFAULT_ACCOUNT_ID);
+        let alice_balance_before = Balances::free_balance(ALICE_ACCOUNT_ID);
+        assert_ok!(Deip::pay_content_royalty(&project_content_id, &BOB_ACCOUNT_ID, 1000));
+        assert_eq!(Balances::free_balance(DEFAULT_ACCOUNT_ID), default_balance_before + 700);
+        assert_eq!(Balances::free_balance(ALICE_ACCOUNT_ID), alice_balance_before + 300);
+
+        // Equal shares, the rounding remainder goes to the first author
+        assert_ok!(Deip::update_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID, ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID],
+            None
+        ));
+        assert_eq!(
+            Deip::split_content_payment(&project_content_id, 100).unwrap(),
+            vec![(DEFAULT_ACCOUNT_ID, 34), (ALICE_ACCOUNT_ID, 33), (BOB_ACCOUNT_ID, 33)]
+        );
+    })
+}
+
+#[test]
+fn create_review_for_project_content_version() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _, domain_id, _) = create_ok_project(None);
+        let assessment_model = create_ok_assessment_model();
+        let project_content_id = ProjectContentId::random();
+
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+
+        assert_ok!(Deip::update_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None
+        ));
+
+        let review_id = ReviewId::random();
+        assert_ok!(Deip::create_review(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            review_id,
+            ALICE_ACCOUNT_ID,
+            H256::random(),
+            vec![domain_id],
+            assessment_model,
+            vec![Permill::from_percent(50)],
+            project_content_id,
+            Some(0)
+        ));
+        assert_eq!(ReviewMapV1::<Test>::get(review_id).project_content_version, 0);
+
+        let review_id = ReviewId::random();
+        assert_ok!(Deip::create_review(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            review_id,
+            ALICE_ACCOUNT_ID,
+            H256::random(),
+            vec![domain_id],
+            assessment_model,
+            vec![Permill::from_percent(50)],
+            project_content_id,
+            None
+        ));
+        assert_eq!(ReviewMapV1::<Test>::get(review_id).project_content_version, 1);
+
+        assert_noop!(
+            Deip::create_review(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                ReviewId::random(),
+                ALICE_ACCOUNT_ID,
+                H256::random(),
+                vec![domain_id],
+                assessment_model,
+                vec![Permill::from_percent(50)],
+                project_content_id,
+                Some(2)
+            ),
+            Error::<Test>::NoSuchProjectContentVersion
+        );
+    })
+}
+
+#[test]
+fn add_assessment_model() {
+    new_test_ext().execute_with(|| {
+        let id = create_ok_assessment_model();
+        assert_eq!(Deip::get_assessment_model(&id).unwrap().criteria.len(), 2);
+
+        assert_noop!(
+            Deip::add_assessment_model(Origin::root(), id, b"model".to_vec(), vec![]),
+            Error::<Test>::AssessmentModelAlreadyExists
+        );
+        assert_noop!(
+            Deip::add_assessment_model(Origin::root(), 2, b"model".to_vec(), vec![]),
+            Error::<Test>::AssessmentModelNoCriteria
+        );
+        assert_noop!(
+            Deip::add_assessment_model(
+                Origin::root(),
+                2,
+                b"model".to_vec(),
+                vec![AssessmentCriterion {
+                    name: b"novelty".to_vec(),
+                    min: Permill::from_percent(60),
+                    max: Permill::from_percent(40),
+                }]
+            ),
+            Error::<Test>::AssessmentModelWrongScoreRange
+        );
+        assert_noop!(
+            Deip::add_assessment_model(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                2,
+                b"model".to_vec(),
+                vec![AssessmentCriterion::default()]
+            ),
+            DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn cant_create_review_with_wrong_scores() {
+    new_test_ext().execute_with(|| {
+        let (project_id, _, domain_id, _) = create_ok_project(None);
+        let assessment_model = create_ok_assessment_model();
+        let project_content_id = ProjectContentId::random();
+
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+
+        let create_review = |assessment_model, scores| {
+            Deip::create_review(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                ReviewId::random(),
+                ALICE_ACCOUNT_ID,
+                H256::random(),
+                vec![domain_id],
+                assessment_model,
+                scores,
+                project_content_id,
+                None,
+            )
+        };
+
+        assert_noop!(
+            create_review(2, vec![Permill::one(), Permill::from_percent(50)]),
+            Error::<Test>::NoSuchAssessmentModel
+        );
+        assert_noop!(
+            create_review(assessment_model, vec![Permill::one()]),
+            Error::<Test>::ReviewScoresMismatchAssessmentModel
+        );
+        assert_noop!(
+            create_review(assessment_model, vec![Permill::one(), Permill::one()]),
+            Error::<Test>::ReviewScoreOutOfRange
+        );
+        assert_ok!(create_review(assessment_model, vec![Permill::one(), Permill::from_percent(90)]));
+    })
+}
+
+#[test]
+fn upvote_review_accrues_expertise() {
+    new_test_ext().execute_with(|| {
+        for dao in [DEFAULT_ACCOUNT_ID, ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID] {
+            DaoLookupMock::register(dao);
+        }
+        let (project_id, _, domain_id, _) = create_ok_project(None);
+        let assessment_model = create_ok_assessment_model();
+        let project_content_id = ProjectContentId::random();
+        Timestamp::set_timestamp(INIT_TIMESTAMP);
+
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+
+        let create_review = |author| {
+            let review_id = ReviewId::random();
+            assert_ok!(Deip::create_review(
+                Origin::signed(author),
+                review_id,
+                author,
+                H256::random(),
+                vec![domain_id],
+                assessment_model,
+                vec![Permill::one(), Permill::from_percent(50)],
+                project_content_id,
+                None,
+            ));
+            review_id
+        };
+
+        let alice_review = create_review(ALICE_ACCOUNT_ID);
+        assert_ok!(Deip::upvote_review(Origin::signed(BOB_ACCOUNT_ID), alice_review, domain_id));
+        assert_ok!(Deip::upvote_review(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            alice_review,
+            domain_id
+        ));
+        assert_eq!(Deip::get_expertise(&ALICE_ACCOUNT_ID, &domain_id), 200);
+
+        // self-votes don't accrue expertise
+        assert_ok!(Deip::upvote_review(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            alice_review,
+            domain_id
+        ));
+        assert_eq!(Deip::get_expertise(&ALICE_ACCOUNT_ID, &domain_id), 200);
+
+        // votes of plain accounts don't accrue expertise
+        assert_ok!(Deip::upvote_review(Origin::signed(BOB_ACCOUNT_ID + 1), alice_review, domain_id));
+        assert_eq!(Deip::get_expertise(&ALICE_ACCOUNT_ID, &domain_id), 200);
+
+        // upvote is weighted by the voter's expertise
+        let bob_review = create_review(BOB_ACCOUNT_ID);
+        assert_ok!(Deip::upvote_review(Origin::signed(ALICE_ACCOUNT_ID), bob_review, domain_id));
+        assert_eq!(Deip::get_expertise(&BOB_ACCOUNT_ID, &domain_id), 120);
+        assert_eq!(Deip::get_account_expertise(&BOB_ACCOUNT_ID), vec![(domain_id, 120)]);
+
+        Timestamp::set_timestamp(INIT_TIMESTAMP + DAY_IN_MILLIS);
+        assert_eq!(Deip::get_expertise(&ALICE_ACCOUNT_ID, &domain_id), 100);
+
+        Timestamp::set_timestamp(INIT_TIMESTAMP + 2 * DAY_IN_MILLIS + 1);
+        assert_eq!(Deip::get_expertise(&ALICE_ACCOUNT_ID, &domain_id), 50);
+    })
+}
+
+#[test]
+fn review_request_rewards_upvoted_review() {
+    new_test_ext2().execute_with(|| {
+        DaoLookupMock::register(BOB_ACCOUNT_ID);
+        DaoLookupMock::register(DEFAULT_ACCOUNT_ID);
+        let (project_id, _, domain_id, _) = create_ok_project(None);
+        let assessment_model = create_ok_assessment_model();
+        let project_content_id = ProjectContentId::random();
+        let now = Timestamp::get();
+
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+
+        let request_id = ReviewRequestId::random();
+        assert_noop!(
+            Deip::create_review_request(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                request_id,
+                project_content_id,
+                domain_id,
+                100,
+                2,
+                now + DAY_IN_MILLIS
             ),
-            Error::<Test>::NoSuchReference
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(Deip::create_review_request(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            request_id,
+            project_content_id,
+            domain_id,
+            100,
+            2,
+            now + DAY_IN_MILLIS
+        ));
+        assert_eq!(Balances::reserved_balance(DEFAULT_ACCOUNT_ID), 100);
+
+        let review_id = ReviewId::random();
+        assert_ok!(Deip::create_review(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            review_id,
+            ALICE_ACCOUNT_ID,
+            H256::random(),
+            vec![domain_id],
+            assessment_model,
+            vec![Permill::one(), Permill::from_percent(50)],
+            project_content_id,
+            None,
+        ));
+
+        assert_ok!(Deip::upvote_review(Origin::signed(BOB_ACCOUNT_ID), review_id, domain_id));
+        // self-votes don't count
+        assert_ok!(Deip::upvote_review(Origin::signed(ALICE_ACCOUNT_ID), review_id, domain_id));
+        assert_eq!(Deip::review_upvote_count(review_id, domain_id), 1);
+        assert_noop!(
+            Deip::claim_review_reward(Origin::signed(ALICE_ACCOUNT_ID), request_id, review_id),
+            Error::<Test>::ReviewNotEnoughUpvotes
+        );
+
+        assert_ok!(Deip::upvote_review(Origin::signed(DEFAULT_ACCOUNT_ID), review_id, domain_id));
+        assert_noop!(
+            Deip::refund_review_request(Origin::signed(DEFAULT_ACCOUNT_ID), request_id),
+            Error::<Test>::ReviewRequestNotExpiredYet
+        );
+
+        let alice_balance = Balances::free_balance(ALICE_ACCOUNT_ID);
+        assert_ok!(Deip::claim_review_reward(Origin::signed(BOB_ACCOUNT_ID), request_id, review_id));
+        assert_eq!(Balances::free_balance(ALICE_ACCOUNT_ID), alice_balance + 100);
+        assert_eq!(Balances::reserved_balance(DEFAULT_ACCOUNT_ID), 0);
+        assert!(Deip::review_request(request_id).is_none());
+
+        // unclaimed reward is refunded after the deadline
+        let request_id = ReviewRequestId::random();
+        assert_ok!(Deip::create_review_request(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            request_id,
+            project_content_id,
+            domain_id,
+            100,
+            3,
+            now + DAY_IN_MILLIS
+        ));
+        Timestamp::set_timestamp(now + DAY_IN_MILLIS + 1);
+        assert_noop!(
+            Deip::claim_review_reward(Origin::signed(ALICE_ACCOUNT_ID), request_id, review_id),
+            Error::<Test>::ReviewRequestExpired
+        );
+        assert_ok!(Deip::refund_review_request(Origin::signed(BOB_ACCOUNT_ID), request_id));
+        assert_eq!(Balances::reserved_balance(DEFAULT_ACCOUNT_ID), 0);
+    })
+}
+
+#[test]
+fn review_request_ignores_upvotes_of_plain_accounts() {
+    new_test_ext2().execute_with(|| {
+        let (project_id, _, domain_id, _) = create_ok_project(None);
+        let assessment_model = create_ok_assessment_model();
+        let project_content_id = ProjectContentId::random();
+
+        assert_ok!(Deip::create_project_content(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            project_content_id,
+            project_id,
+            DEFAULT_ACCOUNT_ID,
+            ProjectContentType::Announcement.into(),
+            H256::random(),
+            H256::random(),
+            vec![DEFAULT_ACCOUNT_ID],
+            None,
+            None,
+            None
+        ));
+
+        let request_id = ReviewRequestId::random();
+        assert_ok!(Deip::create_review_request(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            request_id,
+            project_content_id,
+            domain_id,
+            100,
+            1,
+            Timestamp::get() + DAY_IN_MILLIS
+        ));
+
+        let review_id = ReviewId::random();
+        assert_ok!(Deip::create_review(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            review_id,
+            ALICE_ACCOUNT_ID,
+            H256::random(),
+            vec![domain_id],
+            assessment_model,
+            vec![Permill::one(), Permill::from_percent(50)],
+            project_content_id,
+            None,
+        ));
+
+        assert_ok!(Deip::upvote_review(Origin::signed(BOB_ACCOUNT_ID), review_id, domain_id));
+        assert_eq!(Deip::review_upvote_count(review_id, domain_id), 0);
+        assert_noop!(
+            Deip::claim_review_reward(Origin::signed(ALICE_ACCOUNT_ID), request_id, review_id),
+            Error::<Test>::ReviewNotEnoughUpvotes
         );
     })
 }
@@ -561,7 +1775,6 @@ fn create_project_nda() {
     new_test_ext().execute_with(|| {
         let (project_nda_id, expected_nda) = create_ok_nda();
 
-        let nda_list = Ndas::<Test>::get();
         let nda_stored = NdaMapV1::<Test>::get(project_nda_id);
 
         assert!(
@@ -572,13 +1785,13 @@ fn create_project_nda() {
 
         assert_eq!(expected_nda, nda_stored);
 
-        assert!(
-            nda_list
-                .binary_search_by_key(&project_nda_id, |&(external_id, ..)| external_id)
-                .is_ok(),
-            "NDA List did not contain the NDA, value was `{}`",
-            project_nda_id
-        );
+        for project_id in &expected_nda.projects {
+            assert!(
+                NdaIdByProjectIdV1::contains_key(project_id, project_nda_id),
+                "NdaIdByProjectIdV1 did not contain the NDA, value was `{}`",
+                project_nda_id
+            );
+        }
     })
 }
 
@@ -745,7 +1958,7 @@ fn create_nda_content_access_request() {
         let (access_request_id, expected_nda_request) =
             create_ok_nda_content_access_request(project_nda_id);
 
-        let nda_list = NdaAccessRequests::<Test>::get();
+        let nda_requests = NdaAccessRequestIdsByNdaIdV1::<Test>::get(project_nda_id);
         let nda_stored = NdaAccessRequestMapV1::<Test>::get(access_request_id);
 
         assert!(
@@ -757,10 +1970,8 @@ fn create_nda_content_access_request() {
         assert_eq!(expected_nda_request, nda_stored);
 
         assert!(
-            nda_list
-                .binary_search_by_key(&access_request_id, |&(external_id, ..)| external_id)
-                .is_ok(),
-            "NDA request List did not contain the NDA request, value was `{}`",
+            nda_requests.contains(&access_request_id),
+            "NDA request index did not contain the NDA request, value was `{}`",
             access_request_id
         );
     })
@@ -808,6 +2019,29 @@ fn cant_create_duplicated_nda_content_access() {
     })
 }
 
+#[test]
+fn cant_create_nda_content_access_request_over_limit() {
+    new_test_ext().execute_with(|| {
+        let (project_nda_id, ..) = create_ok_nda();
+        let limit = <MaxNdaAccessRequests as Get<u32>>::get();
+
+        for _ in 0..limit {
+            create_ok_nda_content_access_request(project_nda_id);
+        }
+
+        assert_noop!(
+            Deip::create_nda_content_access_request(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                NdaAccessRequestId::random(),
+                project_nda_id,
+                H256::random(),
+                vec![1, 2, 3]
+            ),
+            Error::<Test>::TooMuchNdaAccessRequests
+        );
+    })
+}
+
 #[test]
 fn fulfill_nda_content_access_request() {
     new_test_ext().execute_with(|| {
@@ -890,486 +2124,80 @@ fn cant_fulfill_finalized_nda_content_access_request() {
     })
 }
 
-#[test]
-fn reject_nda_content_access_request() {
-    new_test_ext().execute_with(|| {
-        let (project_nda_id, ..) = create_ok_nda();
-
-        let (access_request_id, nda_request) = create_ok_nda_content_access_request(project_nda_id);
-
-        assert_ok!(Deip::reject_nda_content_access_request(
-            Origin::signed(DEFAULT_ACCOUNT_ID),
-            access_request_id.clone(),
-        ));
-
-        let nda_stored = NdaAccessRequestMapV1::<Test>::get(access_request_id);
-
-        let expected_nda_request =
-            NdaAccessRequest { status: NdaAccessRequestStatus::Rejected, ..nda_request };
-
-        assert_eq!(expected_nda_request, nda_stored);
-    })
-}
-
-#[test]
-fn cant_reject_not_existed_nda_content_access_request() {
-    new_test_ext().execute_with(|| {
-        let access_request_id = NdaAccessRequestId::random();
-
-        assert_noop!(
-            Deip::reject_nda_content_access_request(
-                Origin::signed(DEFAULT_ACCOUNT_ID),
-                access_request_id.clone(),
-            ),
-            Error::<Test>::NoSuchNdaAccessRequest
-        );
-    })
-}
-
-#[test]
-fn cant_reject_finalized_nda_content_access_request() {
-    new_test_ext().execute_with(|| {
-        let (project_nda_id, ..) = create_ok_nda();
-
-        let (access_request_id, ..) = create_ok_nda_content_access_request(project_nda_id);
-
-        assert_ok!(Deip::reject_nda_content_access_request(
-            Origin::signed(DEFAULT_ACCOUNT_ID),
-            access_request_id.clone(),
-        ));
-
-        assert_noop!(
-            Deip::reject_nda_content_access_request(
-                Origin::signed(DEFAULT_ACCOUNT_ID),
-                access_request_id.clone(),
-            ),
-            Error::<Test>::NdaAccessRequestAlreadyFinalized
-        );
-    })
-}
-
-#[test]
-fn simple_crowdfunding_create_should_fail() {
-    new_test_ext2().execute_with(|| {
-        let start_time = pallet_timestamp::Pallet::<Test>::get();
-        assert_noop!(
-            Deip::create_simple_crowdfunding(
-                DEFAULT_ACCOUNT_ID,
-                H160::random(),
-                start_time,
-                start_time + 1,
-                DeipAsset::new(DeipAssetId(0u32), 100u32.into()),
-                DeipAsset::new(DeipAssetId(0u32), 120u32.into()),
-                vec![
-                    DeipAsset::new(DeipAssetId(0u32), 100u32.into()),
-                    DeipAsset::new(DeipAssetId(14u32), 200u32.into())
-                ]
-            ),
-            Error::<Test>::InvestmentOpportunityWrongAssetId
-        );
-
-        assert_noop!(
-            Deip::create_simple_crowdfunding(
-                DEFAULT_ACCOUNT_ID,
-                H160::random(),
-                start_time,
-                start_time + 1,
-                DeipAsset::new(DeipAssetId(0u32), 100u32.into()),
-                DeipAsset::new(DeipAssetId(0u32), 120u32.into()),
-                vec![]
-            ),
-            Error::<Test>::InvestmentOpportunitySecurityTokenNotSpecified
-        );
-    })
-}
-
-#[test]
-fn simple_crowdfunding_hard_cap_reached() {
-    let mut ext = new_test_ext2();
-    let state = offchainify(&mut ext, 2);
-    ext.execute_with(|| {
-        let (ref project_id, .., ref account_id) = create_ok_project(None);
-
-        let base_asset_id = DeipAssetId(3u32);
-        let base_asset_total = 120_000u64;
-        create_mint_asset(ALICE_ACCOUNT_ID, base_asset_id, base_asset_total, None);
-
-        let call = pallet_deip_assets::Call::<Test>::transfer {
-            id: base_asset_id,
-            target: BOB_ACCOUNT_ID,
-            amount: base_asset_total / 2,
-        };
-        let result = call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID));
-        assert_ok!(result);
-
-        let usd_id = DeipAssetId(0u32);
-        let usd_total = 100_000u64;
-        create_mint_asset(*account_id, usd_id, usd_total, Some(*project_id));
-
-        let eur_id = DeipAssetId(1u32);
-        let eur_total = 80_000u64;
-        create_mint_asset(*account_id, eur_id, eur_total, Some(*project_id));
-
-        let balance_before = DeipAssets::account_balance(account_id, &base_asset_id);
-
-        let start_time = pallet_timestamp::Pallet::<Test>::get();
-        let sale_id = H160::random();
-        let soft_cap = 100_000u64;
-        let hard_cap = base_asset_total;
-        let usd_to_sale = 80_000u64;
-        let eur_to_sale = 75_000u64;
-        assert_ok!(Deip::create_simple_crowdfunding(
-            DEFAULT_ACCOUNT_ID,
-            sale_id,
-            start_time,
-            start_time + 100,
-            DeipAsset::new(base_asset_id, soft_cap),
-            DeipAsset::new(base_asset_id, hard_cap),
-            vec![DeipAsset::new(usd_id, usd_to_sale), DeipAsset::new(eur_id, eur_to_sale)]
-        ));
-
-        Deip::offchain_worker(System::block_number());
-        assert_eq!(state.read().transactions.len(), 1);
-
-        let inner = decode_validate_deip_call(&state.read().transactions[0]);
-        match inner {
-            crate::Call::activate_crowdfunding(id) => Deip::activate_crowdfunding_impl(id).unwrap(),
-            _ => unreachable!(),
-        };
-
-        assert_ok!(Deip::invest_to_crowdfunding_impl(
-            BOB_ACCOUNT_ID,
-            sale_id,
-            DeipAsset::new(base_asset_id, hard_cap / 2),
-        ));
-
-        // investors should get their tokens in any case
-        let call = pallet_deip_assets::Call::<Test>::freeze(usd_id, BOB_ACCOUNT_ID);
-        let _result = call.dispatch_bypass_filter(Origin::signed(*account_id));
-
-        let call = pallet_deip_assets::Call::<Test>::freeze_asset(eur_id);
-        let _result = call.dispatch_bypass_filter(Origin::signed(*account_id));
-
-        assert_ok!(Deip::invest_to_crowdfunding_impl(
-            ALICE_ACCOUNT_ID,
-            sale_id,
-            DeipAsset::new(base_asset_id, hard_cap / 2),
-        ));
-
-        assert_eq!(DeipAssets::account_balance(&BOB_ACCOUNT_ID, &usd_id), usd_to_sale / 2);
-        assert_eq!(DeipAssets::account_balance(&ALICE_ACCOUNT_ID, &usd_id), usd_to_sale / 2);
-        assert_eq!(
-            DeipAssets::account_balance(account_id, &usd_id),
-            DeipAssets::total_supply(&usd_id) - usd_to_sale
-        );
-
-        assert_eq!(DeipAssets::account_balance(&BOB_ACCOUNT_ID, &eur_id), eur_to_sale / 2);
-        assert_eq!(DeipAssets::account_balance(&ALICE_ACCOUNT_ID, &eur_id), eur_to_sale / 2);
-        assert_eq!(
-            DeipAssets::account_balance(account_id, &eur_id),
-            DeipAssets::total_supply(&eur_id) - eur_to_sale
-        );
-
-        assert_eq!(
-            DeipAssets::account_balance(account_id, &base_asset_id),
-            hard_cap + balance_before
-        );
-    })
-}
-
-#[test]
-fn simple_crowdfunding_expired() {
-    let mut ext = new_test_ext2();
-    let state = offchainify(&mut ext, 2);
-    ext.execute_with(|| {
-        let (ref project_id, .., ref account_id) = create_ok_project(None);
-
-        let base_asset_id = DeipAssetId(3u32);
-        let base_asset_total = 120_000u64;
-        create_mint_asset(*account_id, base_asset_id, base_asset_total, Some(*project_id));
-
-        let call = pallet_deip_assets::Call::<Test>::transfer(
-            base_asset_id,
-            ALICE_ACCOUNT_ID,
-            base_asset_total / 2,
-        );
-        let result = call.dispatch_bypass_filter(Origin::signed(*account_id));
-        assert_ok!(result);
-
-        let call = pallet_deip_assets::Call::<Test>::transfer(
-            base_asset_id,
-            BOB_ACCOUNT_ID,
-            base_asset_total / 2,
-        );
-        let result = call.dispatch_bypass_filter(Origin::signed(*account_id));
-        assert_ok!(result);
-
-        let usd_id = DeipAssetId(0u32);
-        let usd_total = 100_000u64;
-        create_mint_asset(*account_id, usd_id, usd_total, Some(*project_id));
-
-        let eur_id = DeipAssetId(1u32);
-        let eur_total = 80_000u64;
-        create_mint_asset(*account_id, eur_id, eur_total, Some(*project_id));
-
-        let balance_before = DeipAssets::account_balance(account_id, &base_asset_id);
-        let bob_balance_before = DeipAssets::account_balance(&BOB_ACCOUNT_ID, &base_asset_id);
-
-        let start_time_in_blocks = 5;
-        let start_time =
-            pallet_timestamp::Pallet::<Test>::get() + start_time_in_blocks * BLOCK_TIME;
-        let sale_id = H160::random();
-        let soft_cap = 100_000u64;
-        let alice_investing = soft_cap / 2;
-        let hard_cap = base_asset_total;
-        let usd_to_sale = 80_000u64;
-        let eur_to_sale = 75_000u64;
-        let duration_in_blocks = 5;
-        assert_ok!(Deip::create_simple_crowdfunding(
-            DEFAULT_ACCOUNT_ID,
-            sale_id,
-            start_time,
-            start_time + duration_in_blocks * BLOCK_TIME,
-            DeipAsset::new(base_asset_id, soft_cap),
-            DeipAsset::new(base_asset_id, hard_cap),
-            vec![DeipAsset::new(usd_id, usd_to_sale), DeipAsset::new(eur_id, eur_to_sale)]
-        ));
-
-        let start_block = System::block_number() + start_time_in_blocks + 1;
-        while System::block_number() < start_block {
-            let block_number = System::block_number();
-            <System as OnFinalize<BlockNumber>>::on_finalize(block_number);
-            Deip::offchain_worker(System::block_number());
-            System::set_block_number(block_number + 1);
-            <System as OnInitialize<BlockNumber>>::on_initialize(System::block_number());
-            Timestamp::set_timestamp(System::block_number() * BLOCK_TIME + INIT_TIMESTAMP);
-        }
-
-        assert_eq!(state.read().transactions.len(), 1);
-
-        let inner = decode_validate_deip_call(&state.read().transactions[0]);
-        match inner {
-            crate::Call::activate_crowdfunding(id) => Deip::activate_crowdfunding_impl(id).unwrap(),
-            _ => unreachable!(),
-        };
-
-        state.write().transactions.clear();
-
-        assert_ok!(Deip::invest_to_crowdfunding_impl(
-            BOB_ACCOUNT_ID,
-            sale_id,
-            DeipAsset::new(base_asset_id, soft_cap / 4),
-        ));
-
-        assert_ok!(Deip::invest_to_crowdfunding_impl(
-            ALICE_ACCOUNT_ID,
-            sale_id,
-            DeipAsset::new(base_asset_id, alice_investing),
-        ));
-
-        // make alice zombie
-        let alice_remainder = DeipAssets::account_balance(&ALICE_ACCOUNT_ID, &base_asset_id);
-        let call = pallet_deip_assets::Call::<Test>::transfer(
-            base_asset_id,
-            BOB_ACCOUNT_ID,
-            alice_remainder,
-        );
-        let result = call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID));
-        assert_ok!(result);
-
-        let _a = Balances::slash(&ALICE_ACCOUNT_ID, Balances::total_balance(&ALICE_ACCOUNT_ID));
-
-        // since the sale expired the tokens should be transfered back to
-        // the seller doesn't matter if assets/accounts frozen or not
-        let call = pallet_deip_assets::Call::<Test>::freeze(eur_id, BOB_ACCOUNT_ID);
-        let _result = call.dispatch_bypass_filter(Origin::signed(*account_id));
-
-        let call = pallet_deip_assets::Call::<Test>::freeze_asset(usd_id);
-        let _result = call.dispatch_bypass_filter(Origin::signed(*account_id));
-
-        let end_block = start_block + duration_in_blocks + 1;
-        while System::block_number() < end_block {
-            let block_number = System::block_number();
-            <System as OnFinalize<BlockNumber>>::on_finalize(block_number);
-            Deip::offchain_worker(System::block_number());
-            System::set_block_number(block_number + 1);
-            <System as OnInitialize<BlockNumber>>::on_initialize(System::block_number());
-            Timestamp::set_timestamp(System::block_number() * BLOCK_TIME + INIT_TIMESTAMP);
-        }
-
-        let inner = decode_validate_deip_call(&state.read().transactions[0]);
-        match inner {
-            crate::Call::expire_crowdfunding(id) => Deip::expire_crowdfunding_impl(id).unwrap(),
-            _ => unreachable!(),
-        };
-
-        assert_eq!(
-            DeipAssets::account_balance(&BOB_ACCOUNT_ID, &base_asset_id),
-            bob_balance_before + alice_remainder
-        );
-        assert_eq!(DeipAssets::account_balance(&ALICE_ACCOUNT_ID, &base_asset_id), alice_investing);
-        assert_eq!(DeipAssets::account_balance(account_id, &base_asset_id), balance_before);
+#[test]
+fn cant_fulfill_nda_content_access_request_by_not_a_party() {
+    new_test_ext().execute_with(|| {
+        let (project_nda_id, ..) = create_ok_nda();
 
-        assert_eq!(DeipAssets::account_balance(&BOB_ACCOUNT_ID, &usd_id), 0);
-        assert_eq!(DeipAssets::account_balance(&ALICE_ACCOUNT_ID, &usd_id), 0);
-        assert_eq!(DeipAssets::account_balance(account_id, &usd_id), usd_total);
+        let (access_request_id, ..) = create_ok_nda_content_access_request(project_nda_id);
 
-        assert_eq!(DeipAssets::account_balance(&BOB_ACCOUNT_ID, &eur_id), 0);
-        assert_eq!(DeipAssets::account_balance(&ALICE_ACCOUNT_ID, &eur_id), 0);
-        assert_eq!(DeipAssets::account_balance(account_id, &eur_id), eur_total);
+        assert_noop!(
+            Deip::fulfill_nda_content_access_request(
+                Origin::signed(BOB_ACCOUNT_ID),
+                access_request_id,
+                vec![1, 3, 4, 2],
+                vec![3, 4, 5, 6]
+            ),
+            Error::<Test>::NdaAccessRequestGrantorIsNotParty
+        );
     })
 }
 
 #[test]
-fn two_simultaneous_crowdfundings_expired() {
-    let mut ext = new_test_ext2();
-    let state = offchainify(&mut ext, 2);
-    ext.execute_with(|| {
-        let base_asset_id = DeipAssetId(3u32);
-        let base_asset_total = 120_000u64;
-        create_mint_asset(DEFAULT_ACCOUNT_ID, base_asset_id, base_asset_total, None);
+fn reject_nda_content_access_request() {
+    new_test_ext().execute_with(|| {
+        let (project_nda_id, ..) = create_ok_nda();
 
-        let call = pallet_deip_assets::Call::<Test>::transfer(
-            base_asset_id,
-            ALICE_ACCOUNT_ID,
-            base_asset_total / 2,
-        );
-        let result = call.dispatch_bypass_filter(Origin::signed(DEFAULT_ACCOUNT_ID));
-        assert_ok!(result);
-
-        let call = pallet_deip_assets::Call::<Test>::transfer(
-            base_asset_id,
-            BOB_ACCOUNT_ID,
-            base_asset_total / 2,
-        );
-        let result = call.dispatch_bypass_filter(Origin::signed(DEFAULT_ACCOUNT_ID));
-        assert_ok!(result);
-
-        let usd_id = DeipAssetId(0u32);
-        let usd_total = 100_000u64;
-        create_mint_asset(ALICE_ACCOUNT_ID, usd_id, usd_total, None);
-
-        let eur_id = DeipAssetId(1u32);
-        let eur_total = 80_000u64;
-        create_mint_asset(BOB_ACCOUNT_ID, eur_id, eur_total, None);
-
-        let bob_usd_balance_before = DeipAssets::account_balance(&BOB_ACCOUNT_ID, &usd_id);
-        let bob_eur_balance_before = DeipAssets::account_balance(&BOB_ACCOUNT_ID, &eur_id);
-
-        let alice_usd_balance_before = DeipAssets::account_balance(&ALICE_ACCOUNT_ID, &usd_id);
-        let alice_base_balance_before =
-            DeipAssets::account_balance(&ALICE_ACCOUNT_ID, &base_asset_id);
-
-        let start_time_in_blocks = 5;
-        let start_time =
-            pallet_timestamp::Pallet::<Test>::get() + start_time_in_blocks * BLOCK_TIME;
-        let eur_sale_id = H160::random();
-        let usd_sale_id = H160::random();
-        let soft_cap = 50_000u64;
-        let hard_cap = 60_000_u64;
-        let usd_to_sale = 70_000u64;
-        let eur_to_sale = 75_000u64;
-        let duration_in_blocks = 5;
-        assert_ok!(Deip::create_simple_crowdfunding(
-            BOB_ACCOUNT_ID,
-            eur_sale_id,
-            start_time,
-            start_time + duration_in_blocks * BLOCK_TIME,
-            DeipAsset::new(usd_id, soft_cap),
-            DeipAsset::new(usd_id, hard_cap),
-            vec![DeipAsset::new(eur_id, eur_to_sale)]
-        ));
+        let (access_request_id, nda_request) = create_ok_nda_content_access_request(project_nda_id);
 
-        assert_ok!(Deip::create_simple_crowdfunding(
-            ALICE_ACCOUNT_ID,
-            usd_sale_id,
-            start_time,
-            start_time + duration_in_blocks * BLOCK_TIME,
-            DeipAsset::new(base_asset_id, soft_cap),
-            DeipAsset::new(base_asset_id, hard_cap),
-            vec![DeipAsset::new(usd_id, usd_to_sale)]
+        assert_ok!(Deip::reject_nda_content_access_request(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            access_request_id.clone(),
         ));
 
-        let start_block = System::block_number() + start_time_in_blocks + 1;
-        while System::block_number() < start_block {
-            let block_number = System::block_number();
-            <System as OnFinalize<BlockNumber>>::on_finalize(block_number);
-            Deip::offchain_worker(System::block_number());
-            System::set_block_number(block_number + 1);
-            <System as OnInitialize<BlockNumber>>::on_initialize(System::block_number());
-            Timestamp::set_timestamp(System::block_number() * BLOCK_TIME + INIT_TIMESTAMP);
-        }
-
-        assert_eq!(state.read().transactions.len(), 2);
-
-        let inner = decode_validate_deip_call(&state.read().transactions[0]);
-        match inner {
-            crate::Call::activate_crowdfunding(id) => Deip::activate_crowdfunding_impl(id).unwrap(),
-            _ => unreachable!(),
-        };
-
-        let inner = decode_validate_deip_call(&state.read().transactions[1]);
-        match inner {
-            crate::Call::activate_crowdfunding(id) => Deip::activate_crowdfunding_impl(id).unwrap(),
-            _ => unreachable!(),
-        };
+        let nda_stored = NdaAccessRequestMapV1::<Test>::get(access_request_id);
 
-        state.write().transactions.clear();
+        let expected_nda_request =
+            NdaAccessRequest { status: NdaAccessRequestStatus::Rejected, ..nda_request };
 
-        assert_ok!(Deip::invest_to_crowdfunding_impl(
-            BOB_ACCOUNT_ID,
-            usd_sale_id,
-            DeipAsset::new(base_asset_id, soft_cap / 4),
-        ));
+        assert_eq!(expected_nda_request, nda_stored);
+    })
+}
 
-        assert_ok!(Deip::invest_to_crowdfunding_impl(
-            ALICE_ACCOUNT_ID,
-            eur_sale_id,
-            DeipAsset::new(usd_id, soft_cap / 2),
-        ));
+#[test]
+fn cant_reject_not_existed_nda_content_access_request() {
+    new_test_ext().execute_with(|| {
+        let access_request_id = NdaAccessRequestId::random();
 
-        // since the sale expired the tokens should be transfered back to
-        // the seller doesn't matter if assets/accounts frozen or not
-        let call = pallet_deip_assets::Call::<Test>::freeze(eur_id, BOB_ACCOUNT_ID);
-        let _result = call.dispatch_bypass_filter(Origin::signed(BOB_ACCOUNT_ID));
-
-        let call = pallet_deip_assets::Call::<Test>::freeze_asset(usd_id);
-        let _result = call.dispatch_bypass_filter(Origin::signed(ALICE_ACCOUNT_ID));
-
-        let end_block = start_block + duration_in_blocks + 1;
-        while System::block_number() < end_block {
-            let block_number = System::block_number();
-            <System as OnFinalize<BlockNumber>>::on_finalize(block_number);
-            Deip::offchain_worker(System::block_number());
-            System::set_block_number(block_number + 1);
-            <System as OnInitialize<BlockNumber>>::on_initialize(System::block_number());
-            Timestamp::set_timestamp(System::block_number() * BLOCK_TIME + INIT_TIMESTAMP);
-        }
+        assert_noop!(
+            Deip::reject_nda_content_access_request(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                access_request_id.clone(),
+            ),
+            Error::<Test>::NoSuchNdaAccessRequest
+        );
+    })
+}
 
-        let inner = decode_validate_deip_call(&state.read().transactions[0]);
-        match inner {
-            crate::Call::expire_crowdfunding(id) => Deip::expire_crowdfunding_impl(id).unwrap(),
-            _ => unreachable!(),
-        };
+#[test]
+fn cant_reject_finalized_nda_content_access_request() {
+    new_test_ext().execute_with(|| {
+        let (project_nda_id, ..) = create_ok_nda();
 
-        let inner = decode_validate_deip_call(&state.read().transactions[1]);
-        match inner {
-            crate::Call::expire_crowdfunding(id) => Deip::expire_crowdfunding_impl(id).unwrap(),
-            _ => unreachable!(),
-        };
+        let (access_request_id, ..) = create_ok_nda_content_access_request(project_nda_id);
 
-        assert_eq!(
-            DeipAssets::account_balance(&ALICE_ACCOUNT_ID, &base_asset_id),
-            alice_base_balance_before
-        );
+        assert_ok!(Deip::reject_nda_content_access_request(
+            Origin::signed(DEFAULT_ACCOUNT_ID),
+            access_request_id.clone(),
+        ));
 
-        assert_eq!(DeipAssets::account_balance(&BOB_ACCOUNT_ID, &usd_id), bob_usd_balance_before);
-        assert_eq!(
-            DeipAssets::account_balance(&ALICE_ACCOUNT_ID, &usd_id),
-            alice_usd_balance_before
+        assert_noop!(
+            Deip::reject_nda_content_access_request(
+                Origin::signed(DEFAULT_ACCOUNT_ID),
+                access_request_id.clone(),
+            ),
+            Error::<Test>::NdaAccessRequestAlreadyFinalized
         );
-
-        assert_eq!(DeipAssets::account_balance(&BOB_ACCOUNT_ID, &eur_id), bob_eur_balance_before);
     })
 }
 
@@ -1387,7 +2215,7 @@ fn create_license_agreement_well_known_cases() {
                 None,
                 ContractAgreementTermsOf::<Test>::LicenseAgreement {
                     source: ProjectId::random(),
-                    price: DeipAsset::new(Default::default(), One::one()),
+                    price: SerializableAtLeast32BitUnsigned(1),
                 }
             ),
             Error::<Test>::NoSuchProject
@@ -1405,7 +2233,7 @@ fn create_license_agreement_well_known_cases() {
                 None,
                 ContractAgreementTermsOf::<Test>::LicenseAgreement {
                     source: *project_id,
-                    price: DeipAsset::new(Default::default(), One::one()),
+                    price: SerializableAtLeast32BitUnsigned(1),
                 }
             ),
             Error::<Test>::ContractAgreementLicenseProjectTeamIsNotListedInParties
@@ -1422,7 +2250,7 @@ fn create_license_agreement_well_known_cases() {
                 None,
                 ContractAgreementTermsOf::<Test>::LicenseAgreement {
                     source: *project_id,
-                    price: DeipAsset::new(Default::default(), Zero::zero()),
+                    price: SerializableAtLeast32BitUnsigned(0),
                 }
             ),
             Error::<Test>::ContractAgreementFeeMustBePositive
@@ -1439,7 +2267,7 @@ fn create_license_agreement_well_known_cases() {
                 Some(pallet_timestamp::Pallet::<Test>::get()),
                 ContractAgreementTermsOf::<Test>::LicenseAgreement {
                     source: *project_id,
-                    price: DeipAsset::new(Default::default(), Zero::zero()),
+                    price: SerializableAtLeast32BitUnsigned(0),
                 }
             ),
             Error::<Test>::ContractAgreementEndTimeMustBeLaterStartTime
@@ -1456,7 +2284,7 @@ fn create_license_agreement_well_known_cases() {
                 Some(pallet_timestamp::Pallet::<Test>::get()),
                 ContractAgreementTermsOf::<Test>::LicenseAgreement {
                     source: *project_id,
-                    price: DeipAsset::new(Default::default(), Zero::zero()),
+                    price: SerializableAtLeast32BitUnsigned(0),
                 }
             ),
             Error::<Test>::ContractAgreementEndTimeMustBeLaterStartTime
@@ -1473,7 +2301,7 @@ fn create_license_agreement_well_known_cases() {
             None,
             ContractAgreementTermsOf::<Test>::LicenseAgreement {
                 source: *project_id,
-                price: DeipAsset::new(Default::default(), One::one()),
+                price: SerializableAtLeast32BitUnsigned(1),
             }
         )
         .is_ok());
@@ -1489,10 +2317,475 @@ fn create_license_agreement_well_known_cases() {
                 None,
                 ContractAgreementTermsOf::<Test>::LicenseAgreement {
                     source: *project_id,
-                    price: DeipAsset::new(Default::default(), One::one()),
+                    price: SerializableAtLeast32BitUnsigned(1),
                 }
             ),
             Error::<Test>::ContractAgreementAlreadyExists
         );
     })
 }
+
+fn create_ok_license_agreement(
+    price: u128,
+    expiration_time: Option<u64>,
+) -> (ContractAgreementId, ProjectId) {
+    let (project_id, ..) = create_ok_project(Some(ALICE_ACCOUNT_ID));
+    let license_id = ContractAgreementId::random();
+
+    assert_ok!(Deip::create_contract_agreement(
+        Origin::signed(ALICE_ACCOUNT_ID),
+        license_id,
+        ALICE_ACCOUNT_ID,
+        vec![ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID],
+        HashOf::<Test>::random(),
+        None,
+        expiration_time,
+        ContractAgreementTermsOf::<Test>::LicenseAgreement {
+            source: project_id,
+            price: SerializableAtLeast32BitUnsigned(price),
+        }
+    ));
+
+    (license_id, project_id)
+}
+
+#[test]
+fn accept_license_agreement_charges_fee() {
+    new_test_ext2().execute_with(|| {
+        let price = 100;
+        let (license_id, _) = create_ok_license_agreement(price, None);
+
+        assert_noop!(
+            Deip::accept_contract_agreement(
+                Origin::signed(BOB_ACCOUNT_ID),
+                license_id,
+                BOB_ACCOUNT_ID
+            ),
+            Error::<Test>::ContractAgreementLicensePartyIsNotLicenser
+        );
+
+        assert_ok!(Deip::accept_contract_agreement(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            license_id,
+            ALICE_ACCOUNT_ID
+        ));
+
+        let alice_balance_before = Balances::free_balance(ALICE_ACCOUNT_ID);
+        let bob_balance_before = Balances::free_balance(BOB_ACCOUNT_ID);
+
+        assert_ok!(Deip::accept_contract_agreement(
+            Origin::signed(BOB_ACCOUNT_ID),
+            license_id,
+            BOB_ACCOUNT_ID
+        ));
+
+        assert_eq!(Balances::free_balance(ALICE_ACCOUNT_ID), alice_balance_before + price);
+        assert_eq!(Balances::free_balance(BOB_ACCOUNT_ID), bob_balance_before - price);
+        assert!(matches!(
+            ContractAgreementMap::<Test>::get(license_id),
+            contract::Agreement::License(contract::LicenseStatus::Signed(_))
+        ));
+        assert!(ContractAgreementIdByType::contains_key(
+            ContractAgreementIndexTerms::LicenseAgreement,
+            license_id
+        ));
+    })
+}
+
+#[test]
+fn cant_accept_license_agreement_without_balance() {
+    new_test_ext2().execute_with(|| {
+        let price = Balances::free_balance(BOB_ACCOUNT_ID) + 1;
+        let (license_id, _) = create_ok_license_agreement(price, None);
+
+        assert_ok!(Deip::accept_contract_agreement(
+            Origin::signed(ALICE_ACCOUNT_ID),
+            license_id,
+            ALICE_ACCOUNT_ID
+        ));
+
+        assert_noop!(
+            Deip::accept_contract_agreement(
+                Origin::signed(BOB_ACCOUNT_ID),
+                license_id,
+                BOB_ACCOUNT_ID
+            ),
+            Error::<Test>::ContractAgreementLicenseNotEnoughBalance
+        );
+    })
+}
+
+#[test]
+fn cant_accept_expired_license_agreement() {
+    new_test_ext2().execute_with(|| {
+        let expiration_time = pallet_timestamp::Pallet::<Test>::get() + BLOCK_TIME;
+        let (license_id, _) = create_ok_license_agreement(1, Some(expiration_time));
+
+        Timestamp::set_timestamp(expiration_time);
+
+        assert_noop!(
+            Deip::accept_contract_agreement(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                license_id,
+                ALICE_ACCOUNT_ID
+            ),
+            Error::<Test>::ContractAgreementLicenseExpired
+        );
+    })
+}
+
+#[test]
+fn offchain_worker_expires_contract_agreements() {
+    let mut ext = new_test_ext2();
+    let state = offchainify(&mut ext, 1);
+    ext.execute_with(|| {
+        let expiration_time = pallet_timestamp::Pallet::<Test>::get() + BLOCK_TIME;
+        let (license_id, project_id) = create_ok_license_agreement(1, Some(expiration_time));
+        let (not_expiring_license_id, _) = create_ok_license_agreement(1, None);
+
+        assert_noop!(
+            Deip::expire_contract_agreements(Origin::none(), vec![license_id]),
+            Error::<Test>::ContractAgreementNotExpired
+        );
+
+        Deip::offchain_worker(System::block_number());
+        assert!(state.read().transactions.is_empty());
+
+        Timestamp::set_timestamp(expiration_time);
+
+        Deip::offchain_worker(System::block_number());
+        assert_eq!(state.read().transactions.len(), 1);
+
+        let inner = decode_validate_deip_call(&state.read().transactions[0]);
+        assert_eq!(inner, crate::Call::expire_contract_agreements(vec![license_id]));
+        assert_ok!(inner.dispatch_bypass_filter(Origin::none()));
+
+        assert!(matches!(
+            ContractAgreementMap::<Test>::get(license_id),
+            contract::Agreement::License(contract::LicenseStatus::Expired(_))
+        ));
+        assert!(!ContractAgreementIdByProjectIdV1::contains_key(project_id, license_id));
+        assert!(matches!(
+            ContractAgreementMap::<Test>::get(not_expiring_license_id),
+            contract::Agreement::License(contract::LicenseStatus::Unsigned(_))
+        ));
+
+        assert!(<Deip as sp_runtime::traits::ValidateUnsigned>::validate_unsigned(
+            TransactionSource::Local,
+            &inner,
+        )
+        .is_err());
+        assert_noop!(
+            Deip::accept_contract_agreement(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                license_id,
+                ALICE_ACCOUNT_ID
+            ),
+            Error::<Test>::ContractAgreementExpired
+        );
+    })
+}
+
+#[test]
+fn offchain_worker_expires_due_contract_agreements_in_batches() {
+    let mut ext = new_test_ext2();
+    let state = offchainify(&mut ext, 1);
+    ext.execute_with(|| {
+        let expiration_time = pallet_timestamp::Pallet::<Test>::get() + BLOCK_TIME;
+        let (late_id, _) = create_ok_license_agreement(1, Some(expiration_time + BLOCK_TIME));
+        let due_ids = (0..3)
+            .map(|_| create_ok_license_agreement(1, Some(expiration_time)).0)
+            .collect::<Vec<_>>();
+        let (signed_id, _) = create_ok_license_agreement(1, Some(expiration_time));
+        for party in [ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID] {
+            assert_ok!(Deip::accept_contract_agreement(Origin::signed(party), signed_id, party));
+        }
+        assert!(!ContractAgreementIdByExpirationTime::contains_key(
+            Deip::expiration_key(&expiration_time),
+            signed_id
+        ));
+
+        Timestamp::set_timestamp(expiration_time);
+
+        Deip::offchain_worker(System::block_number());
+        assert_eq!(state.read().transactions.len(), 1);
+        let inner = decode_validate_deip_call(&state.read().transactions[0]);
+        let first_ids = match &inner {
+            crate::Call::expire_contract_agreements(ids) => ids.clone(),
+            _ => unreachable!(),
+        };
+        assert_eq!(first_ids.len(), 2);
+        assert!(first_ids.iter().all(|id| due_ids.contains(id)));
+        assert_ok!(inner.dispatch_bypass_filter(Origin::none()));
+
+        Deip::offchain_worker(System::block_number());
+        assert_eq!(state.read().transactions.len(), 2);
+        let inner = decode_validate_deip_call(&state.read().transactions[1]);
+        let rest_ids = due_ids.iter().filter(|id| !first_ids.contains(id)).copied().collect();
+        assert_eq!(inner, crate::Call::expire_contract_agreements(rest_ids));
+        assert_ok!(inner.dispatch_bypass_filter(Origin::none()));
+
+        Deip::offchain_worker(System::block_number());
+        assert_eq!(state.read().transactions.len(), 2);
+        assert!(ContractAgreementIdByExpirationTime::iter().map(|(_, id, _)| id).eq([late_id]));
+
+        assert_noop!(
+            Deip::expire_contract_agreements(Origin::none(), vec![late_id; 3]),
+            Error::<Test>::ContractAgreementTooManyToExpire
+        );
+    })
+}
+
+#[test]
+fn migrate_contract_agreement_expiration_index_from_v13() {
+    use frame_support::{storage::StorageValue, traits::OnRuntimeUpgrade};
+
+    new_test_ext2().execute_with(|| {
+        let expiration_time = pallet_timestamp::Pallet::<Test>::get() + BLOCK_TIME;
+        let (license_id, _) = create_ok_license_agreement(1, Some(expiration_time));
+        let (rejected_id, _) = create_ok_license_agreement(1, Some(expiration_time));
+        assert_ok!(Deip::reject_contract_agreement(
+            Origin::signed(BOB_ACCOUNT_ID),
+            rejected_id,
+            BOB_ACCOUNT_ID
+        ));
+
+        let key = Deip::expiration_key(&expiration_time);
+        ContractAgreementIdByExpirationTime::remove(key, license_id);
+        PalletStorageVersion::put(StorageVersion::V13);
+
+        Deip::on_runtime_upgrade();
+
+        assert!(Deip::pallet_storage_version() == StorageVersion::V14);
+        assert!(ContractAgreementIdByExpirationTime::iter().map(|(_, id, _)| id).eq([license_id]));
+    })
+}
+
+fn create_ok_escrow_contract(expiration_time: Option<u64>) -> ContractAgreementId {
+    let id = ContractAgreementId::random();
+
+    assert_ok!(Deip::create_contract_agreement(
+        Origin::signed(ALICE_ACCOUNT_ID),
+        id,
+        ALICE_ACCOUNT_ID,
+        vec![ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID, DEFAULT_ACCOUNT_ID],
+        HashOf::<Test>::random(),
+        None,
+        expiration_time,
+        ContractAgreementTermsOf::<Test>::EscrowContractAgreement {
+            milestones: vec![
+                ContractMilestone {
+                    payee: BOB_ACCOUNT_ID,
+                    amount: SerializableAtLeast32BitUnsigned(100),
+                    approvers: vec![ALICE_ACCOUNT_ID],
+                },
+                ContractMilestone {
+                    payee: BOB_ACCOUNT_ID,
+                    amount: SerializableAtLeast32BitUnsigned(50),
+                    approvers: vec![ALICE_ACCOUNT_ID, DEFAULT_ACCOUNT_ID],
+                },
+            ],
+        }
+    ));
+
+    id
+}
+
+#[test]
+fn escrow_contract_releases_milestones() {
+    new_test_ext2().execute_with(|| {
+        let alice_balance_before = Balances::free_balance(ALICE_ACCOUNT_ID);
+        let bob_balance_before = Balances::free_balance(BOB_ACCOUNT_ID);
+
+        let id = create_ok_escrow_contract(None);
+        assert_eq!(Balances::reserved_balance(ALICE_ACCOUNT_ID), 150);
+
+        assert_noop!(
+            Deip::approve_contract_milestone(Origin::signed(ALICE_ACCOUNT_ID), id, 0),
+            Error::<Test>::ContractAgreementEscrowNotAccepted
+        );
+
+        for party in [ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID, DEFAULT_ACCOUNT_ID] {
+            assert_ok!(Deip::accept_contract_agreement(Origin::signed(party), id, party));
+        }
+
+        assert_noop!(
+            Deip::approve_contract_milestone(Origin::signed(BOB_ACCOUNT_ID), id, 0),
+            Error::<Test>::ContractAgreementEscrowNotApprover
+        );
+        assert_noop!(
+            Deip::approve_contract_milestone(Origin::signed(ALICE_ACCOUNT_ID), id, 2),
+            Error::<Test>::ContractAgreementEscrowNoSuchMilestone
+        );
+
+        assert_ok!(Deip::approve_contract_milestone(Origin::signed(ALICE_ACCOUNT_ID), id, 0));
+        assert_eq!(Balances::free_balance(BOB_ACCOUNT_ID), bob_balance_before + 100);
+        assert_noop!(
+            Deip::approve_contract_milestone(Origin::signed(ALICE_ACCOUNT_ID), id, 0),
+            Error::<Test>::ContractAgreementEscrowMilestoneReleased
+        );
+
+        assert_ok!(Deip::approve_contract_milestone(Origin::signed(ALICE_ACCOUNT_ID), id, 1));
+        assert_eq!(Balances::free_balance(BOB_ACCOUNT_ID), bob_balance_before + 100);
+        assert_ok!(Deip::approve_contract_milestone(Origin::signed(DEFAULT_ACCOUNT_ID), id, 1));
+        assert_eq!(Balances::free_balance(BOB_ACCOUNT_ID), bob_balance_before + 150);
+
+        assert_eq!(Balances::reserved_balance(ALICE_ACCOUNT_ID), 0);
+        assert_eq!(Balances::free_balance(ALICE_ACCOUNT_ID), alice_balance_before - 150);
+        assert!(matches!(
+            ContractAgreementMap::<Test>::get(id),
+            contract::Agreement::Escrow(contract::EscrowStatus::Completed(_))
+        ));
+    })
+}
+
+#[test]
+fn escrow_contract_refunds_payer() {
+    new_test_ext2().execute_with(|| {
+        let alice_balance_before = Balances::free_balance(ALICE_ACCOUNT_ID);
+
+        let id = create_ok_escrow_contract(None);
+        assert_ok!(Deip::reject_contract_agreement(
+            Origin::signed(BOB_ACCOUNT_ID),
+            id,
+            BOB_ACCOUNT_ID
+        ));
+        assert_eq!(Balances::reserved_balance(ALICE_ACCOUNT_ID), 0);
+        assert_eq!(Balances::free_balance(ALICE_ACCOUNT_ID), alice_balance_before);
+
+        let expiration_time = pallet_timestamp::Pallet::<Test>::get() + BLOCK_TIME;
+        let id = create_ok_escrow_contract(Some(expiration_time));
+        for party in [ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID, DEFAULT_ACCOUNT_ID] {
+            assert_ok!(Deip::accept_contract_agreement(Origin::signed(party), id, party));
+        }
+        assert_ok!(Deip::approve_contract_milestone(Origin::signed(ALICE_ACCOUNT_ID), id, 0));
+
+        Timestamp::set_timestamp(expiration_time);
+        assert_ok!(Deip::expire_contract_agreements(Origin::none(), vec![id]));
+
+        assert_eq!(Balances::reserved_balance(ALICE_ACCOUNT_ID), 0);
+        assert_eq!(Balances::free_balance(ALICE_ACCOUNT_ID), alice_balance_before - 100);
+        assert!(matches!(
+            ContractAgreementMap::<Test>::get(id),
+            contract::Agreement::Escrow(contract::EscrowStatus::Expired(_))
+        ));
+    })
+}
+
+#[test]
+fn escrow_contract_reports_reserve_shortfall() {
+    new_test_ext2().execute_with(|| {
+        let expiration_time = pallet_timestamp::Pallet::<Test>::get() + BLOCK_TIME;
+        let id = create_ok_escrow_contract(Some(expiration_time));
+        for party in [ALICE_ACCOUNT_ID, BOB_ACCOUNT_ID, DEFAULT_ACCOUNT_ID] {
+            assert_ok!(Deip::accept_contract_agreement(Origin::signed(party), id, party));
+        }
+
+        let _ = Balances::slash_reserved(&ALICE_ACCOUNT_ID, 100);
+        assert_noop!(
+            Deip::approve_contract_milestone(Origin::signed(ALICE_ACCOUNT_ID), id, 0),
+            Error::<Test>::ContractAgreementEscrowReserveShortfall
+        );
+
+        Timestamp::set_timestamp(expiration_time);
+        assert_ok!(Deip::expire_contract_agreements(Origin::none(), vec![id]));
+
+        assert_eq!(Balances::reserved_balance(ALICE_ACCOUNT_ID), 0);
+        let shortfall = mock::Event::Deip(RawEvent::ContractEscrowRefundShortfall(id, 100));
+        assert!(System::events().iter().any(|record| record.event == shortfall));
+    })
+}
+
+#[test]
+fn migrate_project_content_and_reviews_from_v4() {
+    use frame_support::{
+        storage::{migration::put_storage_value, StorageValue},
+        traits::OnRuntimeUpgrade,
+        Blake2_128Concat, StorageHasher,
+    };
+
+    #[derive(Encode)]
+    struct V4ProjectContent {
+        external_id: ProjectContentId,
+        project_external_id: ProjectId,
+        team_id: u64,
+        content_type: ProjectContentType,
+        description: H256,
+        content: H256,
+        authors: Vec<u64>,
+        references: Option<Vec<ProjectContentId>>,
+    }
+
+    #[derive(Encode)]
+    struct V4Review {
+        external_id: ReviewId,
+        author: u64,
+        content: H256,
+        domains: Vec<DomainId>,
+        assessment_model: u32,
+        weight: Vec<u8>,
+        project_content_external_id: ProjectContentId,
+    }
+
+    new_test_ext2().execute_with(|| {
+        let project_id = ProjectId::random();
+        let content_id = ProjectContentId::random();
+        let review_id = ReviewId::random();
+        let domain_id = DomainId::random();
+
+        put_storage_value(
+            b"Deip",
+            b"ProjectContentMapV1",
+            &Blake2_128Concat::hash(&content_id.encode()),
+            V4ProjectContent {
+                external_id: content_id,
+                project_external_id: project_id,
+                team_id: DEFAULT_ACCOUNT_ID,
+                content_type: ProjectContentType::FinalResult,
+                description: H256::repeat_byte(1),
+                content: H256::repeat_byte(2),
+                authors: vec![ALICE_ACCOUNT_ID],
+                references: None,
+            },
+        );
+        put_storage_value(
+            b"Deip",
+            b"ReviewMapV1",
+            &Blake2_128Concat::hash(&review_id.encode()),
+            V4Review {
+                external_id: review_id,
+                author: BOB_ACCOUNT_ID,
+                content: H256::repeat_byte(3),
+                domains: vec![domain_id],
+                assessment_model: 0,
+                weight: b"50.00 %".to_vec(),
+                project_content_external_id: content_id,
+            },
+        );
+        PalletStorageVersion::put(StorageVersion::V4);
+
+        Deip::on_runtime_upgrade();
+
+        assert!(Deip::pallet_storage_version() == StorageVersion::V14);
+
+        let content = ProjectContentMapV1::<Test>::try_get(content_id).unwrap();
+        assert_eq!(content.content_type, ContentTypeId::from(ProjectContentType::FinalResult));
+        assert_eq!(content.team_id, DEFAULT_ACCOUNT_ID);
+        assert_eq!(content.description, H256::repeat_byte(1));
+        assert_eq!(content.content, H256::repeat_byte(2));
+        assert_eq!(content.authors, vec![ALICE_ACCOUNT_ID]);
+        assert_eq!(content.version, 0);
+        assert!(content.author_shares.is_empty());
+        assert!(content.license.is_none());
+        assert!(ProjectContentVersionMapV1::<Test>::contains_key(content_id, 0));
+        assert!(FinishedProjectV1::contains_key(project_id));
+
+        let review = ReviewMapV1::<Test>::try_get(review_id).unwrap();
+        assert_eq!(review.author, BOB_ACCOUNT_ID);
+        assert_eq!(review.weight, b"50.00 %".to_vec());
+        assert_eq!(review.project_content_external_id, content_id);
+        assert_eq!(review.project_content_version, 0);
+        assert!(review.scores.is_empty());
+        assert!(ReviewIdByDomainIdV1::contains_key(domain_id, review_id));
+    })
+}
@@ -26,6 +26,16 @@
 // --output
 // weights.rs
 
+// PROVISIONAL: `archive_project`, `remove_project`, the project transfer calls,
+// `mint_project_nft`, `update_project_content`, `set_project_content_license`,
+// `add_assessment_model`, the review request calls, the domain calls other than `add_domain`,
+// the content type calls, the escrow contract calls, `expire_contract_agreement` and
+// `reject_contract_agreement` are estimated by hand, not benchmarked. The storage reads and
+// writes of `create_project`, `create_project_content`, the NDA calls, `create_review`,
+// `upvote_review`, `add_domain` and the contract agreement calls were updated by hand for the
+// indexes and deposits added since, on top of the measured times.
+// Regenerate the file with the command above to replace them.
+
 #![allow(unused_parens)]
 #![allow(unused_imports)]
 
@@ -42,20 +52,42 @@ pub trait WeightInfo {
     fn invest() -> Weight;
     fn invest_hard_cap_reached() -> Weight;
     fn update_project() -> Weight;
+    fn archive_project() -> Weight;
+    fn offer_project_transfer() -> Weight;
+    fn cancel_project_transfer() -> Weight;
+    fn accept_project_transfer() -> Weight;
+    fn mint_project_nft() -> Weight;
+    fn remove_project(c: u32, r: u32, v: u32) -> Weight;
     fn create_project_content(a: u32, r: u32) -> Weight;
+    fn update_project_content(a: u32) -> Weight;
+    fn set_project_content_license() -> Weight;
     fn create_project_nda(p: u32) -> Weight;
     fn create_nda_content_access_request() -> Weight;
     fn fulfill_nda_content_access_request() -> Weight;
     fn reject_nda_content_access_request() -> Weight;
+    fn add_assessment_model(c: u32) -> Weight;
     fn create_review(d: u32) -> Weight;
     fn upvote_review() -> Weight;
+    fn create_review_request() -> Weight;
+    fn claim_review_reward() -> Weight;
+    fn refund_review_request() -> Weight;
     fn add_domain() -> Weight;
+    fn rename_domain() -> Weight;
+    fn set_domain_parent(d: u32) -> Weight;
+    fn deprecate_domain(c: u32) -> Weight;
+    fn add_content_type() -> Weight;
+    fn deprecate_content_type() -> Weight;
     fn create_contract_agreement_project_license() -> Weight;
     fn create_contract_agreement_generic_contract() -> Weight;
     fn accept_contract_agreement_project_license_unsigned() -> Weight;
     fn accept_contract_agreement_project_license_signed_by_licenser() -> Weight;
     fn accept_contract_agreement_generic_contract_partially_accepted() -> Weight;
     fn accept_contract_agreement_generic_contract_finalized() -> Weight;
+    fn create_contract_agreement_escrow_contract(m: u32) -> Weight;
+    fn accept_contract_agreement_escrow_contract() -> Weight;
+    fn approve_contract_milestone() -> Weight;
+    fn expire_contract_agreement() -> Weight;
+    fn reject_contract_agreement() -> Weight;
 }
 
 /// Weight functions for pallet_deip.
@@ -65,13 +97,17 @@ impl<T: frame_system::Config> WeightInfo for Weights<T> {
     // Storage: Deip Domains (r:1 w:0)
     // Storage: Deip ProjectMapV1 (r:1 w:1)
     // Storage: Deip ProjectIdByTeamIdV1 (r:0 w:1)
+    // Storage: Deip StorageDepositV1 (r:0 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Deip ProjectIdByDomainIdV1 (r:0 w:10)
     fn create_project(d: u32) -> Weight {
         (36_645_000 as Weight)
             // Standard Error: 29_000
             .saturating_add((6_632_000 as Weight).saturating_mul(d as Weight))
-            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
             .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
-            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(d as Weight)))
     }
     // Storage: Timestamp Now (r:1 w:0)
     // Storage: Deip SimpleCrowdfundingMap (r:1 w:1)
@@ -167,38 +203,141 @@ impl<T: frame_system::Config> WeightInfo for Weights<T> {
             .saturating_add(T::DbWeight::get().reads(1 as Weight))
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
-    // Storage: Deip ProjectContentMapV1 (r:51 w:1)
+    // Storage: Deip ProjectMapV1 (r:1 w:1)
+    fn archive_project() -> Weight {
+        (27_913_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: Deip ProjectMapV1 (r:1 w:0)
+    // Storage: DeipFNFT ItemRepo (r:1 w:0)
+    // Storage: Deip ProjectTransferOfferV1 (r:0 w:1)
+    fn offer_project_transfer() -> Weight {
+        (28_904_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: Deip ProjectMapV1 (r:1 w:0)
+    // Storage: Deip ProjectTransferOfferV1 (r:1 w:1)
+    fn cancel_project_transfer() -> Weight {
+        (25_701_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: Deip ProjectTransferOfferV1 (r:1 w:1)
+    // Storage: Deip ProjectMapV1 (r:1 w:1)
+    // Storage: Deip ProjectIdByTeamIdV1 (r:0 w:2)
+    fn accept_project_transfer() -> Weight {
+        (38_265_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
     // Storage: Deip ProjectMapV1 (r:1 w:0)
+    // Storage: DeipFNFT ItemRepo (r:1 w:1)
+    // Storage: DeipFNFT CollectionRepo (r:1 w:1)
+    // Storage: Uniques Class (r:1 w:1)
+    // Storage: Uniques Asset (r:1 w:1)
+    // Storage: Uniques Account (r:0 w:1)
+    // Storage: Deip ProjectTransferOfferV1 (r:1 w:1)
+    fn mint_project_nft() -> Weight {
+        (71_352_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
+    }
+    // Storage: Deip ProjectMapV1 (r:1 w:1)
+    // Storage: DeipFNFT ItemRepo (r:1 w:0)
+    // Storage: Deip ContractAgreementIdByProjectIdV1 (r:1 w:0)
+    // Storage: Deip NdaIdByProjectIdV1 (r:1 w:0)
     // Storage: Deip ContentIdByProjectIdV1 (r:1 w:1)
+    // Storage: Deip ReviewIdByProjectIdV1 (r:1 w:1)
+    // Storage: Deip ReviewRequestIdByContentIdV1 (r:1 w:0)
+    // Storage: Deip FinishedProjectV1 (r:0 w:1)
+    // Storage: Deip ProjectContentMapV1 (r:1 w:1)
+    // Storage: Deip ProjectContentVersionMapV1 (r:0 w:1)
+    // Storage: Deip CitedByV1 (r:0 w:1)
+    // Storage: Deip CitationCountV1 (r:0 w:1)
+    // Storage: Deip ReviewIdByContentIdV1 (r:0 w:1)
+    // Storage: Deip ProjectIdByTeamIdV1 (r:0 w:1)
+    // Storage: Deip ProjectTransferOfferV1 (r:0 w:1)
+    // Storage: Deip StorageDepositV1 (r:51 w:51)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Deip ProjectIdByDomainIdV1 (r:0 w:10)
+    // Storage: Deip ContentIdByDomainIdV1 (r:0 w:500)
+    fn remove_project(c: u32, r: u32, v: u32) -> Weight {
+        (93_516_000 as Weight)
+            .saturating_add((35_208_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add((6_018_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add((9_000_000 as Weight).saturating_mul(v as Weight))
+            .saturating_add(T::DbWeight::get().reads(8 as Weight))
+            .saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(v as Weight)))
+            .saturating_add(T::DbWeight::get().writes(17 as Weight))
+            .saturating_add(T::DbWeight::get().writes((16 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(v as Weight)))
+    }
+    // Storage: Deip ProjectContentMapV1 (r:51 w:1)
+    // Storage: Deip ProjectMapV1 (r:1 w:0)
+    // Storage: Deip FinishedProjectV1 (r:1 w:1)
+    // Storage: Deip ContentTypeMapV1 (r:1 w:0)
+    // Storage: Deip ContentIdByProjectIdV1 (r:0 w:1)
+    // Storage: Deip ContentIdByDomainIdV1 (r:0 w:10)
+    // Storage: Deip ProjectContentVersionMapV1 (r:0 w:1)
+    // Storage: Deip CitedByV1 (r:50 w:50)
+    // Storage: Deip CitationCountV1 (r:50 w:50)
+    // Storage: Deip StorageDepositV1 (r:0 w:1)
+    // Storage: System Account (r:1 w:1)
     fn create_project_content(a: u32, r: u32) -> Weight {
         (0 as Weight)
             // Standard Error: 652_000
             .saturating_add((798_000 as Weight).saturating_mul(a as Weight))
             // Standard Error: 652_000
             .saturating_add((9_357_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(r as Weight)))
+            .saturating_add(T::DbWeight::get().writes(16 as Weight))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+    }
+    // Storage: Deip ProjectContentMapV1 (r:1 w:1)
+    // Storage: Deip ProjectMapV1 (r:1 w:0)
+    // Storage: Deip ProjectContentVersionMapV1 (r:0 w:1)
+    // Storage: Deip StorageDepositV1 (r:0 w:1)
+    // Storage: System Account (r:1 w:1)
+    fn update_project_content(a: u32) -> Weight {
+        (59_631_000 as Weight)
+            .saturating_add((812_000 as Weight).saturating_mul(a as Weight))
             .saturating_add(T::DbWeight::get().reads(3 as Weight))
-            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
-            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    // Storage: Deip ProjectContentMapV1 (r:1 w:1)
+    // Storage: Deip ProjectMapV1 (r:1 w:0)
+    // Storage: DeipFNFT ItemRepo (r:1 w:0)
+    fn set_project_content_license() -> Weight {
+        (27_306_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
     // Storage: Timestamp Now (r:1 w:0)
-    // Storage: Deip Ndas (r:1 w:1)
-    // Storage: Deip NdaMapV1 (r:0 w:1)
     // Storage: Deip ProjectMapV1 (r:1 w:0)
+    // Storage: Deip NdaMapV1 (r:1 w:1)
+    // Storage: Deip NdaIdByProjectIdV1 (r:0 w:1)
     fn create_project_nda(p: u32) -> Weight {
         (71_768_000 as Weight)
             // Standard Error: 60_000
             .saturating_add((8_374_000 as Weight).saturating_mul(p as Weight))
             .saturating_add(T::DbWeight::get().reads(2 as Weight))
             .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
-            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
     }
     // Storage: Timestamp Now (r:1 w:0)
     // Storage: Deip NdaMapV1 (r:1 w:0)
-    // Storage: Deip NdaAccessRequests (r:1 w:1)
-    // Storage: Deip NdaAccessRequestMapV1 (r:0 w:1)
+    // Storage: Deip NdaAccessRequestMapV1 (r:1 w:1)
+    // Storage: Deip NdaAccessRequestIdsByNdaIdV1 (r:1 w:1)
     fn create_nda_content_access_request() -> Weight {
         (60_406_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
             .saturating_add(T::DbWeight::get().writes(2 as Weight))
     }
     // Storage: Deip NdaAccessRequestMapV1 (r:1 w:1)
@@ -215,19 +354,31 @@ impl<T: frame_system::Config> WeightInfo for Weights<T> {
             .saturating_add(T::DbWeight::get().reads(2 as Weight))
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
+    // Storage: Deip AssessmentModelMapV1 (r:1 w:1)
+    fn add_assessment_model(c: u32) -> Weight {
+        (30_482_000 as Weight)
+            .saturating_add((1_236_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
     // Storage: Deip Domains (r:1 w:0)
+    // Storage: Deip AssessmentModelMapV1 (r:1 w:0)
     // Storage: Deip ReviewMapV1 (r:1 w:1)
     // Storage: Deip ProjectContentMapV1 (r:1 w:0)
     // Storage: Deip ReviewIdByAccountIdV1 (r:0 w:1)
     // Storage: Deip ReviewIdByProjectIdV1 (r:0 w:1)
     // Storage: Deip ReviewIdByContentIdV1 (r:0 w:1)
+    // Storage: Deip StorageDepositV1 (r:0 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Deip ReviewIdByDomainIdV1 (r:0 w:10)
     fn create_review(d: u32) -> Weight {
         (44_425_000 as Weight)
             // Standard Error: 22_000
             .saturating_add((6_612_000 as Weight).saturating_mul(d as Weight))
-            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
             .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
-            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(d as Weight)))
     }
     // Storage: Deip Domains (r:1 w:0)
     // Storage: Deip ReviewMapV1 (r:1 w:0)
@@ -235,41 +386,116 @@ impl<T: frame_system::Config> WeightInfo for Weights<T> {
     // Storage: Timestamp Now (r:1 w:0)
     // Storage: Deip VoteIdByReviewIdV1 (r:0 w:1)
     // Storage: Deip VoteIdByAccountId (r:0 w:1)
+    // Storage: Deip ExpertiseV1 (r:2 w:1)
+    // Storage: Deip ReviewUpvoteCountV1 (r:1 w:1)
     fn upvote_review() -> Weight {
         (65_252_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    // Storage: Deip ReviewRequestMapV1 (r:1 w:1)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Storage: Deip ProjectContentMapV1 (r:1 w:0)
+    // Storage: Deip ProjectMapV1 (r:1 w:0)
+    // Storage: DeipFNFT ItemRepo (r:1 w:0)
+    // Storage: Deip Domains (r:1 w:0)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Deip ReviewRequestIdByContentIdV1 (r:0 w:1)
+    fn create_review_request() -> Weight {
+        (61_283_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    // Storage: Deip ReviewRequestMapV1 (r:1 w:1)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Storage: Deip ReviewMapV1 (r:1 w:0)
+    // Storage: Deip ReviewUpvoteCountV1 (r:1 w:0)
+    // Storage: System Account (r:2 w:2)
+    // Storage: Deip ReviewRequestIdByContentIdV1 (r:0 w:1)
+    fn claim_review_reward() -> Weight {
+        (68_590_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    // Storage: Deip ReviewRequestMapV1 (r:1 w:1)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Deip ReviewRequestIdByContentIdV1 (r:0 w:1)
+    fn refund_review_request() -> Weight {
+        (42_176_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
             .saturating_add(T::DbWeight::get().writes(3 as Weight))
     }
     // Storage: Deip DomainCount (r:1 w:1)
-    // Storage: Deip Domains (r:1 w:1)
+    // Storage: Deip Domains (r:2 w:1)
+    // Storage: Deip DomainIdByParentIdV1 (r:0 w:1)
     fn add_domain() -> Weight {
         (46_126_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    // Storage: Deip Domains (r:1 w:1)
+    fn rename_domain() -> Weight {
+        (24_310_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: Deip Domains (r:1 w:1)
+    // Storage: Deip DomainIdByParentIdV1 (r:0 w:2)
+    fn set_domain_parent(d: u32) -> Weight {
+        (31_540_000 as Weight)
+            .saturating_add((4_915_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    // Storage: Deip DomainIdByParentIdV1 (r:1 w:0)
+    // Storage: Deip Domains (r:1 w:1)
+    fn deprecate_domain(c: u32) -> Weight {
+        (29_870_000 as Weight)
+            .saturating_add((4_102_000 as Weight).saturating_mul(c as Weight))
             .saturating_add(T::DbWeight::get().reads(2 as Weight))
-            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: Deip ContentTypeMapV1 (r:1 w:1)
+    fn add_content_type() -> Weight {
+        (19_364_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: Deip ContentTypeMapV1 (r:1 w:1)
+    fn deprecate_content_type() -> Weight {
+        (18_921_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
     // Storage: Timestamp Now (r:1 w:0)
     // Storage: Deip ContractAgreementMap (r:1 w:1)
     // Storage: Deip ProjectMapV1 (r:1 w:0)
     // Storage: Deip ContractAgreementIdByType (r:0 w:1)
+    // Storage: Deip ContractAgreementIdByExpirationTime (r:0 w:1)
     fn create_contract_agreement_project_license() -> Weight {
         (49_030_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(3 as Weight))
-            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
     }
     // Storage: Timestamp Now (r:1 w:0)
     // Storage: Deip ContractAgreementMap (r:1 w:1)
     // Storage: Deip ContractAgreementIdByType (r:0 w:1)
+    // Storage: Deip ContractAgreementIdByExpirationTime (r:0 w:1)
     fn create_contract_agreement_generic_contract() -> Weight {
         (42_595_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(2 as Weight))
-            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
     }
     // Storage: Deip ContractAgreementMap (r:1 w:1)
     // Storage: Timestamp Now (r:1 w:0)
+    // Storage: Deip ContractAgreementIdByExpirationTime (r:0 w:1)
     fn accept_contract_agreement_project_license_unsigned() -> Weight {
         (40_842_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(2 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
     }
     // Storage: Deip ContractAgreementMap (r:1 w:1)
     // Storage: Timestamp Now (r:1 w:0)
@@ -280,21 +506,71 @@ impl<T: frame_system::Config> WeightInfo for Weights<T> {
     // Storage: Assets FtBalanceMap (r:1 w:0)
     // Storage: Deip ProjectMapV1 (r:1 w:0)
     // Storage: Assets ProjectIdByAssetId (r:1 w:0)
+    // Storage: Deip ContractAgreementIdByExpirationTime (r:0 w:1)
     fn accept_contract_agreement_project_license_signed_by_licenser() -> Weight {
         (197_721_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(14 as Weight))
-            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
     }
     // Storage: Deip ContractAgreementMap (r:1 w:1)
+    // Storage: Deip ContractAgreementIdByExpirationTime (r:0 w:1)
     fn accept_contract_agreement_generic_contract_partially_accepted() -> Weight {
         (36_490_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(1 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
     }
     // Storage: Deip ContractAgreementMap (r:1 w:1)
+    // Storage: Deip ContractAgreementIdByExpirationTime (r:0 w:1)
     fn accept_contract_agreement_generic_contract_finalized() -> Weight {
         (47_737_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(1 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: Timestamp Now (r:1 w:0)
+    // Storage: Deip ContractAgreementMap (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Deip ContractAgreementIdByType (r:0 w:1)
+    // Storage: Deip ContractAgreementIdByExpirationTime (r:0 w:1)
+    fn create_contract_agreement_escrow_contract(m: u32) -> Weight {
+        (61_427_000 as Weight)
+            .saturating_add((2_314_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    // Storage: Deip ContractAgreementMap (r:1 w:1)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Storage: Deip ContractAgreementIdByExpirationTime (r:0 w:1)
+    fn accept_contract_agreement_escrow_contract() -> Weight {
+        (48_105_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: Deip ContractAgreementMap (r:1 w:1)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Storage: System Account (r:2 w:2)
+    // Storage: Deip ContractAgreementIdByExpirationTime (r:0 w:1)
+    fn approve_contract_milestone() -> Weight {
+        (72_836_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    // Storage: Deip ContractAgreementMap (r:1 w:1)
+    // Storage: Timestamp Now (r:1 w:0)
+    // Storage: Deip ContractAgreementIdByProjectIdV1 (r:0 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Deip ContractAgreementIdByExpirationTime (r:0 w:1)
+    fn expire_contract_agreement() -> Weight {
+        (42_563_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    // Storage: Deip ContractAgreementMap (r:1 w:1)
+    // Storage: Deip ContractAgreementIdByProjectIdV1 (r:0 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Deip ContractAgreementIdByExpirationTime (r:0 w:1)
+    fn reject_contract_agreement() -> Weight {
+        (38_914_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
     }
 }
@@ -3,20 +3,30 @@ use sp_std::prelude::*;
 use codec::Codec;
 
 use super::dao::{DaoId, Dao};
+use super::timelock::{ChangeId, PendingChange};
 
 pub type GetResult<AccountId> = Option<Dao<AccountId, DaoId>>;
 pub type GetMultiResult<AccountId> = Vec<Option<Dao<AccountId, DaoId>>>;
+pub type PendingAuthorityChangesResult<AccountId, BlockNumber> =
+    Vec<(ChangeId, PendingChange<AccountId, BlockNumber>)>;
+pub type SubDaosResult = Vec<DaoId>;
 
 sp_api::decl_runtime_apis! {
-    pub trait DeipDaoRuntimeApi<AccountId>
-        where AccountId: Codec
+    #[api_version(2)]
+    pub trait DeipDaoRuntimeApi<AccountId, BlockNumber>
+        where AccountId: Codec,
+              BlockNumber: Codec,
     {
         fn get(id: DaoId) -> GetResult<AccountId>;
         fn get_multi(ids: Vec<DaoId>) -> GetMultiResult<AccountId>;
+        /// Since version 2
+        fn get_pending_authority_changes(id: DaoId) -> PendingAuthorityChangesResult<AccountId, BlockNumber>;
+        /// Since version 2
+        fn get_sub_daos(id: DaoId) -> SubDaosResult;
     }
 }
 
-use super::{Pallet, Config, DaoRepository};
+use super::{Pallet, Config, DaoRepository, DaoPendingAuthorityChanges, DaoChildren};
 
 impl<T: Config> Pallet<T> {
     pub fn rpc_get(id: DaoId) -> GetResult<T::AccountId> {
@@ -25,4 +35,18 @@ impl<T: Config> Pallet<T> {
     pub fn rpc_get_multi(ids: Vec<DaoId>) -> GetMultiResult<T::AccountId> {
         ids.into_iter().map(|x| DaoRepository::<T>::try_get(x).ok()).collect()
     }
+    pub fn rpc_get_pending_authority_changes(
+        id: DaoId,
+    ) -> PendingAuthorityChangesResult<T::AccountId, T::BlockNumber> {
+        let mut changes = DaoPendingAuthorityChanges::<T>::iter_prefix(id).collect::<Vec<_>>();
+        changes.sort_by_key(|(change_id, _)| *change_id);
+        changes
+    }
+    pub fn rpc_get_sub_daos(id: DaoId) -> SubDaosResult {
+        let mut sub_daos = DaoChildren::<T>::iter_prefix(id)
+            .map(|(sub_dao, _)| sub_dao)
+            .collect::<Vec<_>>();
+        sub_daos.sort();
+        sub_daos
+    }
 }
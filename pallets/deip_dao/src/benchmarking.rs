@@ -1,10 +1,11 @@
 #![cfg(feature = "runtime-benchmarks")]
 
-use super::{dao::*, *};
+use super::{dao::*, dissolution::*, role::*, spending::*, *};
 use core::convert::TryInto;
 use frame_benchmarking::{account, benchmarks, whitelist_account, whitelisted_caller};
 use frame_support::{ensure, traits::Get};
 use frame_system::{Config as Sys, EventRecord, RawOrigin};
+use sp_runtime::Perbill;
 use sp_std::prelude::*;
 
 use crate::Pallet;
@@ -86,6 +87,66 @@ fn replace_authority<T: Config>(dao: &DaoOf<T>) -> AlterAuthority<T::AccountId>
     AlterAuthority::<T::AccountId>::ReplaceAuthority { authority_key, authority }
 }
 
+fn init_role(index: u32) -> RoleId {
+    RoleId::from_low_u64_be(index as u64)
+}
+
+fn set_role<T: Config>(dao: &DaoOf<T>, role: RoleId, calls: Vec<CallIndex>) {
+    Pallet::<T>::alter_roles(
+        RawOrigin::Signed(dao.dao_key().clone()).into(),
+        AlterRoles::SetRole { role, calls },
+    )
+    .unwrap();
+}
+
+fn grant_role<T: Config>(dao: &DaoOf<T>, role: RoleId, member: T::AccountId) {
+    Pallet::<T>::alter_roles(
+        RawOrigin::Signed(dao.dao_key().clone()).into(),
+        AlterRoles::Grant { role, member },
+    )
+    .unwrap();
+}
+
+fn limit_spending<T: Config>(dao: &DaoOf<T>, asset: AssetIdOf<T>) {
+    let limit = SpendingLimitOf::<T> { amount: 1u32.into(), period: 1u32.into() };
+    Pallet::<T>::set_spending_limit(
+        RawOrigin::Signed(dao.dao_key().clone()).into(),
+        asset,
+        Some(limit),
+    )
+    .unwrap();
+}
+
+fn lock_authority_changes<T: Config>(dao: &DaoOf<T>) {
+    Pallet::<T>::set_timelock(RawOrigin::Signed(dao.dao_key().clone()).into(), Some(1u32.into()))
+        .unwrap();
+}
+
+fn queue_authority_change<T: Config>(dao: &DaoOf<T>) -> ChangeId {
+    let change_id = DaoAuthorityChangeNonce::<T>::get(dao.id());
+    Pallet::<T>::alter_authority(
+        RawOrigin::Signed(dao.dao_key().clone()).into(),
+        add_member::<T>(dao, true),
+    )
+    .unwrap();
+    change_id
+}
+
+fn init_sub_dao<T: Config>(parent: &DaoOf<T>) -> DaoOf<T> {
+    let id = DaoId::from([8; 20]);
+    let authority = init_authority::<T>(1, 0);
+    let authority_key = authority.authority_key();
+    Pallet::<T>::create_sub_dao(
+        RawOrigin::Signed(parent.dao_key().clone()).into(),
+        id,
+        authority_key,
+        authority.into(),
+        None,
+    )
+    .unwrap();
+    DaoRepository::<T>::get(id).unwrap()
+}
+
 benchmarks! {
     create {
         let m in 1 .. T::MaxSignatories::get().try_into().unwrap();
@@ -98,6 +159,36 @@ benchmarks! {
         assert_last_event::<T>(Event::DaoCreate(dao).into())
     }
 
+    create_sub_dao {
+        let m in 1 .. T::MaxSignatories::get().try_into().unwrap();
+        let parent = init_dao::<T>(1);
+        let parent = create_dao::<T>(parent);
+        let id = DaoId::from([8; 20]);
+        let authority = init_authority::<T>(m as u16, 0);
+        let authority_key = authority.authority_key();
+    }: _(RawOrigin::Signed(parent.dao_key().clone()), id, authority_key, authority.into(), None)
+    verify {
+        assert_last_event::<T>(Event::DaoSubDaoCreated(*parent.id(), id).into())
+    }
+
+    freeze_sub_dao {
+        let parent = init_dao::<T>(1);
+        let parent = create_dao::<T>(parent);
+        let dao = init_sub_dao::<T>(&parent);
+    }: _(RawOrigin::Signed(parent.dao_key().clone()), *dao.id(), true)
+    verify {
+        assert_last_event::<T>(Event::DaoSubDaoFrozen(*parent.id(), *dao.id(), true).into())
+    }
+
+    reclaim_sub_dao {
+        let parent = init_dao::<T>(1);
+        let parent = create_dao::<T>(parent);
+        let dao = init_sub_dao::<T>(&parent);
+    }: _(RawOrigin::Signed(parent.dao_key().clone()), *dao.id())
+    verify {
+        assert_last_event::<T>(Event::DaoSubDaoReclaimed(*parent.id(), *dao.id()).into())
+    }
+
     alter_authority_add_member {
         let dao = init_dao::<T>(T::MaxSignatories::get() - 1);
         let dao = create_dao::<T>(dao);
@@ -163,9 +254,139 @@ benchmarks! {
         assert_last_event::<T>(Event::DaoMetadataUpdated(dao).into())
     }
 
+    alter_roles_set_role {
+        let c in 1 .. T::MaxRoleCalls::get().try_into().unwrap();
+        let dao = init_dao::<T>(1);
+        let dao = create_dao::<T>(dao);
+        let calls = (0..c).map(|x| (x as u8, (x >> 8) as u8)).collect();
+        let alter_roles = AlterRoles::<T::AccountId>::SetRole { role: init_role(0), calls };
+    }: alter_roles(RawOrigin::Signed(dao.dao_key().clone()), alter_roles.clone())
+    verify {
+        assert_last_event::<T>(Event::DaoAlterRoles(*dao.id(), alter_roles).into())
+    }
+
+    alter_roles_remove_role {
+        let dao = init_dao::<T>(1);
+        let dao = create_dao::<T>(dao);
+        set_role::<T>(&dao, init_role(0), vec![]);
+        let alter_roles = AlterRoles::<T::AccountId>::RemoveRole { role: init_role(0) };
+    }: alter_roles(RawOrigin::Signed(dao.dao_key().clone()), alter_roles.clone())
+    verify {
+        assert_last_event::<T>(Event::DaoAlterRoles(*dao.id(), alter_roles).into())
+    }
+
+    alter_roles_grant {
+        let dao = init_dao::<T>(1);
+        let dao = create_dao::<T>(dao);
+        let member = init_member::<T>(1);
+        for x in 1..T::MaxMemberRoles::get() as u32 {
+            set_role::<T>(&dao, init_role(x), vec![]);
+            grant_role::<T>(&dao, init_role(x), member.clone());
+        }
+        set_role::<T>(&dao, init_role(0), vec![]);
+        let alter_roles = AlterRoles::<T::AccountId>::Grant { role: init_role(0), member };
+    }: alter_roles(RawOrigin::Signed(dao.dao_key().clone()), alter_roles.clone())
+    verify {
+        assert_last_event::<T>(Event::DaoAlterRoles(*dao.id(), alter_roles).into())
+    }
+
+    alter_roles_revoke {
+        let dao = init_dao::<T>(1);
+        let dao = create_dao::<T>(dao);
+        let member = init_member::<T>(1);
+        for x in 0..T::MaxMemberRoles::get() as u32 {
+            set_role::<T>(&dao, init_role(x), vec![]);
+            grant_role::<T>(&dao, init_role(x), member.clone());
+        }
+        let alter_roles = AlterRoles::<T::AccountId>::Revoke { role: init_role(0), member };
+    }: alter_roles(RawOrigin::Signed(dao.dao_key().clone()), alter_roles.clone())
+    verify {
+        assert_last_event::<T>(Event::DaoAlterRoles(*dao.id(), alter_roles).into())
+    }
+
+    set_spending_limit {
+        let l in 1 .. T::MaxSpendingLimits::get().try_into().unwrap();
+        let dao = init_dao::<T>(1);
+        let dao = create_dao::<T>(dao);
+        for x in 1..l {
+            limit_spending::<T>(&dao, T::Assets::benchmark_asset(x));
+        }
+        let asset = T::Assets::benchmark_asset(0);
+        let limit = Some(SpendingLimitOf::<T> { amount: 1u32.into(), period: 1u32.into() });
+    }: _(RawOrigin::Signed(dao.dao_key().clone()), asset.clone(), limit.clone())
+    verify {
+        assert_last_event::<T>(Event::DaoSpendingLimitSet(*dao.id(), asset, limit).into())
+    }
+
+    set_timelock {
+        let dao = init_dao::<T>(1);
+        let dao = create_dao::<T>(dao);
+        let delay = Some(1u32.into());
+    }: _(RawOrigin::Signed(dao.dao_key().clone()), delay)
+    verify {
+        assert_last_event::<T>(Event::DaoTimelockSet(*dao.id(), delay).into())
+    }
+
+    cancel_authority_change {
+        let dao = init_dao::<T>(1);
+        let dao = create_dao::<T>(dao);
+        lock_authority_changes::<T>(&dao);
+        let change_id = queue_authority_change::<T>(&dao);
+    }: _(RawOrigin::Signed(dao.dao_key().clone()), change_id)
+    verify {
+        assert_last_event::<T>(Event::DaoAuthorityChangeCancelled(*dao.id(), change_id).into())
+    }
+
+    apply_authority_change {
+        let m in 1 .. T::MaxSignatories::get().saturating_sub(1).into();
+        let dao = init_dao::<T>(m as u16);
+        let dao = create_dao::<T>(dao);
+        lock_authority_changes::<T>(&dao);
+        let change_id = queue_authority_change::<T>(&dao);
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + 1u32.into()
+        );
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), *dao.id(), change_id)
+    verify {
+        assert_last_event::<T>(Event::DaoAuthorityChangeApplied(*dao.id(), change_id).into())
+    }
+
+    dissolve {
+        let r in 1 .. T::MaxSignatories::get().try_into().unwrap();
+        let a in 0 .. T::MaxDistributedAssets::get().try_into().unwrap();
+        let dao = init_dao::<T>(1);
+        let dao = create_dao::<T>(dao);
+        let assets = (0..a).map(|x| T::Assets::benchmark_asset(x)).collect::<Vec<_>>();
+        for asset in &assets {
+            T::Assets::benchmark_deposit(asset, dao.dao_key(), 1_000_000u32.into());
+        }
+        let share = Perbill::from_rational(1, r);
+        let mut recipients = (0..r).map(|x| (init_member::<T>(x), share)).collect::<Vec<_>>();
+        recipients[0].1 = Perbill::from_parts(
+            Perbill::one().deconstruct() - share.deconstruct() * (r - 1)
+        );
+        let distribution = Distribution::Recipients(recipients);
+    }: _(RawOrigin::Signed(dao.dao_key().clone()), distribution, assets)
+    verify {
+        assert_last_event::<T>(Event::DaoDissolved(*dao.id()).into())
+    }
+
     on_behalf {
+        let r in 1 .. T::MaxMemberRoles::get().try_into().unwrap();
+        let l in 0 .. T::MaxSpendingLimits::get().try_into().unwrap();
         let dao = init_dao::<T>(1);
         let dao = create_dao::<T>(dao);
-        let call = frame_system::Call::<T>::remark{ remark: vec![] }.into();
-    }: _(RawOrigin::Signed(dao.authority_key().clone()), dao.id().clone(), Box::new(call))
+        for x in 0..l {
+            limit_spending::<T>(&dao, T::Assets::benchmark_asset(x));
+        }
+        let call: <T as Config>::Call = frame_system::Call::<T>::remark{ remark: vec![] }.into();
+        // Only the last role of the member allows the call
+        let member = init_member::<T>(1);
+        for x in 0..r {
+            let calls = if x + 1 == r { vec![call_index(&call).unwrap()] } else { vec![] };
+            set_role::<T>(&dao, init_role(x), calls);
+            grant_role::<T>(&dao, init_role(x), member.clone());
+        }
+    }: _(RawOrigin::Signed(member), dao.id().clone(), Box::new(call))
 }
@@ -12,7 +12,16 @@
 //! ### Dispatchable Functions
 //!
 //! * `create` - Create a DAO.
+//! * `create_sub_dao` - Create a DAO controlled by the parent DAO.
+//! * `freeze_sub_dao` - Freeze or unfreeze a sub-DAO.
+//! * `reclaim_sub_dao` - Replace authority of a sub-DAO with the parent DAO.
 //! * `alter_authority` - Alter DAO's authority.
+//! * `alter_roles` - Alter DAO's roles and their assignment to accounts.
+//! * `set_spending_limit` - Limit DAO's spending of an asset per period.
+//! * `set_timelock` - Set delay of DAO's authority changes.
+//! * `cancel_authority_change` - Cancel queued authority change.
+//! * `apply_authority_change` - Apply queued authority change after its delay.
+//! * `dissolve` - Distribute DAO's assets and close it for good.
 //! * `on_behalf` - Perform action on behalf of a DAO.
 //!
 //! [`Call`]: ./enum.Call.html
@@ -41,6 +50,7 @@ pub mod pallet {
 
     use frame_support::{
         pallet_prelude::*,
+        transactional,
         weights::{GetDispatchInfo, PostDispatchInfo},
         Hashable,
     };
@@ -51,7 +61,7 @@ pub mod pallet {
 
     use frame_support::dispatch::DispatchResult;
     use sp_runtime::{
-        traits::{Dispatchable, IdentifyAccount},
+        traits::{Dispatchable, IdentifyAccount, Zero},
         MultiSigner,
     };
 
@@ -81,6 +91,20 @@ pub mod pallet {
         /// Max signatories in DAO Authority
         #[pallet::constant]
         type MaxSignatories: Get<u16>;
+        /// Max calls allowed to a single DAO role
+        #[pallet::constant]
+        type MaxRoleCalls: Get<u16>;
+        /// Max roles assigned to a single account within a DAO
+        #[pallet::constant]
+        type MaxMemberRoles: Get<u16>;
+        /// Assets held by DAOs that spending limits apply to
+        type Assets: DaoAssetsT<Self::AccountId>;
+        /// Max assets with spending limits per DAO
+        #[pallet::constant]
+        type MaxSpendingLimits: Get<u16>;
+        /// Max assets distributed by a single dissolution
+        #[pallet::constant]
+        type MaxDistributedAssets: Get<u16>;
     }
 
     use frame_support::traits::{StorageVersion, GetStorageVersion};
@@ -120,6 +144,44 @@ pub mod pallet {
         Forbidden,
         ///
         AuthorityMismatch,
+        /// Role not found in the DAO
+        RoleNotFound,
+        /// Role is assigned to accounts and can't be removed
+        RoleInUse,
+        /// Role allows more calls than `MaxRoleCalls`
+        TooManyRoleCalls,
+        /// Account is assigned more roles than `MaxMemberRoles`
+        TooManyMemberRoles,
+        /// None of the account roles allows the call
+        CallNotPermitted,
+        /// Spending limit must have non-zero period
+        SpendingLimitWrongTerms,
+        /// DAO has more spending limits than `MaxSpendingLimits`
+        TooManySpendingLimits,
+        /// Spending over the limit requires the full authority
+        SpendingLimitExceeded,
+        /// Authority or spending limits of the DAO with spending limits
+        /// can be altered by the full authority only
+        EscalationRequired,
+        /// Timelock delay must be non-zero
+        TimelockWrongTerms,
+        /// Queued authority change can't be applied before its execution block
+        AuthorityChangeIsLocked,
+        /// Dissolution recipients must be distinct from the DAO and their shares
+        /// must sum up to 100%
+        DissolutionWrongTerms,
+        /// Dissolution distributes more assets than `MaxDistributedAssets`
+        TooManyDistributedAssets,
+        /// DAO with a timelock can't be dissolved, the timelock must be removed first
+        DissolutionIsLocked,
+        /// DAO was dissolved, its id can't be reused
+        Dissolved,
+        /// DAO isn't a sub-DAO of the origin DAO
+        NotSubDao,
+        /// Sub-DAO is frozen by its parent
+        Frozen,
+        /// DAO with sub-DAOs can't be dissolved
+        HasSubDaos,
     }
 
     #[pallet::event]
@@ -130,6 +192,26 @@ pub mod pallet {
         /// Emits when authority alteration
         DaoAlterAuthority(DaoOf<T>),
         DaoMetadataUpdated(DaoOf<T>),
+        /// Emits when DAO roles alteration
+        DaoAlterRoles(DaoId, AlterRoles<T::AccountId>),
+        /// Emits when spending limit of the asset is set or removed
+        DaoSpendingLimitSet(DaoId, AssetIdOf<T>, Option<SpendingLimitOf<T>>),
+        /// Emits when timelock of authority changes is set or removed
+        DaoTimelockSet(DaoId, Option<T::BlockNumber>),
+        /// Emits when authority change is queued by the timelock
+        DaoAuthorityChangeQueued(DaoId, ChangeId, PendingChangeOf<T>),
+        /// Emits when queued authority change is cancelled
+        DaoAuthorityChangeCancelled(DaoId, ChangeId),
+        /// Emits when queued authority change is applied
+        DaoAuthorityChangeApplied(DaoId, ChangeId),
+        /// Emits when DAO is dissolved
+        DaoDissolved(DaoId),
+        /// Emits when sub-DAO is created by the parent DAO
+        DaoSubDaoCreated(DaoId, DaoId),
+        /// Emits when sub-DAO is frozen or unfrozen by the parent DAO
+        DaoSubDaoFrozen(DaoId, DaoId, bool),
+        /// Emits when sub-DAO is reclaimed by the parent DAO
+        DaoSubDaoReclaimed(DaoId, DaoId),
     }
 
     #[doc(hidden)]
@@ -253,6 +335,14 @@ pub mod pallet {
                 }
                 multi_account_id::<AccountId>(&self.signatories[..], self.threshold)
             }
+            /// Key of the authority with all signatories required. It's the same as
+            /// `authority_key` for plain account or when the threshold is already full
+            pub fn full_authority_key(&self) -> AccountId {
+                if self.threshold == 0 {
+                    return self.authority_key()
+                }
+                multi_account_id::<AccountId>(&self.signatories[..], self.signatories.len() as u16)
+            }
         }
         impl<AccountId: Ord + Eq + PartialEq> Authority<AccountId> {
             pub fn add_member(&mut self, member: AccountId, preserve_threshold: bool) {
@@ -412,6 +502,498 @@ pub mod pallet {
         }
     }
 
+    pub use role::{CallIndex, RoleId};
+    use role::*;
+    /// Named roles of the DAO. A role carries an allowlist of calls that accounts
+    /// assigned to it may dispatch through `on_behalf` without the DAO authority.
+    pub mod role {
+        use super::{Config, DaoId, DaoMemberRoles, DaoRoles, Error};
+        use frame_support::pallet_prelude::*;
+        use sp_std::prelude::*;
+
+        #[cfg(feature = "std")]
+        use serde::{Deserialize, Serialize};
+
+        pub type RoleId = sp_core::H160;
+        /// Index of the pallet in the runtime and index of the call in the pallet
+        pub type CallIndex = (u8, u8);
+
+        #[derive(Debug, Clone, Default, Eq, PartialEq, Encode, Decode, TypeInfo)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub struct Role {
+            /// Sorted allowlist of calls
+            pub(crate) calls: Vec<CallIndex>,
+            /// Number of accounts the role is assigned to
+            pub(crate) members: u32,
+        }
+        impl Role {
+            pub fn calls(&self) -> &[CallIndex] {
+                &self.calls
+            }
+            pub fn members(&self) -> u32 {
+                self.members
+            }
+            pub fn allows(&self, call: &CallIndex) -> bool {
+                self.calls.binary_search(call).is_ok()
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "std", serde(tag = "operation", content = "data"))]
+        pub enum AlterRoles<AccountId> {
+            /// Create the role or replace its allowlist
+            SetRole { role: RoleId, calls: Vec<CallIndex> },
+            /// Remove the role that isn't assigned to anybody
+            RemoveRole { role: RoleId },
+            Grant { role: RoleId, member: AccountId },
+            Revoke { role: RoleId, member: AccountId },
+        }
+
+        /// Index of the runtime call: outer call encoding starts with the pallet index
+        /// followed by the call index
+        pub fn call_index<C: Encode>(call: &C) -> Option<CallIndex> {
+            call.using_encoded(|encoded| <CallIndex>::decode(&mut &encoded[..]).ok())
+        }
+
+        /// Checks that some of the `who` roles in the DAO allows the call.
+        /// Note that allowing a call which dispatches other calls, e.g. a batch,
+        /// allows everything those calls may dispatch.
+        pub fn ensure_permitted<T: Config>(
+            dao: &DaoId,
+            who: &T::AccountId,
+            call: &<T as Config>::Call,
+        ) -> Result<(), Error<T>> {
+            let roles = DaoMemberRoles::<T>::get(dao, who);
+            ensure!(!roles.is_empty(), Error::<T>::Forbidden);
+            let call = call_index(call).ok_or(Error::<T>::CallNotPermitted)?;
+            let permitted = roles
+                .iter()
+                .filter_map(|role| DaoRoles::<T>::get(dao, role))
+                .any(|role| role.allows(&call));
+            ensure!(permitted, Error::<T>::CallNotPermitted);
+            Ok(())
+        }
+    }
+
+    pub use spending::{DaoAsset, DaoAssets, DaoAssetsT};
+    use spending::*;
+    /// Limits on how much of an asset the DAO may spend per period.
+    /// Spending is measured as decrease of the DAO transferable balances during `on_behalf`,
+    /// so nested calls (batches, proposals etc.) are limited as well. Funds reserved
+    /// by the call count as spent since the DAO can't control them anymore.
+    pub mod spending {
+        use super::{
+            ChangeId, Config, DaoAuthorityChangeNonce, DaoId, DaoOf, DaoRepository,
+            DaoSpendingLimits, DaoSpendings, Error, StorageOps,
+        };
+        use frame_support::{
+            pallet_prelude::*,
+            traits::{Currency, ExistenceRequirement},
+        };
+        use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating, Zero};
+        use sp_std::{marker::PhantomData, prelude::*};
+
+        use deip_asset_system::{pick_fraction, transfer_fraction, NFTImplT, NFTokenFractionT};
+        use deip_storage_ops::StorageOpsTransaction;
+        #[cfg(feature = "std")]
+        use serde::{Deserialize, Serialize};
+
+        use super::dao::Authority;
+
+        /// Balances of the assets held by DAOs
+        pub trait DaoAssetsT<AccountId> {
+            type AssetId: Member + Parameter;
+            type Balance: Member + Parameter + AtLeast32BitUnsigned + Copy;
+
+            fn balance(asset: &Self::AssetId, who: &AccountId) -> Self::Balance;
+
+            /// Part of the balance the account is free to transfer
+            fn transferable(asset: &Self::AssetId, who: &AccountId) -> Self::Balance;
+
+            fn transfer(
+                asset: &Self::AssetId,
+                from: &AccountId,
+                to: &AccountId,
+                amount: Self::Balance,
+            ) -> DispatchResult;
+
+            #[cfg(feature = "runtime-benchmarks")]
+            fn benchmark_asset(index: u32) -> Self::AssetId;
+
+            #[cfg(feature = "runtime-benchmarks")]
+            fn benchmark_deposit(asset: &Self::AssetId, who: &AccountId, amount: Self::Balance);
+        }
+
+        #[allow(type_alias_bounds)]
+        pub type AssetIdOf<T: Config> = <T::Assets as DaoAssetsT<T::AccountId>>::AssetId;
+        #[allow(type_alias_bounds)]
+        pub type BalanceOf<T: Config> = <T::Assets as DaoAssetsT<T::AccountId>>::Balance;
+        #[allow(type_alias_bounds)]
+        pub type SpendingLimitOf<T: Config> = SpendingLimit<BalanceOf<T>, T::BlockNumber>;
+        #[allow(type_alias_bounds)]
+        pub type SpendingOf<T: Config> = Spending<BalanceOf<T>, T::BlockNumber>;
+
+        #[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, TypeInfo)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub enum DaoAsset<Fingerprint> {
+            /// Native currency
+            Native,
+            /// Fractions of the F-NFT item
+            Fraction(Fingerprint),
+        }
+
+        /// [`DaoAssetsT`] backed by the native currency and an F-NFT implementation
+        /// (e.g. `pallet_deip_f_nft`)
+        pub struct DaoAssets<Currency, Impl>(PhantomData<(Currency, Impl)>);
+
+        impl<AccountId, C, Impl> DaoAssetsT<AccountId> for DaoAssets<C, Impl>
+        where
+            C: Currency<AccountId>,
+            C::Balance: Member + Parameter,
+            Impl: NFTImplT<Account = AccountId, FractionAmount = C::Balance>,
+            Impl::Fingerprint: Member,
+        {
+            type AssetId = DaoAsset<Impl::Fingerprint>;
+            type Balance = C::Balance;
+
+            fn balance(asset: &Self::AssetId, who: &AccountId) -> Self::Balance {
+                match asset {
+                    DaoAsset::Native => C::total_balance(who),
+                    DaoAsset::Fraction(fingerprint) => pick_fraction::<Impl>(who, *fingerprint)
+                        .map(|fraction| *fraction.amount())
+                        .unwrap_or_else(|_| Zero::zero()),
+                }
+            }
+
+            fn transferable(asset: &Self::AssetId, who: &AccountId) -> Self::Balance {
+                match asset {
+                    DaoAsset::Native => C::free_balance(who),
+                    DaoAsset::Fraction(_) => Self::balance(asset, who),
+                }
+            }
+
+            fn transfer(
+                asset: &Self::AssetId,
+                from: &AccountId,
+                to: &AccountId,
+                amount: Self::Balance,
+            ) -> DispatchResult {
+                match asset {
+                    DaoAsset::Native =>
+                        C::transfer(from, to, amount, ExistenceRequirement::AllowDeath),
+                    DaoAsset::Fraction(fingerprint) =>
+                        transfer_fraction::<Impl>(*fingerprint, from, to, amount),
+                }
+            }
+
+            #[cfg(feature = "runtime-benchmarks")]
+            fn benchmark_asset(index: u32) -> Self::AssetId {
+                use sp_runtime::traits::Hash;
+                match index {
+                    0 => DaoAsset::Native,
+                    _ => DaoAsset::Fraction(Impl::Hasher::hash_of(&index)),
+                }
+            }
+
+            #[cfg(feature = "runtime-benchmarks")]
+            fn benchmark_deposit(asset: &Self::AssetId, who: &AccountId, amount: Self::Balance) {
+                if let DaoAsset::Native = asset {
+                    drop(C::deposit_creating(who, amount));
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub struct SpendingLimit<Balance, BlockNumber> {
+            /// Max amount spent within the period
+            pub amount: Balance,
+            /// Period length in blocks
+            pub period: BlockNumber,
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub struct Spending<Balance, BlockNumber> {
+            /// Block the current period started at
+            pub(crate) since: BlockNumber,
+            /// Amount spent within the current period
+            pub(crate) amount: Balance,
+        }
+
+        /// Snapshot of the DAO taken before a limited `on_behalf` dispatch
+        pub struct SpendingGuard<T: Config> {
+            authority_key: T::AccountId,
+            authority: Authority<T::AccountId>,
+            change_nonce: ChangeId,
+            limits: Vec<(AssetIdOf<T>, SpendingLimitOf<T>, BalanceOf<T>)>,
+        }
+
+        impl<T: Config> SpendingGuard<T> {
+            pub fn new(dao: &DaoOf<T>) -> Self {
+                let limits = DaoSpendingLimits::<T>::iter_prefix(dao.id())
+                    .map(|(asset, limit)| {
+                        let balance = T::Assets::transferable(&asset, dao.dao_key());
+                        (asset, limit, balance)
+                    })
+                    .collect();
+                Self {
+                    authority_key: dao.authority_key().clone(),
+                    authority: dao.authority().clone(),
+                    change_nonce: DaoAuthorityChangeNonce::<T>::get(dao.id()),
+                    limits,
+                }
+            }
+
+            /// Accounts spending of the dispatched call. Fails if the call spent over
+            /// the limits, altered the authority or the limits themselves
+            /// or queued an authority change.
+            pub fn check(self, dao: &DaoOf<T>) -> Result<(), Error<T>> {
+                if self.limits.is_empty() {
+                    return Ok(())
+                }
+                let id = *dao.id();
+                let altered = DaoRepository::<T>::get(&id).map_or(true, |dao| {
+                    dao.authority_key() != &self.authority_key || dao.authority() != &self.authority
+                }) || DaoAuthorityChangeNonce::<T>::get(&id) != self.change_nonce;
+                ensure!(!altered, Error::<T>::EscalationRequired);
+                let limits = DaoSpendingLimits::<T>::iter_prefix(&id).collect::<Vec<_>>();
+                ensure!(
+                    limits.len() == self.limits.len() &&
+                        self.limits.iter().zip(limits.iter()).all(
+                            |((asset, limit, _), (new_asset, new_limit))| {
+                                asset == new_asset && limit == new_limit
+                            }
+                        ),
+                    Error::<T>::EscalationRequired
+                );
+
+                let now = frame_system::Pallet::<T>::block_number();
+                let mut spendings = Vec::new();
+                for (asset, limit, balance) in self.limits {
+                    let spent =
+                        balance.saturating_sub(T::Assets::transferable(&asset, dao.dao_key()));
+                    if spent.is_zero() {
+                        continue
+                    }
+                    let mut spending = DaoSpendings::<T>::get(&id, &asset)
+                        .filter(|s| now < s.since.saturating_add(limit.period))
+                        .unwrap_or(Spending { since: now, amount: Zero::zero() });
+                    spending.amount = spending.amount.saturating_add(spent);
+                    ensure!(spending.amount <= limit.amount, Error::<T>::SpendingLimitExceeded);
+                    spendings.push((asset, spending));
+                }
+                StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
+                    for (asset, spending) in spendings {
+                        ops.push_op(StorageOps::UpdateSpending(id, asset, spending));
+                    }
+                });
+                Ok(())
+            }
+        }
+    }
+
+    pub use timelock::ChangeId;
+    use timelock::*;
+    /// Optional delay of the DAO authority changes. Changes are queued and may be
+    /// cancelled by the current authority before the execution block.
+    pub mod timelock {
+        use super::{
+            AlterAuthority, Config, DaoAuthorityChangeNonce, DaoId, DaoTimelocks, Event, StorageOps,
+        };
+        use frame_support::pallet_prelude::*;
+        use sp_runtime::traits::Saturating;
+        use sp_std::prelude::*;
+
+        use deip_storage_ops::StorageOpsTransaction;
+        #[cfg(feature = "std")]
+        use serde::{Deserialize, Serialize};
+
+        /// Sequential number of the queued change within the DAO
+        pub type ChangeId = u32;
+
+        #[allow(type_alias_bounds)]
+        pub type AuthorityChangeOf<T: Config> = AuthorityChange<T::AccountId, T::BlockNumber>;
+        #[allow(type_alias_bounds)]
+        pub type PendingChangeOf<T: Config> = PendingChange<T::AccountId, T::BlockNumber>;
+
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "std", serde(tag = "operation", content = "data"))]
+        pub enum AuthorityChange<AccountId, BlockNumber> {
+            AlterAuthority(AlterAuthority<AccountId>),
+            /// Shortening or removal of the timelock is timelocked itself
+            SetTimelock(Option<BlockNumber>),
+        }
+
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub struct PendingChange<AccountId, BlockNumber> {
+            pub(crate) change: AuthorityChange<AccountId, BlockNumber>,
+            /// The change can be applied starting from this block
+            pub(crate) execute_at: BlockNumber,
+        }
+        impl<AccountId, BlockNumber> PendingChange<AccountId, BlockNumber> {
+            pub fn change(&self) -> &AuthorityChange<AccountId, BlockNumber> {
+                &self.change
+            }
+            pub fn execute_at(&self) -> &BlockNumber {
+                &self.execute_at
+            }
+        }
+
+        /// Queues the change if the DAO has a timelock. Returns `false` if the change
+        /// should be applied immediately instead.
+        pub fn queue<T: Config>(id: DaoId, change: AuthorityChangeOf<T>) -> bool {
+            let delay = match DaoTimelocks::<T>::get(&id) {
+                Some(delay) => delay,
+                None => return false,
+            };
+            let change_id = DaoAuthorityChangeNonce::<T>::get(&id);
+            let execute_at = frame_system::Pallet::<T>::block_number().saturating_add(delay);
+            let pending = PendingChange { change, execute_at };
+            StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
+                ops.push_op(StorageOps::QueueAuthorityChange(id, change_id, pending.clone()));
+                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoAuthorityChangeQueued(
+                    id, change_id, pending,
+                )));
+            });
+            true
+        }
+    }
+
+    pub use dissolution::Distribution;
+    use dissolution::*;
+    /// Closing of DAOs. Assets of the dissolved DAO are split among the recipients
+    /// and its id is tombstoned.
+    pub mod dissolution {
+        use super::{AssetIdOf, BalanceOf, Config, DaoAssetsT, DaoOf, Error};
+        use frame_support::pallet_prelude::*;
+        use sp_runtime::{
+            traits::{Saturating, Zero},
+            PerThing, Perbill,
+        };
+        use sp_std::prelude::*;
+
+        #[cfg(feature = "std")]
+        use serde::{Deserialize, Serialize};
+
+        /// Recipients of the DAO assets
+        #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+        #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+        pub enum Distribution<AccountId> {
+            /// Equal shares to the signatories of the DAO authority
+            Signatories,
+            /// Nominated recipients with their shares summing up to 100%
+            Recipients(Vec<(AccountId, Perbill)>),
+        }
+
+        impl<AccountId> Distribution<AccountId> {
+            /// Upper bound of the recipients count
+            pub fn recipients<T: Config>(&self) -> u32 {
+                match self {
+                    Self::Signatories => T::MaxSignatories::get() as u32,
+                    Self::Recipients(recipients) => recipients.len() as u32,
+                }
+            }
+        }
+
+        /// Checks the distribution terms and resolves the recipients
+        pub fn shares<T: Config>(
+            dao: &DaoOf<T>,
+            distribution: Distribution<T::AccountId>,
+        ) -> Result<Vec<(T::AccountId, Perbill)>, Error<T>> {
+            let shares = match distribution {
+                Distribution::Signatories => {
+                    let signatories = &dao.authority().signatories;
+                    let equal_share = Perbill::from_rational(1, signatories.len() as u32);
+                    signatories.iter().map(|who| (who.clone(), equal_share)).collect()
+                },
+                Distribution::Recipients(shares) => {
+                    let total =
+                        shares.iter().map(|(_, share)| share.deconstruct() as u64).sum::<u64>();
+                    ensure!(
+                        total == Perbill::one().deconstruct() as u64,
+                        Error::<T>::DissolutionWrongTerms
+                    );
+                    shares
+                },
+            };
+            ensure!(
+                !shares.is_empty() &&
+                    shares.len() <= T::MaxSignatories::get() as usize &&
+                    shares.iter().all(|(who, _)| who != dao.dao_key()),
+                Error::<T>::DissolutionWrongTerms
+            );
+            Ok(shares)
+        }
+
+        /// Transfers the transferable balances of the DAO assets to the recipients
+        /// by their shares. The rounding remainder goes to the first recipient.
+        pub fn distribute<T: Config>(
+            dao: &DaoOf<T>,
+            assets: &[AssetIdOf<T>],
+            shares: &[(T::AccountId, Perbill)],
+        ) -> DispatchResult {
+            for asset in assets {
+                let amount = T::Assets::transferable(asset, dao.dao_key());
+                let mut split = shares
+                    .iter()
+                    .map(|(who, share)| (who, share.mul_floor(amount)))
+                    .collect::<Vec<_>>();
+                let distributed = split
+                    .iter()
+                    .fold(BalanceOf::<T>::zero(), |total, (_, part)| total.saturating_add(*part));
+                split[0].1 = split[0].1.saturating_add(amount.saturating_sub(distributed));
+                for (who, part) in split {
+                    if !part.is_zero() {
+                        T::Assets::transfer(asset, dao.dao_key(), who, part)?;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    use sub_dao::*;
+    /// DAOs controlled by a parent DAO. The parent acts through its own `on_behalf`,
+    /// i.e. it is signed by the parent's `dao_key` (see `DeipAccountId::Dao` of the runtime).
+    pub mod sub_dao {
+        use super::{
+            dao_key2, load_dao, Config, DaoId, DaoOf, DaoParents, DaoRepository, Error, LoadBy,
+        };
+        use frame_support::pallet_prelude::*;
+
+        /// Checks that `who` is the parent DAO key of the sub-DAO
+        pub fn is_parent_key<T: Config>(sub_dao: &DaoId, who: &T::AccountId) -> bool {
+            DaoParents::<T>::get(sub_dao).map_or(false, |parent| &dao_key2::<T>(&parent) == who)
+        }
+
+        /// Loads the parent DAO by its key and the sub-DAO of that parent
+        pub fn load_sub_dao<T: Config>(
+            parent_key: &T::AccountId,
+            sub_dao: &DaoId,
+        ) -> Result<(DaoOf<T>, DaoOf<T>), Error<T>> {
+            let parent = load_dao::<T>(LoadBy::DaoKey { dao_key: parent_key })?;
+            ensure!(DaoParents::<T>::get(sub_dao) == Some(*parent.id()), Error::<T>::NotSubDao);
+            let sub_dao = DaoRepository::<T>::get(sub_dao).ok_or(Error::<T>::NotFound)?;
+            Ok((parent, sub_dao))
+        }
+    }
+
+    impl<AccountId> AlterRoles<AccountId> {
+        pub fn weight<T: Config>(&self) -> Weight {
+            match self {
+                Self::SetRole { calls, .. } =>
+                    T::DeipDaoWeightInfo::alter_roles_set_role(calls.len() as u32),
+                Self::RemoveRole { .. } => T::DeipDaoWeightInfo::alter_roles_remove_role(),
+                Self::Grant { .. } => T::DeipDaoWeightInfo::alter_roles_grant(),
+                Self::Revoke { .. } => T::DeipDaoWeightInfo::alter_roles_revoke(),
+            }
+        }
+    }
+
     impl<AccountId> AlterAuthority<AccountId> {
         pub fn weight<T: Config>(&self) -> Weight {
             match self {
@@ -444,6 +1026,26 @@ pub mod pallet {
         dao_key::<T::AccountId>(dao_id)
     }
 
+    /// Ensures that the origin is signed by the own key of the DAO identified by `Id`,
+    /// i.e. the call is dispatched through `on_behalf` of that DAO.
+    pub struct EnsureDao<T, Id>(sp_std::marker::PhantomData<(T, Id)>);
+
+    impl<T: Config, Id: Get<DaoId>> EnsureOrigin<T::Origin> for EnsureDao<T, Id> {
+        type Success = T::AccountId;
+
+        fn try_origin(o: T::Origin) -> Result<Self::Success, T::Origin> {
+            o.into().and_then(|o| match o {
+                RawOrigin::Signed(who) if DaoLookup::<T>::get(&who) == Some(Id::get()) => Ok(who),
+                r => Err(T::Origin::from(r)),
+            })
+        }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn successful_origin() -> T::Origin {
+            RawOrigin::Signed(dao_key2::<T>(&Id::get())).into()
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::weight((
@@ -462,11 +1064,105 @@ pub mod pallet {
                 authority.assert::<T>(&authority_key).map_err::<Error<T>, _>(Into::into)?;
             ensure!(!name.is_zero(), Error::<T>::Exists);
             ensure!(!DaoRepository::<T>::contains_key(&name), Error::<T>::Exists);
+            ensure!(!DaoTombstones::<T>::contains_key(&name), Error::<T>::Dissolved);
+            let dao_key = Self::dao_key(&name);
+            let dao = DaoOf::<T>::new(authority_key, authority, name, dao_key, metadata);
+            StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
+                ops.push_op(StorageOps::CreateDao(dao.clone()));
+                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoCreate(dao)));
+            });
+            Ok(Some(0).into())
+        }
+
+        /// Creates a sub-DAO of the origin DAO
+        #[pallet::weight((
+            T::DeipDaoWeightInfo::create_sub_dao(authority.signatories.len() as u32),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn create_sub_dao(
+            origin: OriginFor<T>,
+            name: DaoId,
+            authority_key: T::AccountId,
+            authority: InputAuthority<T::AccountId>,
+            metadata: Option<H256>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let parent = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            let authority =
+                authority.assert::<T>(&authority_key).map_err::<Error<T>, _>(Into::into)?;
+            ensure!(!name.is_zero(), Error::<T>::Exists);
+            ensure!(!DaoRepository::<T>::contains_key(&name), Error::<T>::Exists);
+            ensure!(!DaoTombstones::<T>::contains_key(&name), Error::<T>::Dissolved);
             let dao_key = Self::dao_key(&name);
             let dao = DaoOf::<T>::new(authority_key, authority, name, dao_key, metadata);
+            let parent = *parent.id();
             StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
                 ops.push_op(StorageOps::CreateDao(dao.clone()));
+                ops.push_op(StorageOps::SetParent(name, parent));
                 ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoCreate(dao)));
+                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoSubDaoCreated(parent, name)));
+            });
+            Ok(Some(0).into())
+        }
+
+        /// Frozen sub-DAO can't act, only its parent may act on behalf of it
+        #[pallet::weight((
+            T::DeipDaoWeightInfo::freeze_sub_dao(),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn freeze_sub_dao(
+            origin: OriginFor<T>,
+            sub_dao: DaoId,
+            frozen: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let (parent, _) = load_sub_dao::<T>(&who, &sub_dao)?;
+            let parent = *parent.id();
+            StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
+                ops.push_op(StorageOps::SetFrozen(sub_dao, frozen));
+                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoSubDaoFrozen(
+                    parent, sub_dao, frozen,
+                )));
+            });
+            Ok(Some(0).into())
+        }
+
+        /// Replaces the sub-DAO authority with the parent DAO key immediately,
+        /// regardless of the sub-DAO timelock. Queued authority changes are cancelled,
+        /// roles and spending limits of the sub-DAO are removed.
+        #[pallet::weight((
+            T::DeipDaoWeightInfo::reclaim_sub_dao(),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn reclaim_sub_dao(origin: OriginFor<T>, sub_dao: DaoId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let (parent, dao) = load_sub_dao::<T>(&who, &sub_dao)?;
+            let authority = InputAuthority { signatories: vec![who.clone()], threshold: 0 };
+            let dao = dao
+                .alter_authoriry::<T>(AlterAuthority::ReplaceAuthority {
+                    authority_key: who,
+                    authority,
+                })
+                .map_err::<Error<T>, _>(Into::into)?;
+            let parent = *parent.id();
+            let pending: Vec<ChangeId> =
+                DaoPendingAuthorityChanges::<T>::iter_key_prefix(sub_dao).collect();
+            StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
+                ops.push_op(StorageOps::UpdateDao(dao.clone()));
+                ops.push_op(StorageOps::RemoveDelegations(sub_dao));
+                for change_id in pending {
+                    ops.push_op(StorageOps::RemoveAuthorityChange(sub_dao, change_id));
+                    ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoAuthorityChangeCancelled(
+                        sub_dao, change_id,
+                    )));
+                }
+                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoAlterAuthority(dao)));
+                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoSubDaoReclaimed(
+                    parent, sub_dao,
+                )));
             });
             Ok(Some(0).into())
         }
@@ -482,7 +1178,15 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
             let mut dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
-            dao = dao.alter_authoriry::<T>(authority).map_err::<Error<T>, _>(Into::into)?;
+            // Fail early, queued change is checked against the authority once again when applied
+            let altered = dao
+                .clone()
+                .alter_authoriry::<T>(authority.clone())
+                .map_err::<Error<T>, _>(Into::into)?;
+            if timelock::queue::<T>(*dao.id(), AuthorityChange::AlterAuthority(authority)) {
+                return Ok(Some(0).into())
+            }
+            dao = altered;
             StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
                 ops.push_op(StorageOps::UpdateDao(dao.clone()));
                 ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoAlterAuthority(dao)));
@@ -490,6 +1194,228 @@ pub mod pallet {
             Ok(Some(0).into())
         }
 
+        #[pallet::weight((
+            roles.weight::<T>(),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn alter_roles(
+            origin: OriginFor<T>,
+            roles: AlterRoles<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            let id = *dao.id();
+            let op = match roles.clone() {
+                AlterRoles::SetRole { role, mut calls } => {
+                    calls.sort();
+                    calls.dedup();
+                    ensure!(
+                        calls.len() <= T::MaxRoleCalls::get() as usize,
+                        Error::<T>::TooManyRoleCalls
+                    );
+                    let members = DaoRoles::<T>::get(&id, &role).map_or(0, |r| r.members);
+                    StorageOps::SetRole(id, role, Role { calls, members })
+                },
+                AlterRoles::RemoveRole { role } => {
+                    let r = DaoRoles::<T>::get(&id, &role).ok_or(Error::<T>::RoleNotFound)?;
+                    ensure!(r.members == 0, Error::<T>::RoleInUse);
+                    StorageOps::RemoveRole(id, role)
+                },
+                AlterRoles::Grant { role, member } => {
+                    let mut r = DaoRoles::<T>::get(&id, &role).ok_or(Error::<T>::RoleNotFound)?;
+                    let mut member_roles = DaoMemberRoles::<T>::get(&id, &member);
+                    let pos = member_roles.binary_search(&role).err().ok_or(Error::<T>::Exists)?;
+                    ensure!(
+                        member_roles.len() < T::MaxMemberRoles::get() as usize,
+                        Error::<T>::TooManyMemberRoles
+                    );
+                    member_roles.insert(pos, role);
+                    r.members += 1;
+                    StorageOps::AssignRole(id, role, r, member, member_roles)
+                },
+                AlterRoles::Revoke { role, member } => {
+                    let mut r = DaoRoles::<T>::get(&id, &role).ok_or(Error::<T>::RoleNotFound)?;
+                    let mut member_roles = DaoMemberRoles::<T>::get(&id, &member);
+                    let pos =
+                        member_roles.binary_search(&role).map_err(|_| Error::<T>::NotFound)?;
+                    member_roles.remove(pos);
+                    r.members -= 1;
+                    StorageOps::AssignRole(id, role, r, member, member_roles)
+                },
+            };
+            StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
+                ops.push_op(op);
+                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoAlterRoles(id, roles)));
+            });
+            Ok(Some(0).into())
+        }
+
+        #[pallet::weight((
+            T::DeipDaoWeightInfo::set_spending_limit(T::MaxSpendingLimits::get() as u32),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn set_spending_limit(
+            origin: OriginFor<T>,
+            asset: AssetIdOf<T>,
+            limit: Option<SpendingLimitOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            let id = *dao.id();
+            if let Some(ref limit) = limit {
+                ensure!(!limit.period.is_zero(), Error::<T>::SpendingLimitWrongTerms);
+                ensure!(
+                    DaoSpendingLimits::<T>::contains_key(&id, &asset) ||
+                        DaoSpendingLimits::<T>::iter_prefix_values(&id).count() <
+                            T::MaxSpendingLimits::get() as usize,
+                    Error::<T>::TooManySpendingLimits
+                );
+            }
+            StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
+                ops.push_op(StorageOps::SetSpendingLimit(id, asset.clone(), limit.clone()));
+                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoSpendingLimitSet(
+                    id, asset, limit,
+                )));
+            });
+            Ok(Some(0).into())
+        }
+
+        #[pallet::weight((
+            T::DeipDaoWeightInfo::set_timelock(),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn set_timelock(
+            origin: OriginFor<T>,
+            delay: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            let id = *dao.id();
+            ensure!(delay.map_or(true, |d| !d.is_zero()), Error::<T>::TimelockWrongTerms);
+            // Prolongation takes effect immediately, anything else waits for the current delay
+            let prolongs = match (DaoTimelocks::<T>::get(&id), delay) {
+                (None, _) => true,
+                (Some(current), Some(delay)) => delay >= current,
+                (Some(_), None) => false,
+            };
+            if !prolongs && timelock::queue::<T>(id, AuthorityChange::SetTimelock(delay)) {
+                return Ok(Some(0).into())
+            }
+            StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
+                ops.push_op(StorageOps::SetTimelock(id, delay));
+                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoTimelockSet(id, delay)));
+            });
+            Ok(Some(0).into())
+        }
+
+        #[pallet::weight((
+            T::DeipDaoWeightInfo::cancel_authority_change(),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn cancel_authority_change(
+            origin: OriginFor<T>,
+            change_id: ChangeId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            let id = *dao.id();
+            ensure!(
+                DaoPendingAuthorityChanges::<T>::contains_key(&id, change_id),
+                Error::<T>::NotFound
+            );
+            StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
+                ops.push_op(StorageOps::RemoveAuthorityChange(id, change_id));
+                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoAuthorityChangeCancelled(
+                    id, change_id,
+                )));
+            });
+            Ok(Some(0).into())
+        }
+
+        /// Anybody may apply the queued change once its delay is over
+        #[pallet::weight((
+            T::DeipDaoWeightInfo::apply_authority_change(T::MaxSignatories::get() as u32),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn apply_authority_change(
+            origin: OriginFor<T>,
+            name: DaoId,
+            change_id: ChangeId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            ensure!(!DaoFrozen::<T>::get(&name), Error::<T>::Frozen);
+            let pending = DaoPendingAuthorityChanges::<T>::get(&name, change_id)
+                .ok_or(Error::<T>::NotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= pending.execute_at,
+                Error::<T>::AuthorityChangeIsLocked
+            );
+            let op = match pending.change {
+                AuthorityChange::AlterAuthority(authority) => {
+                    let dao = DaoRepository::<T>::get(&name).ok_or(Error::<T>::NotFound)?;
+                    let dao =
+                        dao.alter_authoriry::<T>(authority).map_err::<Error<T>, _>(Into::into)?;
+                    (StorageOps::UpdateDao(dao.clone()), Event::<T>::DaoAlterAuthority(dao))
+                },
+                AuthorityChange::SetTimelock(delay) =>
+                    (StorageOps::SetTimelock(name, delay), Event::<T>::DaoTimelockSet(name, delay)),
+            };
+            StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
+                let (op, event) = op;
+                ops.push_op(StorageOps::RemoveAuthorityChange(name, change_id));
+                ops.push_op(op);
+                ops.push_op(StorageOps::DepositEvent(event));
+                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoAuthorityChangeApplied(
+                    name, change_id,
+                )));
+            });
+            Ok(Some(0).into())
+        }
+
+        /// Distributes the listed assets of the DAO to the recipients and closes the DAO.
+        /// F-NFT fractions aren't indexed by the holder, so they are listed explicitly.
+        #[pallet::weight((
+            T::DeipDaoWeightInfo::dissolve(
+                distribution.recipients::<T>(),
+                assets.len() as u32,
+            ),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        #[transactional]
+        pub fn dissolve(
+            origin: OriginFor<T>,
+            distribution: Distribution<T::AccountId>,
+            assets: Vec<AssetIdOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let dao = load_dao::<T>(LoadBy::DaoKey { dao_key: &who })?;
+            // Otherwise dissolution would bypass the delay of the authority changes
+            ensure!(!DaoTimelocks::<T>::contains_key(dao.id()), Error::<T>::DissolutionIsLocked);
+            ensure!(
+                DaoChildren::<T>::iter_prefix(dao.id()).next().is_none(),
+                Error::<T>::HasSubDaos
+            );
+            ensure!(
+                assets.len() <= T::MaxDistributedAssets::get() as usize,
+                Error::<T>::TooManyDistributedAssets
+            );
+            let shares = dissolution::shares::<T>(&dao, distribution)?;
+            dissolution::distribute::<T>(&dao, &assets, &shares)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            StorageOpsTransaction::<StorageOps<T>>::new().commit(move |ops| {
+                let id = *dao.id();
+                ops.push_op(StorageOps::DissolveDao(dao, now));
+                ops.push_op(StorageOps::DepositEvent(Event::<T>::DaoDissolved(id)));
+            });
+            Ok(Some(0).into())
+        }
+
         #[pallet::weight((
             T::DeipDaoWeightInfo::update_dao(),
             DispatchClass::Normal,
@@ -511,19 +1437,39 @@ pub mod pallet {
         }
 
         #[pallet::weight((
-            T::DeipDaoWeightInfo::on_behalf()
-                + call.get_dispatch_info().weight,
+            T::DeipDaoWeightInfo::on_behalf(
+                T::MaxMemberRoles::get() as u32,
+                T::MaxSpendingLimits::get() as u32,
+            ) + call.get_dispatch_info().weight,
             DispatchClass::Normal,
             Pays::Yes
         ))]
+        #[transactional]
         pub fn on_behalf(
             origin: OriginFor<T>,
             name: DaoId,
             call: Box<<T as Config>::Call>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            let dao = load_dao::<T>(LoadBy::DaoId { id: &name, who: KeyType::Members(&who) })?;
-            call.dispatch(RawOrigin::Signed(dao.dao_key().clone()).into())
+            let dao = DaoRepository::<T>::get(&name).ok_or(Error::<T>::NotFound)?;
+            let dao_origin = RawOrigin::Signed(dao.dao_key().clone()).into();
+            // The parent DAO overrides its sub-DAO, even a frozen one
+            if is_parent_key::<T>(&name, &who) {
+                return call.dispatch(dao_origin)
+            }
+            ensure!(!DaoFrozen::<T>::get(&name), Error::<T>::Frozen);
+            // The full authority isn't limited at all
+            if who == dao.authority().full_authority_key() {
+                return call.dispatch(dao_origin)
+            }
+            // The authority may dispatch any call, other accounts are limited by their roles
+            if !MatchKey::<T>::match_key(&KeyType::members(&who), &dao) {
+                ensure_permitted::<T>(&name, &who, &call)?;
+            }
+            let guard = SpendingGuard::<T>::new(&dao);
+            let post_info = call.dispatch(dao_origin)?;
+            guard.check(&dao)?;
+            Ok(post_info)
         }
     }
 
@@ -539,10 +1485,103 @@ pub mod pallet {
     pub(super) type DaoLookup<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, DaoId, OptionQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn get_role)]
+    pub(super) type DaoRoles<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, DaoId, Blake2_128Concat, RoleId, Role, OptionQuery>;
+
+    /// Sorted roles of the account within the DAO
+    #[pallet::storage]
+    #[pallet::getter(fn member_roles)]
+    pub(super) type DaoMemberRoles<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        DaoId,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<RoleId>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn spending_limit)]
+    pub(super) type DaoSpendingLimits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        DaoId,
+        Blake2_128Concat,
+        AssetIdOf<T>,
+        SpendingLimitOf<T>,
+        OptionQuery,
+    >;
+
+    /// Delay of the DAO authority changes
+    #[pallet::storage]
+    #[pallet::getter(fn timelock)]
+    pub(super) type DaoTimelocks<T: Config> =
+        StorageMap<_, Blake2_128Concat, DaoId, T::BlockNumber, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_authority_change)]
+    pub(super) type DaoPendingAuthorityChanges<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        DaoId,
+        Twox64Concat,
+        ChangeId,
+        PendingChangeOf<T>,
+        OptionQuery,
+    >;
+
+    /// Id of the next authority change queued by the DAO
+    #[pallet::storage]
+    pub(super) type DaoAuthorityChangeNonce<T: Config> =
+        StorageMap<_, Blake2_128Concat, DaoId, ChangeId, ValueQuery>;
+
+    /// Block the DAO was dissolved at. Dissolved ids can't be reused
+    #[pallet::storage]
+    #[pallet::getter(fn dissolved_at)]
+    pub(super) type DaoTombstones<T: Config> =
+        StorageMap<_, Blake2_128Concat, DaoId, T::BlockNumber, OptionQuery>;
+
+    /// Parent of the sub-DAO
+    #[pallet::storage]
+    #[pallet::getter(fn parent_dao)]
+    pub(super) type DaoParents<T: Config> =
+        StorageMap<_, Blake2_128Concat, DaoId, DaoId, OptionQuery>;
+
+    /// Sub-DAOs of the parent DAO
+    #[pallet::storage]
+    pub(super) type DaoChildren<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, DaoId, Blake2_128Concat, DaoId, (), OptionQuery>;
+
+    /// Sub-DAOs frozen by their parents
+    #[pallet::storage]
+    #[pallet::getter(fn is_frozen)]
+    pub(super) type DaoFrozen<T: Config> = StorageMap<_, Blake2_128Concat, DaoId, bool, ValueQuery>;
+
+    /// Spending of the asset within the current period
+    #[pallet::storage]
+    #[pallet::getter(fn spending)]
+    pub(super) type DaoSpendings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        DaoId,
+        Blake2_128Concat,
+        AssetIdOf<T>,
+        SpendingOf<T>,
+        OptionQuery,
+    >;
+
     use storage_ops::*;
     /// Module contains abstractions over pallet storage operations
     pub mod storage_ops {
-        use super::{Config, DaoLookup, DaoOf, DaoRepository, Event, Pallet};
+        use super::{
+            AssetIdOf, ChangeId, Config, DaoAuthorityChangeNonce, DaoChildren, DaoFrozen, DaoId,
+            DaoLookup, DaoMemberRoles, DaoOf, DaoParents, DaoPendingAuthorityChanges,
+            DaoRepository, DaoRoles, DaoSpendingLimits, DaoSpendings, DaoTimelocks, DaoTombstones,
+            Event, Pallet, PendingChangeOf, Role, RoleId, SpendingLimitOf, SpendingOf,
+        };
         use deip_storage_ops::StorageOp;
         use sp_std::prelude::*;
 
@@ -554,6 +1593,30 @@ pub mod pallet {
             CreateDao(DaoOf<T>),
             /// Update DAO
             UpdateDao(DaoOf<T>),
+            /// Create or update DAO role
+            SetRole(DaoId, RoleId, Role),
+            /// Remove DAO role
+            RemoveRole(DaoId, RoleId),
+            /// Update DAO role and roles of the account
+            AssignRole(DaoId, RoleId, Role, T::AccountId, Vec<RoleId>),
+            /// Set or remove spending limit of the DAO asset
+            SetSpendingLimit(DaoId, AssetIdOf<T>, Option<SpendingLimitOf<T>>),
+            /// Update spending of the DAO asset
+            UpdateSpending(DaoId, AssetIdOf<T>, SpendingOf<T>),
+            /// Set or remove timelock of the DAO authority changes
+            SetTimelock(DaoId, Option<T::BlockNumber>),
+            /// Queue authority change of the DAO
+            QueueAuthorityChange(DaoId, ChangeId, PendingChangeOf<T>),
+            /// Remove queued authority change of the DAO
+            RemoveAuthorityChange(DaoId, ChangeId),
+            /// Remove roles, role assignments and spending limits of the DAO
+            RemoveDelegations(DaoId),
+            /// Remove DAO with its settings and tombstone its id
+            DissolveDao(DaoOf<T>, T::BlockNumber),
+            /// Set parent of the sub-DAO
+            SetParent(DaoId, DaoId),
+            /// Freeze or unfreeze the sub-DAO
+            SetFrozen(DaoId, bool),
         }
         impl<T: Config> StorageOp for StorageOps<T> {
             fn exec(self) {
@@ -566,6 +1629,75 @@ pub mod pallet {
                     Self::UpdateDao(dao) => {
                         DaoRepository::<T>::insert(*dao.id(), dao);
                     },
+                    Self::SetRole(dao, id, role) => {
+                        DaoRoles::<T>::insert(dao, id, role);
+                    },
+                    Self::RemoveRole(dao, id) => {
+                        DaoRoles::<T>::remove(dao, id);
+                    },
+                    Self::AssignRole(dao, id, role, member, member_roles) => {
+                        DaoRoles::<T>::insert(dao, id, role);
+                        if member_roles.is_empty() {
+                            DaoMemberRoles::<T>::remove(dao, member);
+                        } else {
+                            DaoMemberRoles::<T>::insert(dao, member, member_roles);
+                        }
+                    },
+                    Self::SetSpendingLimit(dao, asset, Some(limit)) => {
+                        DaoSpendingLimits::<T>::insert(dao, asset, limit);
+                    },
+                    Self::SetSpendingLimit(dao, asset, None) => {
+                        DaoSpendingLimits::<T>::remove(dao, &asset);
+                        DaoSpendings::<T>::remove(dao, asset);
+                    },
+                    Self::UpdateSpending(dao, asset, spending) => {
+                        DaoSpendings::<T>::insert(dao, asset, spending);
+                    },
+                    Self::SetTimelock(dao, Some(delay)) => {
+                        DaoTimelocks::<T>::insert(dao, delay);
+                    },
+                    Self::SetTimelock(dao, None) => {
+                        DaoTimelocks::<T>::remove(dao);
+                    },
+                    Self::QueueAuthorityChange(dao, id, change) => {
+                        DaoPendingAuthorityChanges::<T>::insert(dao, id, change);
+                        DaoAuthorityChangeNonce::<T>::insert(dao, id.wrapping_add(1));
+                    },
+                    Self::RemoveAuthorityChange(dao, id) => {
+                        DaoPendingAuthorityChanges::<T>::remove(dao, id);
+                    },
+                    Self::RemoveDelegations(dao) => {
+                        DaoRoles::<T>::remove_prefix(dao, None);
+                        DaoMemberRoles::<T>::remove_prefix(dao, None);
+                        DaoSpendingLimits::<T>::remove_prefix(dao, None);
+                        DaoSpendings::<T>::remove_prefix(dao, None);
+                    },
+                    Self::DissolveDao(dao, at) => {
+                        let id = *dao.id();
+                        if let Some(parent) = DaoParents::<T>::take(id) {
+                            DaoChildren::<T>::remove(parent, id);
+                        }
+                        DaoFrozen::<T>::remove(id);
+                        DaoLookup::<T>::remove(dao.dao_key());
+                        DaoRepository::<T>::remove(id);
+                        DaoRoles::<T>::remove_prefix(id, None);
+                        DaoMemberRoles::<T>::remove_prefix(id, None);
+                        DaoSpendingLimits::<T>::remove_prefix(id, None);
+                        DaoSpendings::<T>::remove_prefix(id, None);
+                        DaoPendingAuthorityChanges::<T>::remove_prefix(id, None);
+                        DaoAuthorityChangeNonce::<T>::remove(id);
+                        DaoTombstones::<T>::insert(id, at);
+                    },
+                    Self::SetParent(dao, parent) => {
+                        DaoParents::<T>::insert(dao, parent);
+                        DaoChildren::<T>::insert(parent, dao, ());
+                    },
+                    Self::SetFrozen(dao, true) => {
+                        DaoFrozen::<T>::insert(dao, true);
+                    },
+                    Self::SetFrozen(dao, false) => {
+                        DaoFrozen::<T>::remove(dao);
+                    },
                 }
             }
         }
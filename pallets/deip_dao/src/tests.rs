@@ -17,7 +17,9 @@ frame_support::construct_runtime!(
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
         DeipDao: pallet_deip_dao::{Pallet, Call, Storage, Event<T>, Config},
+        DepositMock: deposit_mock::{Pallet, Call},
     }
 );
 
@@ -25,6 +27,12 @@ frame_support::parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub BlockWeights: frame_system::limits::BlockWeights =
         frame_system::limits::BlockWeights::simple_max(1024);
+    pub const MaxSignatories: u16 = 10;
+    pub const MaxRoleCalls: u16 = 4;
+    pub const MaxMemberRoles: u16 = 2;
+    pub const MaxSpendingLimits: u16 = 2;
+    pub const MaxDistributedAssets: u16 = 2;
+    pub const ExistentialDeposit: u64 = 1;
 }
 
 impl frame_system::Config for TestRuntime {
@@ -45,7 +53,7 @@ impl frame_system::Config for TestRuntime {
     type DbWeight = ();
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -58,15 +66,106 @@ impl crate::Config for TestRuntime {
     type Call = Call;
     type DaoId = ();
     type DeipDaoWeightInfo = weights::Weights<Self>;
-    type MaxSignatories = ();
+    type MaxSignatories = MaxSignatories;
+    type MaxRoleCalls = MaxRoleCalls;
+    type MaxMemberRoles = MaxMemberRoles;
+    type Assets = DaoAssetsMock;
+    type MaxSpendingLimits = MaxSpendingLimits;
+    type MaxDistributedAssets = MaxDistributedAssets;
+}
+
+impl pallet_balances::Config for TestRuntime {
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type Event = Event;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+/// Reserves funds of the caller like the pallets taking storage deposits
+#[frame_support::pallet]
+pub mod deposit_mock {
+    use frame_support::{pallet_prelude::*, traits::ReservableCurrency};
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_balances::Config {}
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::weight(0)]
+        pub fn reserve(origin: OriginFor<T>, amount: T::Balance) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            pallet_balances::Pallet::<T>::reserve(&who, amount)
+        }
+    }
+}
+
+impl deposit_mock::Config for TestRuntime {}
+
+/// Native currency only instead of the F-NFT pallet
+pub struct DaoAssetsMock;
+
+impl DaoAssetsT<u64> for DaoAssetsMock {
+    type AssetId = DaoAsset<sp_core::H256>;
+    type Balance = u64;
+
+    fn balance(asset: &Self::AssetId, who: &u64) -> u64 {
+        match asset {
+            DaoAsset::Native => Balances::total_balance(who),
+            DaoAsset::Fraction(_) => 0,
+        }
+    }
+
+    fn transferable(asset: &Self::AssetId, who: &u64) -> u64 {
+        match asset {
+            DaoAsset::Native => Balances::free_balance(who),
+            DaoAsset::Fraction(_) => 0,
+        }
+    }
+
+    fn transfer(asset: &Self::AssetId, from: &u64, to: &u64, amount: u64) -> DispatchResult {
+        match asset {
+            DaoAsset::Native => <Balances as Currency<u64>>::transfer(
+                from,
+                to,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            ),
+            DaoAsset::Fraction(_) => Err(DispatchError::Other("no fractions")),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn benchmark_asset(_index: u32) -> Self::AssetId {
+        DaoAsset::Native
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn benchmark_deposit(_asset: &Self::AssetId, who: &u64, amount: u64) {
+        drop(Balances::deposit_creating(who, amount));
+    }
 }
 
 pub struct ExtBuilder;
 
 impl ExtBuilder {
     pub fn build() -> sp_io::TestExternalities {
-        let storage =
+        let mut storage =
             frame_system::GenesisConfig::default().build_storage::<TestRuntime>().unwrap();
+        pallet_balances::GenesisConfig::<TestRuntime> { balances: vec![(1, 100), (2, 100)] }
+            .assimilate_storage(&mut storage)
+            .unwrap();
         sp_io::TestExternalities::from(storage)
     }
 }
@@ -75,9 +174,13 @@ fn with_test_ext<R>(t: impl FnOnce() -> R) -> R {
     ExtBuilder::build().execute_with(t)
 }
 
-use crate::dao::*;
-use frame_support::{assert_noop, assert_ok, traits::Everything};
+use crate::{dao::*, role::*, spending::*};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Currency, Everything, ExistenceRequirement, ReservableCurrency},
+};
 use frame_system::RawOrigin;
+use sp_runtime::{DispatchError, DispatchResult, Perbill};
 use sp_std::str::FromStr;
 
 fn last_event() -> Event {
@@ -228,6 +331,394 @@ fn dao_on_behalf() {
     })
 }
 
+#[test]
+fn dao_on_behalf_by_role() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let who = 1;
+        let treasurer = 2;
+        let other = 3;
+        let id = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
+        DeipDao::create(Origin::signed(who), id, plain_key_source(who), None).expect("create OK");
+        let dao_key = DeipDao::dao_key(&id);
+        let role = RoleId::from_slice("treasurer\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
+        let remark = Call::System(frame_system::Call::remark { remark: vec![] });
+        let update_dao = Call::DeipDao(RawCall::update_dao { new_metadata: None });
+
+        assert_noop!(
+            DeipDao::alter_roles(
+                Origin::signed(dao_key),
+                AlterRoles::Grant { role, member: treasurer }
+            ),
+            Error::<TestRuntime>::RoleNotFound,
+        );
+        // Roles are managed on behalf of the DAO
+        assert_ok!(DeipDao::on_behalf(
+            Origin::signed(who),
+            id,
+            Box::new(Call::DeipDao(RawCall::alter_roles {
+                roles: AlterRoles::SetRole { role, calls: vec![call_index(&remark).unwrap()] }
+            }))
+        ));
+        assert_ok!(DeipDao::alter_roles(
+            Origin::signed(dao_key),
+            AlterRoles::Grant { role, member: treasurer }
+        ));
+        expect_event(RawEvent::<TestRuntime>::DaoAlterRoles(
+            id,
+            AlterRoles::Grant { role, member: treasurer },
+        ));
+        assert_eq!(DeipDao::member_roles(id, treasurer), vec![role]);
+
+        assert_ok!(DeipDao::on_behalf(Origin::signed(treasurer), id, Box::new(remark.clone())));
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(treasurer), id, Box::new(update_dao.clone())),
+            Error::<TestRuntime>::CallNotPermitted,
+        );
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(other), id, Box::new(remark.clone())),
+            Error::<TestRuntime>::Forbidden,
+        );
+        // The authority isn't limited by roles
+        assert_ok!(DeipDao::on_behalf(Origin::signed(who), id, Box::new(update_dao)));
+
+        assert_noop!(
+            DeipDao::alter_roles(Origin::signed(dao_key), AlterRoles::RemoveRole { role }),
+            Error::<TestRuntime>::RoleInUse,
+        );
+        assert_ok!(DeipDao::alter_roles(
+            Origin::signed(dao_key),
+            AlterRoles::Revoke { role, member: treasurer }
+        ));
+        assert_ok!(DeipDao::alter_roles(Origin::signed(dao_key), AlterRoles::RemoveRole { role }));
+        assert!(DeipDao::get_role(id, role).is_none());
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(treasurer), id, Box::new(remark)),
+            Error::<TestRuntime>::Forbidden,
+        );
+    })
+}
+
+#[test]
+fn dao_spending_limit() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let authority = Authority { signatories: vec![1, 2], threshold: 1 };
+        let (who, full) = (authority.authority_key(), authority.full_authority_key());
+        let id = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
+        assert_ok!(DeipDao::create(Origin::signed(who), id, authority.into(), None));
+        let dao_key = DeipDao::dao_key(&id);
+        assert_ok!(Balances::transfer(Origin::signed(1), dao_key, 90));
+        let transfer = |value| {
+            Box::new(Call::Balances(pallet_balances::Call::transfer { dest: 3, value }))
+        };
+        let limit = SpendingLimit { amount: 50, period: 10 };
+        let set_limit = |limit| {
+            Box::new(Call::DeipDao(RawCall::set_spending_limit { asset: DaoAsset::Native, limit }))
+        };
+
+        assert_ok!(DeipDao::on_behalf(Origin::signed(who), id, set_limit(Some(limit.clone()))));
+        // Limits can't be loosened below the full authority
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(who), id, set_limit(None)),
+            Error::<TestRuntime>::EscalationRequired,
+        );
+        assert_noop!(
+            DeipDao::on_behalf(
+                Origin::signed(who),
+                id,
+                Box::new(Call::DeipDao(RawCall::alter_authority {
+                    authority: AlterAuthority::RemoveMember { member: 2, preserve_threshold: true }
+                }))
+            ),
+            Error::<TestRuntime>::EscalationRequired,
+        );
+
+        assert_ok!(DeipDao::on_behalf(Origin::signed(who), id, transfer(30)));
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(who), id, transfer(30)),
+            Error::<TestRuntime>::SpendingLimitExceeded,
+        );
+        assert_ok!(DeipDao::on_behalf(Origin::signed(full), id, transfer(30)));
+        assert_eq!(Balances::free_balance(3), 60);
+
+        System::set_block_number(11);
+        assert_ok!(DeipDao::on_behalf(Origin::signed(who), id, transfer(20)));
+        assert_eq!(DeipDao::spending(id, DaoAsset::Native).map(|s| s.amount), Some(20));
+
+        assert_ok!(DeipDao::on_behalf(Origin::signed(full), id, set_limit(None)));
+        assert!(DeipDao::spending(id, DaoAsset::Native).is_none());
+    })
+}
+
+#[test]
+fn dao_spending_limit_counts_reserved_funds() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let who = 1;
+        let id = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
+        DeipDao::create(Origin::signed(who), id, plain_key_source(who), None).expect("create OK");
+        let dao_key = DeipDao::dao_key(&id);
+        assert_ok!(Balances::transfer(Origin::signed(2), dao_key, 90));
+        assert_ok!(Balances::reserve(&dao_key, 20));
+        assert_ok!(DeipDao::set_spending_limit(
+            Origin::signed(dao_key),
+            DaoAsset::Native,
+            Some(SpendingLimit { amount: 50, period: 10 })
+        ));
+        let reserve = |amount| Box::new(Call::DepositMock(deposit_mock::Call::reserve { amount }));
+        let transfer = |value| {
+            Box::new(Call::Balances(pallet_balances::Call::transfer { dest: 3, value }))
+        };
+
+        assert_ok!(DeipDao::on_behalf(Origin::signed(who), id, transfer(10)));
+        assert_eq!(DeipDao::spending(id, DaoAsset::Native).map(|s| s.amount), Some(10));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(who), id, reserve(30)));
+        assert_eq!(DeipDao::spending(id, DaoAsset::Native).map(|s| s.amount), Some(40));
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(who), id, transfer(20)),
+            Error::<TestRuntime>::SpendingLimitExceeded,
+        );
+        assert_ok!(DeipDao::on_behalf(Origin::signed(who), id, transfer(10)));
+        assert_eq!(Balances::reserved_balance(dao_key), 50);
+        assert_eq!(Balances::free_balance(dao_key), 20);
+    })
+}
+
+#[test]
+fn dao_timelocked_authority_change() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let who = 1;
+        let id = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
+        DeipDao::create(Origin::signed(who), id, plain_key_source(who), None).expect("create OK");
+        let dao_key = DeipDao::dao_key(&id);
+        let add_member = AlterAuthority::AddMember { member: 2, preserve_threshold: false };
+
+        assert_noop!(
+            DeipDao::set_timelock(Origin::signed(dao_key), Some(0)),
+            Error::<TestRuntime>::TimelockWrongTerms,
+        );
+        assert_ok!(DeipDao::on_behalf(
+            Origin::signed(who),
+            id,
+            Box::new(Call::DeipDao(RawCall::set_timelock { delay: Some(10) }))
+        ));
+        expect_event(RawEvent::<TestRuntime>::DaoTimelockSet(id, Some(10)));
+
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), add_member.clone()));
+        let queued = DeipDao::pending_authority_change(id, 0).expect("change queued");
+        assert_eq!(queued.execute_at(), &11);
+        expect_event(RawEvent::<TestRuntime>::DaoAuthorityChangeQueued(id, 0, queued));
+        assert_noop!(
+            DeipDao::apply_authority_change(Origin::signed(3), id, 0),
+            Error::<TestRuntime>::AuthorityChangeIsLocked,
+        );
+        assert_ok!(DeipDao::cancel_authority_change(Origin::signed(dao_key), 0));
+        assert!(DeipDao::pending_authority_change(id, 0).is_none());
+
+        // Removal of the timelock waits for the timelock as well
+        assert_ok!(DeipDao::set_timelock(Origin::signed(dao_key), None));
+        assert_eq!(DeipDao::timelock(id), Some(10));
+        assert_ok!(DeipDao::alter_authority(Origin::signed(dao_key), add_member));
+
+        System::set_block_number(11);
+        assert_ok!(DeipDao::apply_authority_change(Origin::signed(3), id, 2));
+        expect_event(RawEvent::<TestRuntime>::DaoAuthorityChangeApplied(id, 2));
+        assert_eq!(DeipDao::get_dao(id).unwrap().authority().signatories, vec![1, 2]);
+        assert_ok!(DeipDao::apply_authority_change(Origin::signed(3), id, 1));
+        assert!(DeipDao::timelock(id).is_none());
+    })
+}
+
+#[test]
+fn dao_dissolve() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let who = 1;
+        let id = DaoId::from_slice("test_dao\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
+        DeipDao::create(Origin::signed(who), id, plain_key_source(who), None).expect("create OK");
+        let dao_key = DeipDao::dao_key(&id);
+        assert_ok!(Balances::transfer(Origin::signed(2), dao_key, 91));
+        let recipients = vec![(3, Perbill::from_percent(50)), (4, Perbill::from_percent(40))];
+
+        assert_noop!(
+            DeipDao::dissolve(
+                Origin::signed(dao_key),
+                Distribution::Recipients(recipients.clone()),
+                vec![DaoAsset::Native]
+            ),
+            Error::<TestRuntime>::DissolutionWrongTerms,
+        );
+        assert_ok!(DeipDao::set_timelock(Origin::signed(dao_key), Some(10)));
+        assert_noop!(
+            DeipDao::dissolve(Origin::signed(dao_key), Distribution::Signatories, vec![]),
+            Error::<TestRuntime>::DissolutionIsLocked,
+        );
+        assert_ok!(DeipDao::set_timelock(Origin::signed(dao_key), None));
+        System::set_block_number(11);
+        assert_ok!(DeipDao::apply_authority_change(Origin::signed(who), id, 0));
+
+        let recipients = vec![(3, Perbill::from_percent(60)), (4, Perbill::from_percent(40))];
+        assert_ok!(DeipDao::on_behalf(
+            Origin::signed(who),
+            id,
+            Box::new(Call::DeipDao(RawCall::dissolve {
+                distribution: Distribution::Recipients(recipients),
+                assets: vec![DaoAsset::Native],
+            }))
+        ));
+        expect_event(RawEvent::<TestRuntime>::DaoDissolved(id));
+        // The rounding remainder goes to the first recipient
+        assert_eq!(Balances::free_balance(3), 55);
+        assert_eq!(Balances::free_balance(4), 36);
+        assert_eq!(Balances::total_balance(&dao_key), 0);
+        assert!(DeipDao::get_dao(id).is_none());
+        assert!(DeipDao::lookup_dao(dao_key).is_none());
+        assert_eq!(DeipDao::dissolved_at(id), Some(11));
+
+        assert_noop!(
+            DeipDao::create(Origin::signed(who), id, plain_key_source(who), None),
+            Error::<TestRuntime>::Dissolved,
+        );
+    })
+}
+
+#[test]
+fn dao_sub_dao() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (who, member) = (1, 2);
+        let parent = DaoId::from_slice("parent_dao\0\0\0\0\0\0\0\0\0\0".as_bytes());
+        let id = DaoId::from_slice("sub_dao\0\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
+        DeipDao::create(Origin::signed(who), parent, plain_key_source(who), None)
+            .expect("create OK");
+        let parent_key = DeipDao::dao_key(&parent);
+        let remark = Box::new(Call::System(frame_system::Call::remark { remark: vec![] }));
+        let on_behalf_sub_dao = |call| {
+            Box::new(Call::DeipDao(RawCall::on_behalf { name: id, call: Box::new(call) }))
+        };
+
+        assert_ok!(DeipDao::on_behalf(
+            Origin::signed(who),
+            parent,
+            Box::new(Call::DeipDao(RawCall::create_sub_dao {
+                name: id,
+                authority_key: member,
+                authority: plain_key_source(member),
+                metadata: None,
+            }))
+        ));
+        expect_event(RawEvent::<TestRuntime>::DaoSubDaoCreated(parent, id));
+        assert_eq!(DeipDao::parent_dao(id), Some(parent));
+        assert_eq!(DeipDao::rpc_get_sub_daos(parent), vec![id]);
+        assert_noop!(
+            DeipDao::freeze_sub_dao(Origin::signed(DeipDao::dao_key(&id)), parent, true),
+            Error::<TestRuntime>::NotSubDao,
+        );
+
+        // The parent overrides the sub-DAO
+        assert_ok!(DeipDao::on_behalf(
+            Origin::signed(who),
+            parent,
+            on_behalf_sub_dao(Call::DeipDao(RawCall::update_dao {
+                new_metadata: Some(Default::default())
+            }))
+        ));
+        assert_eq!(DeipDao::get_dao(id).unwrap().metadata(), &Some(Default::default()));
+
+        assert_ok!(DeipDao::freeze_sub_dao(Origin::signed(parent_key), id, true));
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(member), id, remark.clone()),
+            Error::<TestRuntime>::Frozen,
+        );
+        assert_ok!(DeipDao::on_behalf(Origin::signed(parent_key), id, remark.clone()));
+        assert_ok!(DeipDao::freeze_sub_dao(Origin::signed(parent_key), id, false));
+        assert_ok!(DeipDao::on_behalf(Origin::signed(member), id, remark.clone()));
+
+        assert_ok!(DeipDao::reclaim_sub_dao(Origin::signed(parent_key), id));
+        expect_event(RawEvent::<TestRuntime>::DaoSubDaoReclaimed(parent, id));
+        assert_eq!(DeipDao::get_dao(id).unwrap().authority_key(), &parent_key);
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(member), id, remark),
+            Error::<TestRuntime>::Forbidden,
+        );
+
+        let dissolve = || {
+            Call::DeipDao(RawCall::dissolve {
+                distribution: Distribution::Signatories,
+                assets: vec![],
+            })
+        };
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(who), parent, Box::new(dissolve())),
+            Error::<TestRuntime>::HasSubDaos,
+        );
+        assert_ok!(DeipDao::on_behalf(Origin::signed(who), parent, on_behalf_sub_dao(dissolve())));
+        assert!(DeipDao::parent_dao(id).is_none());
+        assert!(DeipDao::rpc_get_sub_daos(parent).is_empty());
+        assert_ok!(DeipDao::on_behalf(Origin::signed(who), parent, Box::new(dissolve())));
+    })
+}
+
+#[test]
+fn dao_reclaim_sub_dao_drops_delegations() {
+    with_test_ext(|| {
+        System::set_block_number(1);
+        let (who, member, holder) = (1, 2, 3);
+        let parent = DaoId::from_slice("parent_dao\0\0\0\0\0\0\0\0\0\0".as_bytes());
+        let id = DaoId::from_slice("sub_dao\0\0\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
+        DeipDao::create(Origin::signed(who), parent, plain_key_source(who), None)
+            .expect("create OK");
+        let parent_key = DeipDao::dao_key(&parent);
+        assert_ok!(DeipDao::create_sub_dao(
+            Origin::signed(parent_key),
+            id,
+            member,
+            plain_key_source(member),
+            None
+        ));
+        let dao_key = DeipDao::dao_key(&id);
+        let role = RoleId::from_slice("treasurer\0\0\0\0\0\0\0\0\0\0\0".as_bytes());
+        let remark = Call::System(frame_system::Call::remark { remark: vec![] });
+        let limit = SpendingLimit { amount: 50, period: 10 };
+
+        assert_ok!(DeipDao::alter_roles(
+            Origin::signed(dao_key),
+            AlterRoles::SetRole { role, calls: vec![call_index(&remark).unwrap()] }
+        ));
+        assert_ok!(DeipDao::alter_roles(
+            Origin::signed(dao_key),
+            AlterRoles::Grant { role, member: holder }
+        ));
+        assert_ok!(DeipDao::set_spending_limit(
+            Origin::signed(dao_key),
+            DaoAsset::Native,
+            Some(limit)
+        ));
+        assert_ok!(DeipDao::set_timelock(Origin::signed(dao_key), Some(10)));
+        assert_ok!(DeipDao::alter_authority(
+            Origin::signed(dao_key),
+            AlterAuthority::AddMember { member: holder, preserve_threshold: false }
+        ));
+        assert!(DeipDao::pending_authority_change(id, 0).is_some());
+        assert_ok!(DeipDao::on_behalf(Origin::signed(holder), id, Box::new(remark.clone())));
+
+        assert_ok!(DeipDao::reclaim_sub_dao(Origin::signed(parent_key), id));
+        assert!(System::events().iter().any(|r| {
+            r.event == RawEvent::<TestRuntime>::DaoAuthorityChangeCancelled(id, 0).into()
+        }));
+        assert!(DeipDao::pending_authority_change(id, 0).is_none());
+        assert!(DeipDao::get_role(id, role).is_none());
+        assert!(DeipDao::member_roles(id, holder).is_empty());
+        assert!(DeipDao::spending_limit(id, DaoAsset::Native).is_none());
+        assert_noop!(
+            DeipDao::on_behalf(Origin::signed(holder), id, Box::new(remark)),
+            Error::<TestRuntime>::Forbidden,
+        );
+    })
+}
+
 // #[test]
 // fn dao_on_behalf_not_found() {
 //     with_test_ext(|| {
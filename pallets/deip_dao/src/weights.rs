@@ -26,6 +26,11 @@
 // --output
 // weights.rs
 
+// PROVISIONAL: `create_sub_dao`, `freeze_sub_dao`, `reclaim_sub_dao`, `alter_roles_*`,
+// `set_spending_limit`, `set_timelock`, `cancel_authority_change`, `apply_authority_change`,
+// `dissolve` and the components of `on_behalf` are estimated by hand, not benchmarked.
+// Regenerate the file with the command above to replace them.
+
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -35,13 +40,25 @@ use sp_std::marker::PhantomData;
 
 pub trait WeightInfo {
     fn create(m: u32) -> Weight;
+    fn create_sub_dao(m: u32) -> Weight;
+    fn freeze_sub_dao() -> Weight;
+    fn reclaim_sub_dao() -> Weight;
     fn alter_authority_add_member() -> Weight;
     fn alter_authority_add_member_preserve_threshold() -> Weight;
     fn alter_authority_remove_member() -> Weight;
     fn alter_authority_remove_member_preserve_threshold() -> Weight;
     fn alter_authority_replace_authority(m: u32) -> Weight;
+    fn alter_roles_set_role(c: u32) -> Weight;
+    fn alter_roles_remove_role() -> Weight;
+    fn alter_roles_grant() -> Weight;
+    fn alter_roles_revoke() -> Weight;
+    fn set_spending_limit(l: u32) -> Weight;
+    fn set_timelock() -> Weight;
+    fn cancel_authority_change() -> Weight;
+    fn apply_authority_change(m: u32) -> Weight;
+    fn dissolve(r: u32, a: u32) -> Weight;
     fn update_dao() -> Weight;
-    fn on_behalf() -> Weight;
+    fn on_behalf(r: u32, l: u32) -> Weight;
 }
 
 /// Weight functions for pallet_deip_dao.
@@ -55,40 +72,116 @@ impl<T: frame_system::Config> WeightInfo for Weights<T> {
             .saturating_add(T::DbWeight::get().reads(1 as Weight))
             .saturating_add(T::DbWeight::get().writes(2 as Weight))
     }
+    fn create_sub_dao(m: u32) -> Weight {
+        (97_415_000 as Weight)
+            .saturating_add((486_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn freeze_sub_dao() -> Weight {
+        (44_730_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn reclaim_sub_dao() -> Weight {
+        (71_952_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
     fn alter_authority_add_member() -> Weight {
         (135_686_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(2 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
     }
     fn alter_authority_add_member_preserve_threshold() -> Weight {
         (135_061_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(2 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
     }
     fn alter_authority_remove_member() -> Weight {
         (130_946_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(2 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
     }
     fn alter_authority_remove_member_preserve_threshold() -> Weight {
         (131_643_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(2 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
     }
     fn alter_authority_replace_authority(m: u32) -> Weight {
         (96_851_000 as Weight)
             // Standard Error: 7_000
             .saturating_add((715_000 as Weight).saturating_mul(m as Weight))
-            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn alter_roles_set_role(c: u32) -> Weight {
+        (52_410_000 as Weight)
+            .saturating_add((96_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
+    fn alter_roles_remove_role() -> Weight {
+        (49_872_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn alter_roles_grant() -> Weight {
+        (61_305_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn alter_roles_revoke() -> Weight {
+        (60_118_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn set_spending_limit(l: u32) -> Weight {
+        (47_926_000 as Weight)
+            .saturating_add((2_954_000 as Weight).saturating_mul(l as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(l as Weight)))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn set_timelock() -> Weight {
+        (58_371_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn cancel_authority_change() -> Weight {
+        (46_209_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn apply_authority_change(m: u32) -> Weight {
+        (101_532_000 as Weight)
+            .saturating_add((731_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn dissolve(r: u32, a: u32) -> Weight {
+        (94_862_000 as Weight)
+            .saturating_add((41_206_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add((63_418_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(r as Weight)))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(T::DbWeight::get().writes(10 as Weight))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+    }
     fn update_dao() -> Weight {
         (89_137_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(2 as Weight))
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
-    fn on_behalf() -> Weight {
-        (23_231_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+    fn on_behalf(r: u32, l: u32) -> Weight {
+        (33_870_000 as Weight)
+            .saturating_add((4_217_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add((18_644_000 as Weight).saturating_mul(l as Weight))
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+            .saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(l as Weight)))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(l as Weight)))
     }
 }
@@ -7,12 +7,12 @@ use codec::Codec;
 
 use sp_runtime::{generic::BlockId, traits::Block as BlockT};
 
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 
 pub use pallet_deip_dao::api::DeipDaoRuntimeApi;
 use pallet_deip_dao::{
-    api::{GetMultiResult, GetResult},
+    api::{GetMultiResult, GetResult, PendingAuthorityChangesResult, SubDaosResult},
     dao::{Dao, DaoId},
 };
 
@@ -23,7 +23,7 @@ use common_rpc::{BoxFutureResult, HashOf, ListResult, StorageMap};
 mod types;
 
 #[rpc]
-pub trait DeipDaoRpcApi<BlockHash, AccountId> {
+pub trait DeipDaoRpcApi<BlockHash, AccountId, BlockNumber> {
     #[rpc(name = "deipDao_get")]
     fn get(&self, at: Option<BlockHash>, id: DaoId) -> RpcResult<GetResult<AccountId>>;
 
@@ -41,6 +41,43 @@ pub trait DeipDaoRpcApi<BlockHash, AccountId> {
         count: u32,
         start_id: Option<DaoId>,
     ) -> BoxFutureResult<Vec<ListResult<DaoId, Dao<AccountId, DaoId>>>>;
+
+    #[rpc(name = "deipDao_getPendingAuthorityChanges")]
+    fn get_pending_authority_changes(
+        &self,
+        at: Option<BlockHash>,
+        id: DaoId,
+    ) -> RpcResult<PendingAuthorityChangesResult<AccountId, BlockNumber>>;
+
+    #[rpc(name = "deipDao_getSubDaos")]
+    fn get_sub_daos(&self, at: Option<BlockHash>, id: DaoId) -> RpcResult<SubDaosResult>;
+}
+
+/// Fails if the runtime at the block implements an older `DeipDaoRuntimeApi` than `version`
+fn ensure_api_version<Block, Api, AccountId, BlockNumber>(
+    api: &Api,
+    at: &BlockId<Block>,
+    version: u32,
+    error: common_rpc::Error,
+) -> RpcResult<()>
+where
+    Block: BlockT,
+    Api: ApiExt<Block>,
+    AccountId: Codec,
+    BlockNumber: Codec,
+{
+    let supported = api
+        .has_api_with::<dyn DeipDaoRuntimeApi<Block, AccountId, BlockNumber>, _>(at, |v| {
+            v >= version
+        })
+        .map_err(|e| common_rpc::to_rpc_error(error, Some(format!("{:?}", e))))?;
+    if !supported {
+        return Err(common_rpc::to_rpc_error(
+            common_rpc::Error::DaoApiVersionUnsupported,
+            Some(format!("DeipDaoRuntimeApi v{} is required", version)),
+        ))
+    }
+    Ok(())
 }
 
 pub struct DeipDaoRpcApiObj<C, State, Block> {
@@ -55,16 +92,17 @@ impl<C, State, Block> DeipDaoRpcApiObj<C, State, Block> {
     }
 }
 
-impl<C, State, Block, AccountId> DeipDaoRpcApi<HashOf<Block>, AccountId>
+impl<C, State, Block, AccountId, BlockNumber> DeipDaoRpcApi<HashOf<Block>, AccountId, BlockNumber>
     for DeipDaoRpcApiObj<C, State, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static,
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block>,
-    C::Api: DeipDaoRuntimeApi<Block, AccountId>,
+    C::Api: DeipDaoRuntimeApi<Block, AccountId, BlockNumber>,
     State: sc_rpc_api::state::StateApi<HashOf<Block>>,
     AccountId: 'static + Codec + std::marker::Send,
+    BlockNumber: 'static + Codec + std::marker::Send,
 {
     fn get(
         &self,
@@ -112,4 +150,50 @@ where
             start_id.map(types::DaoKeyValue::new),
         )
     }
+
+    fn get_pending_authority_changes(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        id: DaoId,
+    ) -> RpcResult<PendingAuthorityChangesResult<AccountId, BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        ensure_api_version::<_, _, AccountId, BlockNumber>(
+            &*api,
+            &at,
+            2,
+            common_rpc::Error::DaoApiGetPendingAuthorityChangesFailed,
+        )?;
+        let runtime_api_result = api.get_pending_authority_changes(&at, id);
+        runtime_api_result.map_err(|e| {
+            common_rpc::to_rpc_error(
+                common_rpc::Error::DaoApiGetPendingAuthorityChangesFailed,
+                Some(format!("{:?}", e)),
+            )
+        })
+    }
+
+    fn get_sub_daos(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        id: DaoId,
+    ) -> RpcResult<SubDaosResult> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        ensure_api_version::<_, _, AccountId, BlockNumber>(
+            &*api,
+            &at,
+            2,
+            common_rpc::Error::DaoApiGetSubDaosFailed,
+        )?;
+        let runtime_api_result = api.get_sub_daos(&at, id);
+        runtime_api_result.map_err(|e| {
+            common_rpc::to_rpc_error(
+                common_rpc::Error::DaoApiGetSubDaosFailed,
+                Some(format!("{:?}", e)),
+            )
+        })
+    }
 }
@@ -3,7 +3,7 @@ use jsonrpc_core::Result;
 use jsonrpc_derive::rpc;
 pub use pallet_deip::api::DeipApi as DeipStorageRuntimeApi;
 use pallet_deip::{*};
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{
     generic::BlockId,
@@ -15,15 +15,24 @@ use common_rpc::{
     get_list_by_index, to_rpc_error, BoxFutureResult, Error, HashOf, ListResult, StorageMap,
 };
 
-use frame_support::{Blake2_128Concat, Identity, Twox64Concat};
+use frame_support::{Blake2_128Concat, Identity};
 
 mod types;
 
 #[rpc]
-pub trait DeipStorageApi<BlockHash, AccountId, Moment, AssetId, AssetBalance, Hash, TransactionCtx>
-where
+pub trait DeipStorageApi<
+    BlockHash,
+    AccountId,
+    Moment,
+    AssetId,
+    AssetBalance,
+    Hash,
+    TransactionCtx,
+    Balance,
+> where
     AssetBalance: Clone + AtLeast32BitUnsigned,
     TransactionCtx: Default,
+    Balance: Clone + AtLeast32BitUnsigned,
 {
     #[rpc(name = "deip_getProjectList")]
     fn get_project_list(
@@ -47,7 +56,16 @@ where
         team_id: AccountId,
         count: u32,
         start_id: Option<ProjectId>,
-    ) -> BoxFutureResult<Vec<ListResult<ProjectId, Project<Hash, AccountId>>>>;
+    ) -> Result<Vec<ListResult<ProjectId, Project<Hash, AccountId>>>>;
+
+    #[rpc(name = "deip_getProjectListByDomain")]
+    fn get_project_list_by_domain(
+        &self,
+        at: Option<BlockHash>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ProjectId>,
+    ) -> Result<Vec<ListResult<ProjectId, Project<Hash, AccountId>>>>;
 
     #[rpc(name = "deip_getProjectContentList")]
     fn get_project_content_list(
@@ -64,7 +82,16 @@ where
         project_id: ProjectId,
         count: u32,
         start_id: Option<ProjectContentId>,
-    ) -> BoxFutureResult<Vec<ListResult<ProjectContentId, ProjectContent<Hash, AccountId>>>>;
+    ) -> Result<Vec<ListResult<ProjectContentId, ProjectContent<Hash, AccountId>>>>;
+
+    #[rpc(name = "deip_getProjectContentListByDomain")]
+    fn get_project_content_list_by_domain(
+        &self,
+        at: Option<BlockHash>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ProjectContentId>,
+    ) -> Result<Vec<ListResult<ProjectContentId, ProjectContent<Hash, AccountId>>>>;
 
     #[rpc(name = "deip_getProjectContent")]
     fn get_project_content(
@@ -73,6 +100,22 @@ where
         id: ProjectContentId,
     ) -> Result<Option<ProjectContent<Hash, AccountId>>>;
 
+    #[rpc(name = "deip_getContentCitations")]
+    fn get_content_citations(
+        &self,
+        at: Option<BlockHash>,
+        key: ProjectContentId,
+        count: u32,
+        start_id: Option<ProjectContentId>,
+    ) -> Result<Vec<ProjectContentId>>;
+
+    #[rpc(name = "deip_getContentCitationCount")]
+    fn get_content_citation_count(
+        &self,
+        at: Option<BlockHash>,
+        key: ProjectContentId,
+    ) -> Result<u32>;
+
     #[rpc(name = "deip_getDomainList")]
     fn get_domains(
         &self,
@@ -123,7 +166,16 @@ where
         key: ProjectContentId,
         count: u32,
         start_id: Option<ReviewId>,
-    ) -> BoxFutureResult<Vec<ListResult<ReviewId, Review<Hash, AccountId>>>>;
+    ) -> Result<Vec<ListResult<ReviewId, Review<Hash, AccountId>>>>;
+
+    #[rpc(name = "deip_getReviewListByDomain")]
+    fn get_review_list_by_domain(
+        &self,
+        at: Option<BlockHash>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ReviewId>,
+    ) -> Result<Vec<ListResult<ReviewId, Review<Hash, AccountId>>>>;
 
     #[rpc(name = "deip_getReviewListByReviewer")]
     fn get_review_list_by_reviewer(
@@ -146,7 +198,7 @@ where
         &self,
         at: Option<BlockHash>,
         id: ContractAgreementId,
-    ) -> Result<Option<contract::Agreement<AccountId, Hash, Moment>>>;
+    ) -> Result<Option<contract::Agreement<AccountId, Hash, Moment, Balance>>>;
 
     #[rpc(name = "deip_getContractAgreementList")]
     fn get_contract_agreement_list(
@@ -155,7 +207,7 @@ where
         count: u32,
         start_id: Option<ContractAgreementId>,
     ) -> BoxFutureResult<
-        Vec<ListResult<ContractAgreementId, contract::Agreement<AccountId, Hash, Moment>>>,
+        Vec<ListResult<ContractAgreementId, contract::Agreement<AccountId, Hash, Moment, Balance>>>,
     >;
 
     #[rpc(name = "deip_getContractAgreementListByType")]
@@ -165,8 +217,8 @@ where
         key: ContractAgreementIndexTerms,
         count: u32,
         start_id: Option<ContractAgreementId>,
-    ) -> BoxFutureResult<
-        Vec<ListResult<ContractAgreementId, contract::Agreement<AccountId, Hash, Moment>>>,
+    ) -> Result<
+        Vec<ListResult<ContractAgreementId, contract::Agreement<AccountId, Hash, Moment, Balance>>>,
     >;
 
     #[rpc(name = "deip_getReviewUpvoteListByReview")]
@@ -192,6 +244,64 @@ where
     >;
 }
 
+fn into_list_result<Key, Value>(list: Vec<(Key, Value)>) -> Vec<ListResult<Key, Value>> {
+    list.into_iter().map(|(key, value)| ListResult { key: key.into(), value }).collect()
+}
+
+/// The runtime returns no page if `start_id` is not in the index
+fn unknown_start_id() -> jsonrpc_core::Error {
+    to_rpc_error(Error::UnknownListStartId, Some("start_id is not in the index".to_string()))
+}
+
+/// Fails if the runtime at the block implements an older `DeipStorageRuntimeApi` than `version`
+fn ensure_api_version<
+    Block,
+    Api,
+    AccountId,
+    Moment,
+    AssetId,
+    AssetBalance,
+    Hash,
+    TransactionCtx,
+    Balance,
+>(
+    api: &Api,
+    at: &BlockId<Block>,
+    version: u32,
+    error: Error,
+) -> Result<()>
+where
+    Block: BlockT,
+    Api: ApiExt<Block>,
+    AccountId: Codec,
+    Moment: Codec,
+    AssetId: Codec,
+    AssetBalance: Codec + Clone + AtLeast32BitUnsigned,
+    Hash: Codec,
+    TransactionCtx: Codec,
+    Balance: Codec + Clone + AtLeast32BitUnsigned,
+{
+    let supported = api
+        .has_api_with::<dyn DeipStorageRuntimeApi<
+            Block,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >, _>(at, |v| v >= version)
+        .map_err(|e| to_rpc_error(error, Some(format!("{:?}", e))))?;
+    if !supported {
+        return Err(to_rpc_error(
+            Error::DeipApiVersionUnsupported,
+            Some(format!("DeipStorageRuntimeApi v{} is required", version)),
+        ))
+    }
+    Ok(())
+}
+
 /// A struct that implements the `DeipStorage`.
 pub struct DeipStorage<C, State, M> {
     // If you have more generics, no need to DeipStorage<C, M, N, P, ...>
@@ -208,9 +318,17 @@ impl<C, State, M> DeipStorage<C, State, M> {
     }
 }
 
-impl<C, State, Block, AccountId, Moment, AssetId, AssetBalance, Hash, TransactionCtx>
-    DeipStorageApi<HashOf<Block>, AccountId, Moment, AssetId, AssetBalance, Hash, TransactionCtx>
-    for DeipStorage<C, State, Block>
+impl<C, State, Block, AccountId, Moment, AssetId, AssetBalance, Hash, TransactionCtx, Balance>
+    DeipStorageApi<
+        HashOf<Block>,
+        AccountId,
+        Moment,
+        AssetId,
+        AssetBalance,
+        Hash,
+        TransactionCtx,
+        Balance,
+    > for DeipStorage<C, State, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static,
@@ -224,6 +342,7 @@ where
         AssetBalance,
         Hash,
         TransactionCtx,
+        Balance,
     >,
     State: sc_rpc_api::state::StateApi<HashOf<Block>>,
     AccountId: 'static + Codec + Send,
@@ -232,6 +351,7 @@ where
     AssetBalance: 'static + Codec + Send + Clone + AtLeast32BitUnsigned,
     Hash: 'static + Codec + Send,
     TransactionCtx: 'static + Codec + Send + Default,
+    Balance: 'static + Codec + Send + Clone + AtLeast32BitUnsigned,
 {
     fn get_project_list(
         &self,
@@ -257,6 +377,17 @@ where
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
 
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::ProjectApiGetFailed)?;
         let runtime_api_result = api.get_project(&at, &project_id);
         runtime_api_result
             .map_err(|e| to_rpc_error(Error::ProjectApiGetFailed, Some(format!("{:?}", e))))
@@ -268,17 +399,54 @@ where
         key: AccountId,
         count: u32,
         start_id: Option<ProjectId>,
-    ) -> BoxFutureResult<Vec<ListResult<ProjectId, Project<Hash, AccountId>>>> {
-        get_list_by_index::<Blake2_128Concat, Identity, _, _, _, _>(
-            &self.state,
-            at,
-            b"Deip",
-            b"ProjectIdByTeamId",
-            b"ProjectMap",
-            count,
-            &key,
-            start_id.map(types::ProjectKeyValue::new),
-        )
+    ) -> Result<Vec<ListResult<ProjectId, Project<Hash, AccountId>>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::ProjectApiGetFailed)?;
+        let runtime_api_result = api.get_project_list_by_team(&at, &key, start_id, count);
+        runtime_api_result
+            .map_err(|e| to_rpc_error(Error::ProjectApiGetFailed, Some(format!("{:?}", e))))?
+            .map(into_list_result)
+            .ok_or_else(unknown_start_id)
+    }
+
+    fn get_project_list_by_domain(
+        &self,
+        at: Option<HashOf<Block>>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ProjectId>,
+    ) -> Result<Vec<ListResult<ProjectId, Project<Hash, AccountId>>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::ProjectApiGetFailed)?;
+        let runtime_api_result = api.get_project_list_by_domain(&at, &domain_id, start_id, count);
+        runtime_api_result
+            .map_err(|e| to_rpc_error(Error::ProjectApiGetFailed, Some(format!("{:?}", e))))?
+            .map(into_list_result)
+            .ok_or_else(unknown_start_id)
     }
 
     fn get_domains(
@@ -305,6 +473,17 @@ where
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
 
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::DomainApiGetFailed)?;
         let runtime_api_result = api.get_domain(&at, &domain_id);
         runtime_api_result
             .map_err(|e| to_rpc_error(Error::DomainApiGetFailed, Some(format!("{:?}", e))))
@@ -332,17 +511,56 @@ where
         key: ProjectId,
         count: u32,
         start_id: Option<ProjectContentId>,
-    ) -> BoxFutureResult<Vec<ListResult<ProjectContentId, ProjectContent<Hash, AccountId>>>> {
-        get_list_by_index::<Identity, Identity, _, _, _, _>(
-            &self.state,
-            at,
-            b"Deip",
-            b"ContentIdByProjectId",
-            b"ProjectContentMap",
-            count,
-            &key,
-            start_id.map(types::ProjectContentKeyValue::new),
-        )
+    ) -> Result<Vec<ListResult<ProjectContentId, ProjectContent<Hash, AccountId>>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::ProjectContentApiGetFailed)?;
+        let runtime_api_result =
+            api.get_project_content_list_by_project(&at, &key, start_id, count);
+        runtime_api_result
+            .map_err(|e| to_rpc_error(Error::ProjectContentApiGetFailed, Some(format!("{:?}", e))))?
+            .map(into_list_result)
+            .ok_or_else(unknown_start_id)
+    }
+
+    fn get_project_content_list_by_domain(
+        &self,
+        at: Option<HashOf<Block>>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ProjectContentId>,
+    ) -> Result<Vec<ListResult<ProjectContentId, ProjectContent<Hash, AccountId>>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::ProjectContentApiGetFailed)?;
+        let runtime_api_result =
+            api.get_project_content_list_by_domain(&at, &domain_id, start_id, count);
+        runtime_api_result
+            .map_err(|e| to_rpc_error(Error::ProjectContentApiGetFailed, Some(format!("{:?}", e))))?
+            .map(into_list_result)
+            .ok_or_else(unknown_start_id)
     }
 
     fn get_project_content(
@@ -353,11 +571,76 @@ where
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
 
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::ProjectContentApiGetFailed)?;
         let runtime_api_result = api.get_project_content(&at, &id);
         runtime_api_result
             .map_err(|e| to_rpc_error(Error::ProjectContentApiGetFailed, Some(format!("{:?}", e))))
     }
 
+    fn get_content_citations(
+        &self,
+        at: Option<HashOf<Block>>,
+        key: ProjectContentId,
+        count: u32,
+        start_id: Option<ProjectContentId>,
+    ) -> Result<Vec<ProjectContentId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::ContentCitationsApiGetFailed)?;
+        let runtime_api_result = api.get_content_citations(&at, &key, start_id, count);
+        runtime_api_result
+            .map_err(|e| {
+                to_rpc_error(Error::ContentCitationsApiGetFailed, Some(format!("{:?}", e)))
+            })?
+            .ok_or_else(unknown_start_id)
+    }
+
+    fn get_content_citation_count(
+        &self,
+        at: Option<HashOf<Block>>,
+        key: ProjectContentId,
+    ) -> Result<u32> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::ContentCitationsApiGetFailed)?;
+        let runtime_api_result = api.get_content_citation_count(&at, &key);
+        runtime_api_result.map_err(|e| {
+            to_rpc_error(Error::ContentCitationsApiGetFailed, Some(format!("{:?}", e)))
+        })
+    }
+
     fn get_nda_list(
         &self,
         at: Option<HashOf<Block>>,
@@ -428,17 +711,54 @@ where
         key: ProjectContentId,
         count: u32,
         start_id: Option<ReviewId>,
-    ) -> BoxFutureResult<Vec<ListResult<ReviewId, Review<Hash, AccountId>>>> {
-        get_list_by_index::<Identity, Identity, _, _, _, _>(
-            &self.state,
-            at,
-            b"Deip",
-            b"ReviewIdByContentId",
-            b"ReviewMap",
-            count,
-            &key,
-            start_id.map(types::ReviewKeyValue::new),
-        )
+    ) -> Result<Vec<ListResult<ReviewId, Review<Hash, AccountId>>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::ReviewApiGetFailed)?;
+        let runtime_api_result = api.get_review_list_by_project_content(&at, &key, start_id, count);
+        runtime_api_result
+            .map_err(|e| to_rpc_error(Error::ReviewApiGetFailed, Some(format!("{:?}", e))))?
+            .map(into_list_result)
+            .ok_or_else(unknown_start_id)
+    }
+
+    fn get_review_list_by_domain(
+        &self,
+        at: Option<HashOf<Block>>,
+        domain_id: DomainId,
+        count: u32,
+        start_id: Option<ReviewId>,
+    ) -> Result<Vec<ListResult<ReviewId, Review<Hash, AccountId>>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::ReviewApiGetFailed)?;
+        let runtime_api_result = api.get_review_list_by_domain(&at, &domain_id, start_id, count);
+        runtime_api_result
+            .map_err(|e| to_rpc_error(Error::ReviewApiGetFailed, Some(format!("{:?}", e))))?
+            .map(into_list_result)
+            .ok_or_else(unknown_start_id)
     }
 
     fn get_review_list_by_reviewer(
@@ -468,6 +788,17 @@ where
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
 
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::ReviewApiGetFailed)?;
         let runtime_api_result = api.get_review(&at, &id);
         runtime_api_result
             .map_err(|e| to_rpc_error(Error::ReviewApiGetFailed, Some(format!("{:?}", e))))
@@ -477,10 +808,21 @@ where
         &self,
         at: Option<HashOf<Block>>,
         id: ContractAgreementId,
-    ) -> Result<Option<contract::Agreement<AccountId, Hash, Moment>>> {
+    ) -> Result<Option<contract::Agreement<AccountId, Hash, Moment, Balance>>> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
 
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::AgreementApiGetFailed)?;
         let runtime_api_result = api.get_contract_agreement(&at, &id);
         runtime_api_result
             .map_err(|e| to_rpc_error(Error::AgreementApiGetFailed, Some(format!("{:?}", e))))
@@ -492,7 +834,7 @@ where
         count: u32,
         start_id: Option<ContractAgreementId>,
     ) -> BoxFutureResult<
-        Vec<ListResult<ContractAgreementId, contract::Agreement<AccountId, Hash, Moment>>>,
+        Vec<ListResult<ContractAgreementId, contract::Agreement<AccountId, Hash, Moment, Balance>>>,
     > {
         StorageMap::<Blake2_128Concat>::get_list(
             &self.state,
@@ -510,19 +852,29 @@ where
         key: ContractAgreementIndexTerms,
         count: u32,
         start_id: Option<ContractAgreementId>,
-    ) -> BoxFutureResult<
-        Vec<ListResult<ContractAgreementId, contract::Agreement<AccountId, Hash, Moment>>>,
+    ) -> Result<
+        Vec<ListResult<ContractAgreementId, contract::Agreement<AccountId, Hash, Moment, Balance>>>,
     > {
-        get_list_by_index::<Twox64Concat, Blake2_128Concat, _, _, _, _>(
-            &self.state,
-            at,
-            b"Deip",
-            b"ContractAgreementIdByType",
-            b"ContractAgreementMap",
-            count,
-            &key,
-            start_id.map(types::AgreementKeyValue::new),
-        )
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        ensure_api_version::<
+            _,
+            _,
+            AccountId,
+            Moment,
+            AssetId,
+            AssetBalance,
+            Hash,
+            TransactionCtx,
+            Balance,
+        >(&*api, &at, 2, Error::AgreementApiGetFailed)?;
+        let runtime_api_result =
+            api.get_contract_agreement_list_by_type(&at, &key, start_id, count);
+        runtime_api_result
+            .map_err(|e| to_rpc_error(Error::AgreementApiGetFailed, Some(format!("{:?}", e))))?
+            .map(into_list_result)
+            .ok_or_else(unknown_start_id)
     }
 
     fn get_review_upvote_list_by_review(
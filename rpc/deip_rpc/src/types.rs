@@ -94,26 +94,28 @@ impl GetError for AgreementError {
     }
 }
 
-pub struct AgreementKeyValue<AccountId, Hash, Moment> {
+pub struct AgreementKeyValue<AccountId, Hash, Moment, Balance> {
     pub id: super::ContractAgreementId,
-    _m: std::marker::PhantomData<(AccountId, Hash, Moment)>,
+    _m: std::marker::PhantomData<(AccountId, Hash, Moment, Balance)>,
 }
 
-impl<AccountId, Hash, Moment> AgreementKeyValue<AccountId, Hash, Moment> {
+impl<AccountId, Hash, Moment, Balance> AgreementKeyValue<AccountId, Hash, Moment, Balance> {
     pub fn new(id: super::ContractAgreementId) -> Self {
         Self { id, _m: Default::default() }
     }
 }
 
-impl<AccountId, Hash, Moment> KeyValueInfo for AgreementKeyValue<AccountId, Hash, Moment>
+impl<AccountId, Hash, Moment, Balance> KeyValueInfo
+    for AgreementKeyValue<AccountId, Hash, Moment, Balance>
 where
     AccountId: 'static + Decode + Send,
     Hash: 'static + Decode + Send,
     Moment: 'static + Decode + Send,
+    Balance: 'static + Decode + Send + Clone + super::AtLeast32BitUnsigned,
 {
     type Key = super::ContractAgreementId;
     type KeyError = AgreementIdError;
-    type Value = super::contract::Agreement<AccountId, Hash, Moment>;
+    type Value = super::contract::Agreement<AccountId, Hash, Moment, Balance>;
     type ValueError = AgreementError;
 
     fn key(&self) -> &Self::Key {
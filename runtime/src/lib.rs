@@ -25,7 +25,7 @@ pub use frame_support::{
 use frame_system::{
     self,
     limits::{BlockLength, BlockWeights},
-    EnsureNever,
+    EnsureNever, EnsureOneOf, EnsureRoot,
 };
 pub use pallet_balances::Call as BalancesCall;
 use pallet_deip::{ProjectId, H160};
@@ -137,10 +137,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 105,
+    spec_version: 106,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
 };
 
 /// Since BABE is probabilistic this is the average expected block time that
@@ -732,6 +732,26 @@ pub type TransactionCtxId = pallet_deip_portal::TransactionCtxId<TransactionCtx>
 
 parameter_types! {
     pub const MaxNdaParties: u16 = 50;
+    pub const MaxNdaAccessRequests: u32 = 1000;
+    pub const MaxDomains: u32 = 1000;
+    pub const MaxDomainNameLength: u32 = 128;
+    pub const MaxContentTypeNameLength: u32 = 128;
+    pub const MaxAssessmentCriteria: u32 = 20;
+    pub const MaxAssessmentNameLength: u32 = 128;
+    pub const ExpertiseUpvoteReward: u64 = 100;
+    pub const VoterExpertiseShare: Perbill = Perbill::from_percent(10);
+    pub const ExpertiseDecay: Perbill = Perbill::from_percent(5);
+    pub const ExpertiseDecayPeriod: Moment = 30 * 24 * 60 * 60 * 1000;
+    pub const ContractAgreementExpirePeriod: BlockNumber = HOURS;
+    pub const MaxContractMilestones: u32 = 50;
+    pub const MaxExpiredContractAgreements: u32 = 100;
+    pub const DeipDepositBase: Balance = currency::deposit(1, 0);
+    pub const DeipDepositPerByte: Balance = currency::deposit(0, 1);
+    pub const MaxProjectDomains: u32 = 10;
+    pub const MaxReviewDomains: u32 = 10;
+    pub const MaxContentAuthors: u32 = 50;
+    pub const MaxContentReferences: u32 = 50;
+    pub DomainCuratorDao: pallet_deip_dao::DaoId = pallet_deip_dao::DaoId::from_slice(b"deip/domain-curators");
     pub const MaxCrowdfundingShares: u16 = 10;
 }
 
@@ -742,6 +762,44 @@ impl pallet_deip::Config for Runtime {
     type Currency = Balances;
     type DeipWeightInfo = pallet_deip::Weights<Self>;
     type MaxNdaParties = MaxNdaParties;
+    type MaxNdaAccessRequests = MaxNdaAccessRequests;
+    type DomainAdminOrigin = EnsureOneOf<
+        AccountId,
+        EnsureRoot<AccountId>,
+        pallet_deip_dao::EnsureDao<Runtime, DomainCuratorDao>,
+    >;
+    type MaxDomains = MaxDomains;
+    type MaxDomainNameLength = MaxDomainNameLength;
+    type ContentTypeAdminOrigin = EnsureOneOf<
+        AccountId,
+        EnsureRoot<AccountId>,
+        pallet_deip_dao::EnsureDao<Runtime, DomainCuratorDao>,
+    >;
+    type MaxContentTypeNameLength = MaxContentTypeNameLength;
+    // Crowdfundings operate on assets and are not linked to projects yet
+    type ProjectReferences = ();
+    type ProjectNft = pallet_deip::ProjectNft<DeipFNFT>;
+    type DaoLookup = Self;
+    type AssessmentModelAdminOrigin = EnsureOneOf<
+        AccountId,
+        EnsureRoot<AccountId>,
+        pallet_deip_dao::EnsureDao<Runtime, DomainCuratorDao>,
+    >;
+    type MaxAssessmentCriteria = MaxAssessmentCriteria;
+    type MaxAssessmentNameLength = MaxAssessmentNameLength;
+    type ExpertiseUpvoteReward = ExpertiseUpvoteReward;
+    type VoterExpertiseShare = VoterExpertiseShare;
+    type ExpertiseDecay = ExpertiseDecay;
+    type ExpertiseDecayPeriod = ExpertiseDecayPeriod;
+    type ContractAgreementExpirePeriod = ContractAgreementExpirePeriod;
+    type MaxContractMilestones = MaxContractMilestones;
+    type MaxExpiredContractAgreements = MaxExpiredContractAgreements;
+    type DepositBase = DeipDepositBase;
+    type DepositPerByte = DeipDepositPerByte;
+    type MaxProjectDomains = MaxProjectDomains;
+    type MaxReviewDomains = MaxReviewDomains;
+    type MaxContentAuthors = MaxContentAuthors;
+    type MaxContentReferences = MaxContentReferences;
 }
 
 use deip_asset_system::NFTokenFraction;
@@ -783,6 +841,10 @@ impl pallet_deip_proposal::pallet::Config for Runtime {
 
 parameter_types! {
     pub const DaoMaxSignatories: u16 = 50;
+    pub const DaoMaxRoleCalls: u16 = 64;
+    pub const DaoMaxMemberRoles: u16 = 8;
+    pub const DaoMaxSpendingLimits: u16 = 16;
+    pub const DaoMaxDistributedAssets: u16 = 16;
 }
 
 impl pallet_deip_dao::Config for Runtime {
@@ -791,6 +853,11 @@ impl pallet_deip_dao::Config for Runtime {
     type DaoId = pallet_deip_dao::DaoId;
     type DeipDaoWeightInfo = pallet_deip_dao::weights::Weights<Self>;
     type MaxSignatories = DaoMaxSignatories;
+    type MaxRoleCalls = DaoMaxRoleCalls;
+    type MaxMemberRoles = DaoMaxMemberRoles;
+    type Assets = pallet_deip_dao::DaoAssets<Balances, DeipFNFT>;
+    type MaxSpendingLimits = DaoMaxSpendingLimits;
+    type MaxDistributedAssets = DaoMaxDistributedAssets;
 }
 
 parameter_types! {
@@ -847,6 +914,12 @@ impl pallet_deip_portal::Config for Runtime {
     type DeipPortalWeightInfo = pallet_deip_portal::weights::Weights<Self>;
 }
 
+impl pallet_deip::DaoLookupT<AccountId> for Runtime {
+    fn is_dao(account: &AccountId) -> bool {
+        DeipDao::lookup_dao(account).is_some()
+    }
+}
+
 impl pallet_deip_portal::TenantLookupT<AccountId> for Runtime {
     type TenantId = <Self as pallet_deip_portal::Config>::PortalId;
 
@@ -926,7 +999,7 @@ construct_runtime!(
         MmrLeaf: pallet_beefy_mmr,
         Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>},
         Utility: pallet_utility::{Pallet, Call, Event},
-        Deip: pallet_deip::{Pallet, Call, Storage, Event<T>, Config},
+        Deip: pallet_deip::{Pallet, Call, Storage, Event<T>, Config, ValidateUnsigned},
         DeipProposal: pallet_deip_proposal::{Pallet, Call, Storage, Event<T>, Config, ValidateUnsigned},
         DeipDao: pallet_deip_dao::{Pallet, Call, Storage, Event<T>, Config},
         DeipStakeVoting: pallet_deip_stake_voting::{Pallet, Call, Storage, Event<T>},
@@ -1271,7 +1344,7 @@ impl_runtime_apis! {
         }
     }
 
-    impl pallet_deip_dao::api::DeipDaoRuntimeApi<Block, AccountId> for Runtime {
+    impl pallet_deip_dao::api::DeipDaoRuntimeApi<Block, AccountId, BlockNumber> for Runtime {
         fn get(name: pallet_deip_dao::dao::DaoId) -> pallet_deip_dao::api::GetResult<AccountId> {
             DeipDao::rpc_get(name)
         }
@@ -1279,6 +1352,14 @@ impl_runtime_apis! {
         fn get_multi(names: Vec<pallet_deip_dao::dao::DaoId>) -> pallet_deip_dao::api::GetMultiResult<AccountId> {
             DeipDao::rpc_get_multi(names)
         }
+
+        fn get_pending_authority_changes(name: pallet_deip_dao::dao::DaoId) -> pallet_deip_dao::api::PendingAuthorityChangesResult<AccountId, BlockNumber> {
+            DeipDao::rpc_get_pending_authority_changes(name)
+        }
+
+        fn get_sub_daos(name: pallet_deip_dao::dao::DaoId) -> pallet_deip_dao::api::SubDaosResult {
+            DeipDao::rpc_get_sub_daos(name)
+        }
     }
 
     impl pallet_deip::api::DeipApi
@@ -1289,7 +1370,8 @@ impl_runtime_apis! {
         DeipAssetId,
         AssetBalance,
         Hash,
-        pallet_deip_portal::TransactionCtxId<TransactionCtx>
+        pallet_deip_portal::TransactionCtxId<TransactionCtx>,
+        Balance
     >
     for Runtime {
         fn get_project(project_id: &ProjectId) -> Option<pallet_deip::ProjectOf<crate::Runtime>> {
@@ -1300,6 +1382,18 @@ impl_runtime_apis! {
             Deip::get_project_content(id)
         }
 
+        fn get_project_content_version(id: &pallet_deip::ProjectContentId, version: u32) -> Option<pallet_deip::ProjectContentVersionOf<crate::Runtime>> {
+            Deip::get_project_content_version(id, version)
+        }
+
+        fn get_content_citations(id: &pallet_deip::ProjectContentId, start_id: Option<pallet_deip::ProjectContentId>, count: u32) -> Option<Vec<pallet_deip::ProjectContentId>> {
+            Deip::get_content_citations(id, start_id, count)
+        }
+
+        fn get_content_citation_count(id: &pallet_deip::ProjectContentId) -> u32 {
+            Deip::get_content_citation_count(id)
+        }
+
         fn get_domain(domain_id: &pallet_deip::DomainId) -> Option<pallet_deip::Domain> {
             Deip::get_domain(domain_id)
         }
@@ -1312,8 +1406,48 @@ impl_runtime_apis! {
             Deip::get_review(id)
         }
 
+        fn get_assessment_model(id: &pallet_deip::AssessmentModelId) -> Option<pallet_deip::AssessmentModel> {
+            Deip::get_assessment_model(id)
+        }
+
+        fn get_expertise(account: &AccountId, domain_id: &pallet_deip::DomainId) -> u64 {
+            Deip::get_expertise(account, domain_id)
+        }
+
+        fn get_account_expertise(account: &AccountId) -> Vec<(pallet_deip::DomainId, u64)> {
+            Deip::get_account_expertise(account)
+        }
+
         fn get_contract_agreement(id: &pallet_deip::ContractAgreementId) -> Option<pallet_deip::ContractAgreementOf<crate::Runtime>> {
             Deip::get_contract_agreement(id)
         }
+
+        fn get_project_list_by_team(team_id: &AccountId, start_id: Option<ProjectId>, count: u32) -> Option<Vec<(ProjectId, pallet_deip::ProjectOf<crate::Runtime>)>> {
+            Deip::get_project_list_by_team(team_id, start_id, count)
+        }
+
+        fn get_project_content_list_by_project(project_id: &ProjectId, start_id: Option<pallet_deip::ProjectContentId>, count: u32) -> Option<Vec<(pallet_deip::ProjectContentId, pallet_deip::ProjectContentOf<crate::Runtime>)>> {
+            Deip::get_project_content_list_by_project(project_id, start_id, count)
+        }
+
+        fn get_review_list_by_project_content(id: &pallet_deip::ProjectContentId, start_id: Option<pallet_deip::ReviewId>, count: u32) -> Option<Vec<(pallet_deip::ReviewId, pallet_deip::ReviewOf<crate::Runtime>)>> {
+            Deip::get_review_list_by_project_content(id, start_id, count)
+        }
+
+        fn get_project_list_by_domain(domain_id: &pallet_deip::DomainId, start_id: Option<pallet_deip::ProjectId>, count: u32) -> Option<Vec<(pallet_deip::ProjectId, pallet_deip::ProjectOf<crate::Runtime>)>> {
+            Deip::get_project_list_by_domain(domain_id, start_id, count)
+        }
+
+        fn get_project_content_list_by_domain(domain_id: &pallet_deip::DomainId, start_id: Option<pallet_deip::ProjectContentId>, count: u32) -> Option<Vec<(pallet_deip::ProjectContentId, pallet_deip::ProjectContentOf<crate::Runtime>)>> {
+            Deip::get_project_content_list_by_domain(domain_id, start_id, count)
+        }
+
+        fn get_review_list_by_domain(domain_id: &pallet_deip::DomainId, start_id: Option<pallet_deip::ReviewId>, count: u32) -> Option<Vec<(pallet_deip::ReviewId, pallet_deip::ReviewOf<crate::Runtime>)>> {
+            Deip::get_review_list_by_domain(domain_id, start_id, count)
+        }
+
+        fn get_contract_agreement_list_by_type(terms: &pallet_deip::ContractAgreementIndexTerms, start_id: Option<pallet_deip::ContractAgreementId>, count: u32) -> Option<Vec<(pallet_deip::ContractAgreementId, pallet_deip::ContractAgreementOf<crate::Runtime>)>> {
+            Deip::get_contract_agreement_list_by_type(terms, start_id, count)
+        }
     }
 }